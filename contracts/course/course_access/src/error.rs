@@ -3,11 +3,13 @@
 
 use soroban_sdk::{contracterror, panic_with_error, Env};
 
+// Codes are published in schemas/; never reassign a code that has shipped
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
-    UserAlreadyHasAccess = 1,  
+    // Stable code raised by every grant path while the user has active access
+    UserAlreadyHasAccess = 1,
     UserNoAccessCourse = 2,   
     Unauthorized = 3,
     NameRequired = 4,
@@ -18,9 +20,62 @@ pub enum Error {
     EmptyCourseId = 9,
     InvalidTransferData = 10,
     SameUserTransfer = 11,
-    Initialized = 12
+    Initialized = 12,
+    UserProfileNotFound = 13,
+    BackupChecksumMismatch = 14,
+    InvalidBackupData = 15,
+    TrialAlreadyUsed = 16,
+    NotTrialAccess = 17,
+    // Raised by claim_enrollment for a missing, wrong or already used proof
+    InvalidEnrollmentProof = 18,
+    InvalidLimit = 19,
+    // Raised for an empty or oversized reason, completion or certificate hash
+    InvalidHash = 20,
+    VoucherNotFound = 21,
+    VoucherAlreadyExists = 22,
+    VoucherExpired = 23,
+    VoucherExhausted = 24,
+    VoucherRevoked = 25,
+    VoucherAlreadyRedeemed = 26,
+    InvalidVoucher = 27,
+    InviteNotFound = 28,
+    InviteExpired = 29,
+    // Raised by student-initiated enrollment outside the course's enrollment window
+    EnrollmentClosed = 30,
+    CourseAlreadyCompleted = 31,
+    // Raised when the configured user profile contract reports the user as banned
+    UserBanned = 32,
+    CompletionNotFound = 33,
+    ModuleNotFound = 34,
+    AccessSuspended = 35,
+    AccessNotSuspended = 36,
+    TooManyAdmins = 37,
+    InvalidVersion = 38,
+    NotInitialized = 39,
+    RegistryUnavailable = 40,
+    CourseNotFoundInRegistry = 41,
+    UserEnrollmentLimitReached = 42,
+    InvalidTimeRange = 43,
+    UserDenylisted = 44,
+    DenylistFull = 45,
+    InvalidConfig = 46,
+    // Raised by upgrade while a migration is marked as in progress
+    MigrationInProgress = 47,
+    TooManyGranters = 48,
+    Decommissioned = 49,
+    GrantRateLimitExceeded = 50,
 }
 
-pub fn handle_error(env: &Env, error: Error) -> ! {
+// `Error` is at the spec's limit of 50 cases, so later codes live here.
+// Codes continue after those of `Error` and never overlap them.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccessError {
+    // Raised by grants while profiles are required and the user has none
+    ProfileRequired = 51,
+}
+
+pub fn handle_error(env: &Env, error: impl Into<soroban_sdk::Error>) -> ! {
     panic_with_error!(env, error);
 }
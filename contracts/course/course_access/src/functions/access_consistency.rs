@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::config::require_not_decommissioned;
+use crate::functions::grant_access::{register_access_course, validate_course_id};
+use crate::functions::revoke_access::remove_from_user_courses;
+use crate::schema::{CourseUsers, DataKey, RecentGrant, UserCourses};
+
+const REPAIR_ACCESS_EVENT: Symbol = symbol_short!("repairAcc");
+
+/// Repair up to `max_entries` mismatches between a course's access entries
+/// and the user and course indexes
+///
+/// Every user in the course's `CourseUsers` list is checked against the
+/// `CourseAccess` entries:
+/// - a user without an access entry, or listed twice, is dropped from the
+///   list, and the course is dropped from that user's `UserCourses`;
+/// - a user with an access entry gets the course added to their
+///   `UserCourses` if it is missing.
+///
+/// Access entries missing from `CourseUsers` cannot be enumerated from the
+/// list itself, so the users of the course's recent grants are checked too:
+/// they are added back to both indexes when they still have an access
+/// entry, and the course is dropped from their `UserCourses` otherwise.
+///
+/// The caller must be an admin. Call repeatedly until it returns less than
+/// `max_entries` to repair a large course.
+///
+/// # Returns
+///
+/// The number of index entries fixed.
+pub fn repair_access_consistency(env: Env, caller: Address, course_id: String, max_entries: u32) -> u32 {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    require_not_decommissioned(&env);
+
+    let course_users_key: DataKey = DataKey::CourseUsers(course_id.clone());
+    let stored: Option<CourseUsers> = env.storage().persistent().get(&course_users_key);
+    let listed: Vec<Address> = stored
+        .as_ref()
+        .map(|course_users| course_users.users.clone())
+        .unwrap_or(Vec::new(&env));
+
+    let mut users: Vec<Address> = Vec::new(&env);
+    let mut fixed: u32 = 0;
+
+    for user in listed.iter() {
+        if fixed >= max_entries {
+            users.push_back(user);
+            continue;
+        }
+
+        if users.contains(&user) {
+            fixed += 1;
+            continue;
+        }
+
+        if has_access_entry(&env, &course_id, &user) {
+            if add_to_user_courses(&env, &user, &course_id) {
+                fixed += 1;
+            }
+            users.push_back(user);
+        } else {
+            remove_from_user_courses(&env, &user, &course_id);
+            fixed += 1;
+        }
+    }
+
+    let recent: Vec<RecentGrant> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RecentGrants(course_id.clone()))
+        .unwrap_or(Vec::new(&env));
+    for grant in recent.iter() {
+        if fixed >= max_entries {
+            break;
+        }
+        if users.contains(&grant.user) {
+            continue;
+        }
+        if !has_access_entry(&env, &course_id, &grant.user) {
+            if remove_from_user_courses(&env, &grant.user, &course_id) {
+                fixed += 1;
+            }
+            continue;
+        }
+
+        add_to_user_courses(&env, &grant.user, &course_id);
+        users.push_back(grant.user);
+        fixed += 1;
+    }
+
+    if fixed > 0 {
+        if stored.is_none() {
+            register_access_course(&env, &course_id);
+        }
+        env.storage().persistent().set(
+            &course_users_key,
+            &CourseUsers {
+                course: course_id.clone(),
+                users,
+            },
+        );
+        env.storage().persistent().extend_ttl(&course_users_key, 100, 1000);
+    }
+
+    env.events().publish(
+        (REPAIR_ACCESS_EVENT, course_id),
+        (caller, fixed, next_access_event_seq(&env)),
+    );
+
+    fixed
+}
+
+/// Returns `true` if an access entry is stored, whatever its state
+fn has_access_entry(env: &Env, course_id: &String, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::CourseAccess(course_id.clone(), user.clone()))
+}
+
+/// Add a course to the user's course index unless already listed
+///
+/// Returns `true` if the index was changed.
+fn add_to_user_courses(env: &Env, user: &Address, course_id: &String) -> bool {
+    let user_courses_key: DataKey = DataKey::UserCourses(user.clone());
+    let mut user_courses: UserCourses = env
+        .storage()
+        .persistent()
+        .get(&user_courses_key)
+        .unwrap_or(UserCourses {
+            user: user.clone(),
+            courses: Vec::new(env),
+        });
+    if user_courses.courses.contains(course_id) {
+        return false;
+    }
+
+    user_courses.courses.push_back(course_id.clone());
+    env.storage().persistent().set(&user_courses_key, &user_courses);
+    env.storage().persistent().extend_ttl(&user_courses_key, 100, 1000);
+    true
+}
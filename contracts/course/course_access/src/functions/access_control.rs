@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, IntoVal, InvokeError, String, Symbol, TryFromVal, Val, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::admin_list::is_local_admin;
+use crate::schema::{Course, CourseId, KEY_COURSE_REG_ADDR, KEY_USER_MGMT_ADDR};
+
+/// Check if a user is an admin
+///
+/// The local admin set is checked first. Only callers outside it are looked
+/// up in the user management contract, if one is configured.
+pub fn is_admin(env: &Env, who: &Address) -> bool {
+    if is_local_admin(env, who) {
+        return true;
+    }
+
+    let user_mgmt_addr: Option<Address> = env.storage().instance().get(&(KEY_USER_MGMT_ADDR,));
+    match user_mgmt_addr {
+        Some(addr) => env.invoke_contract(
+            &addr,
+            &Symbol::new(env, "is_admin"),
+            (who.clone(),).into_val(env),
+        ),
+        None => false,
+    }
+}
+
+/// Check if a user is the creator of a course by querying the course registry contract
+pub fn is_course_creator(env: &Env, course_id: &String, who: &Address) -> bool {
+    invoke_course_registry(
+        env,
+        "is_course_creator",
+        (course_id.clone(), who.clone()).into_val(env),
+    )
+}
+
+/// Get the module IDs of a course by querying the course registry contract
+pub fn get_course_module_ids(env: &Env, course_id: &String) -> Vec<String> {
+    invoke_course_registry(env, "get_course_module_ids", (course_id.clone(),).into_val(env))
+}
+
+/// Get every prerequisite of a course, transitively, from the course registry contract
+pub fn get_prerequisite_closure(env: &Env, course_id: &String) -> Vec<CourseId> {
+    invoke_course_registry(env, "get_prerequisite_closure", (course_id.clone(),).into_val(env))
+}
+
+/// Get the price a course is currently sold at from the course registry contract
+pub fn get_effective_price(env: &Env, course_id: &String) -> u128 {
+    invoke_course_registry(env, "get_effective_price", (course_id.clone(),).into_val(env))
+}
+
+/// Get a course from the course registry contract
+///
+/// Returns `None` if the registry cannot return the course, e.g. because it
+/// was deleted. Fails with `Error::NotInitialized` if no registry is configured.
+pub fn try_get_course(env: &Env, course_id: &String) -> Option<Course> {
+    env.try_invoke_contract::<Course, InvokeError>(
+        &course_registry_addr(env),
+        &Symbol::new(env, "get_course"),
+        (course_id.clone(),).into_val(env),
+    )
+    .ok()
+    .and_then(|course| course.ok())
+}
+
+/// Call a function of the configured course registry contract
+///
+/// Failures are translated so a misconfigured registry can be told apart
+/// from a rejected course:
+///
+/// * `Error::NotInitialized` if no registry address is configured
+/// * `Error::CourseNotFoundInRegistry` if the registry returns a contract error
+/// * `Error::RegistryUnavailable` if the call traps or returns an unexpected value
+fn invoke_course_registry<T: TryFromVal<Env, Val>>(env: &Env, func: &str, args: Vec<Val>) -> T {
+    match env.try_invoke_contract::<T, InvokeError>(&course_registry_addr(env), &Symbol::new(env, func), args) {
+        Ok(Ok(value)) => value,
+        Err(Ok(InvokeError::Contract(_))) => handle_error(env, Error::CourseNotFoundInRegistry),
+        _ => handle_error(env, Error::RegistryUnavailable),
+    }
+}
+
+/// The configured course registry address, failing with `Error::NotInitialized` if unset
+fn course_registry_addr(env: &Env) -> Address {
+    match env.storage().instance().get(&(KEY_COURSE_REG_ADDR,)) {
+        Some(addr) => addr,
+        None => handle_error(env, Error::NotInitialized),
+    }
+}
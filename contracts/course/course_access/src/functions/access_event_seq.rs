@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::Env;
+
+use crate::schema::DataKey;
+
+/// Returns the sequence number of the most recent access event, or 0 if none
+/// has been emitted yet.
+pub fn get_access_event_seq(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AccessEventSeq)
+        .unwrap_or(0)
+}
+
+/// Increments the access event sequence and returns the new value.
+///
+/// Every grant, revoke and transfer event carries the value returned here,
+/// so indexers can detect missed events by looking for gaps.
+pub fn next_access_event_seq(env: &Env) -> u64 {
+    let seq: u64 = get_access_event_seq(env) + 1;
+    env.storage().persistent().set(&DataKey::AccessEventSeq, &seq);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::AccessEventSeq, 100, 1000);
+    seq
+}
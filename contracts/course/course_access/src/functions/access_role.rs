@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::config::require_not_decommissioned;
+use crate::functions::grant_access::{load_access, validate_course_id};
+use crate::functions::purge_expired_access::is_access_expired;
+use crate::schema::{AccessRole, CourseAccess, DataKey};
+
+const ACCESS_ROLE_EVENT: Symbol = symbol_short!("accRole");
+
+/// Change the role of a user who has access to a course
+///
+/// The caller must be an admin or the creator of the course, and the user
+/// must currently have access.
+pub fn set_access_role(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    user: Address,
+    role: AccessRole,
+) {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !is_admin(&env, &caller) && !is_course_creator(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    require_not_decommissioned(&env);
+
+    let key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
+    let mut access: CourseAccess = match load_access(&env, &key) {
+        Some(access) => access,
+        None => handle_error(&env, Error::UserNoAccessCourse),
+    };
+    if is_access_expired(&env, &access) {
+        handle_error(&env, Error::UserNoAccessCourse)
+    }
+
+    let old_role: AccessRole = access.role;
+    access.role = role;
+    env.storage().persistent().set(&key, &access);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events()
+        .publish((ACCESS_ROLE_EVENT, course_id, user), (old_role, role, caller, next_access_event_seq(&env)));
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{DataKey, ACCESS_TTL_EXTEND_TO, ACCESS_TTL_THRESHOLD};
+
+/// Extend the TTL of a persistent entry that is close to expiring
+///
+/// The entry is extended to `ACCESS_TTL_EXTEND_TO` ledgers once its TTL has
+/// dropped below `ACCESS_TTL_THRESHOLD`. Missing entries are ignored.
+pub fn bump_access_ttl(env: &Env, key: &DataKey) {
+    if env.storage().persistent().has(key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, ACCESS_TTL_THRESHOLD, ACCESS_TTL_EXTEND_TO);
+    }
+}
+
+/// Extend the TTL of a user's access record and of both access indexes
+///
+/// Unlike the automatic bumps on reads, this always extends the entries to
+/// `ACCESS_TTL_EXTEND_TO` ledgers. Anyone may pay for the extension.
+/// Fails with `Error::UserNoAccessCourse` if there is no access record.
+pub fn extend_access_ttl(env: Env, course_id: String, user: Address) {
+    let access_key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
+    if !env.storage().persistent().has(&access_key) {
+        handle_error(&env, Error::UserNoAccessCourse)
+    }
+
+    for key in [access_key, DataKey::UserCourses(user), DataKey::CourseUsers(course_id)] {
+        if env.storage().persistent().has(&key) {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, ACCESS_TTL_EXTEND_TO, ACCESS_TTL_EXTEND_TO);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::config::require_owner;
+use crate::schema::{KEY_ADMINS, MAX_LOCAL_ADMINS};
+
+const ADD_ADMIN_EVENT: Symbol = symbol_short!("adminAdd");
+const REMOVE_ADMIN_EVENT: Symbol = symbol_short!("adminRm");
+
+/// Add an address to the local admin set
+///
+/// Local admins are recognized without calling the user management
+/// contract. Adding an existing admin changes nothing.
+/// The caller must be the contract owner.
+pub fn add_admin(env: Env, caller: Address, admin: Address) {
+    caller.require_auth();
+    require_owner(&env, &caller);
+
+    let mut admins: Vec<Address> = get_admins(env.clone());
+    if admins.contains(&admin) {
+        return;
+    }
+    if admins.len() >= MAX_LOCAL_ADMINS {
+        handle_error(&env, Error::TooManyAdmins)
+    }
+
+    admins.push_back(admin.clone());
+    env.storage().instance().set(&(KEY_ADMINS,), &admins);
+
+    env.events().publish((ADD_ADMIN_EVENT,), (caller, admin));
+}
+
+/// Remove an address from the local admin set
+///
+/// Removing an address that is not a local admin changes nothing.
+/// The caller must be the contract owner.
+pub fn remove_admin(env: Env, caller: Address, admin: Address) {
+    caller.require_auth();
+    require_owner(&env, &caller);
+
+    let mut admins: Vec<Address> = get_admins(env.clone());
+    let index: u32 = match admins.first_index_of(&admin) {
+        Some(index) => index,
+        None => return,
+    };
+
+    admins.remove(index);
+    env.storage().instance().set(&(KEY_ADMINS,), &admins);
+
+    env.events().publish((REMOVE_ADMIN_EVENT,), (caller, admin));
+}
+
+/// Get the local admin set
+pub fn get_admins(env: Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&(KEY_ADMINS,))
+        .unwrap_or(Vec::new(&env))
+}
+
+/// Check if an address is in the local admin set
+pub fn is_local_admin(env: &Env, who: &Address) -> bool {
+    get_admins(env.clone()).contains(who)
+}
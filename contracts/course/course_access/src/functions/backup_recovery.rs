@@ -0,0 +1,382 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, xdr::ToXdr, Address, Env, Map, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::contract_versioning::is_version_compatible;
+use crate::functions::enrollment_history::{get_enrollment_history, record_grant};
+use crate::functions::enrollment_id::restore_enrollment_id;
+use crate::functions::grant_access::{load_access, write_access_record};
+use crate::functions::payment::{get_purchase, record_purchase, remove_purchase};
+use crate::functions::revoke_access::revoke_and_publish;
+use crate::functions::save_profile::add_profile_user;
+use crate::functions::trial_access::mark_trial_used;
+use crate::schema::{
+    AccessBackupData, AccessImportMode, CourseAccess, CourseAccessChunk, CourseUsers, DataKey, EnrollmentRecord,
+    Purchase, RevokeReason, UserProfile, ACCESS_BACKUP_CHUNK_SIZE,
+};
+
+const ACCESS_BACKUP_VERSION: &str = "1.0.0";
+
+const ACCESS_IMPORT_EVENT: Symbol = symbol_short!("accImport");
+
+/// Export all course access data for backup purposes
+///
+/// The backup contains the full access entry of every user on a course roster
+/// (as `CourseAccessChunk`s of at most `ACCESS_BACKUP_CHUNK_SIZE` entries),
+/// including role, grant time, granter, expiry, trial and suspension state
+/// and enrollment ID, together with the payments, the enrollment history of
+/// those entries and every saved user profile, sealed with a checksum.
+///
+/// # Panics
+/// * If caller is not an admin
+pub fn export_access_data(env: Env, caller: Address) -> AccessBackupData {
+    caller.require_auth();
+
+    if !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let mut grants: Vec<CourseAccessChunk> = Vec::new(&env);
+    let mut purchases: Map<u64, Purchase> = Map::new(&env);
+    let mut history: Vec<EnrollmentRecord> = Vec::new(&env);
+
+    let courses: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AccessCourses)
+        .unwrap_or(Vec::new(&env));
+
+    for course_id in courses.iter() {
+        let users: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CourseUsers>(&DataKey::CourseUsers(course_id.clone()))
+            .map(|course_users| course_users.users)
+            .unwrap_or(Vec::new(&env));
+
+        let mut chunk: Vec<CourseAccess> = Vec::new(&env);
+        for user in users.iter() {
+            let access: CourseAccess =
+                match load_access(&env, &DataKey::CourseAccess(course_id.clone(), user.clone())) {
+                    Some(access) => access,
+                    None => continue,
+                };
+
+            for record in get_enrollment_history(env.clone(), course_id.clone(), user.clone()).iter() {
+                history.push_back(record);
+            }
+            if let Some(purchase) = get_purchase(&env, access.enrollment_id) {
+                purchases.set(access.enrollment_id, purchase);
+            }
+
+            chunk.push_back(access);
+            if chunk.len() == ACCESS_BACKUP_CHUNK_SIZE {
+                grants.push_back(CourseAccessChunk {
+                    course: course_id.clone(),
+                    entries: chunk,
+                });
+                chunk = Vec::new(&env);
+            }
+        }
+        if !chunk.is_empty() {
+            grants.push_back(CourseAccessChunk {
+                course: course_id.clone(),
+                entries: chunk,
+            });
+        }
+    }
+
+    let mut profiles: Map<Address, UserProfile> = Map::new(&env);
+    let profile_users: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ProfileUsers)
+        .unwrap_or(Vec::new(&env));
+    for user in profile_users.iter() {
+        if let Some(profile) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, UserProfile>(&DataKey::UserProfile(user.clone()))
+        {
+            profiles.set(user, profile);
+        }
+    }
+
+    let mut backup_data: AccessBackupData = AccessBackupData {
+        grants,
+        purchases,
+        profiles,
+        history,
+        backup_version: String::from_str(&env, ACCESS_BACKUP_VERSION),
+        source_version: String::from_str(&env, crate::VERSION),
+        exported_at: env.ledger().timestamp(),
+        checksum: String::from_str(&env, ""),
+    };
+
+    // Seal the backup so tampering can be detected on import
+    backup_data.checksum = compute_backup_checksum(&env, &backup_data);
+
+    backup_data
+}
+
+/// Import course access data from a backup
+///
+/// The whole backup is validated before anything is written. In `Merge` mode
+/// existing grants and profiles are left untouched and only missing ones are
+/// added. In `Overwrite` mode the grants of every course present in the
+/// backup are replaced by the backed-up ones, and backed-up profiles replace
+/// existing ones.
+///
+/// Access entries are written back unchanged, keeping their enrollment ID
+/// unless it is already used by another course and user here. Payments move
+/// with their entries, and restored trials use up the user's trial.
+///
+/// # Returns
+/// * `u32` - Number of grants written
+///
+/// # Panics
+/// * If caller is not an admin
+/// * If the backup format or source version is incompatible
+/// * If the checksum does not match the backup contents
+/// * If any grant, profile or history record is invalid
+pub fn import_access_data(
+    env: Env,
+    caller: Address,
+    backup_data: AccessBackupData,
+    mode: AccessImportMode,
+) -> u32 {
+    let (course_entries, history) = validate_backup(&env, &caller, &backup_data);
+
+    let mut imported: u32 = 0;
+    let mut skipped: u32 = 0;
+
+    for (course_id, entries) in course_entries.iter() {
+        if mode == AccessImportMode::Overwrite {
+            let mut users: Vec<Address> = Vec::new(&env);
+            for access in entries.iter() {
+                users.push_back(access.user);
+            }
+
+            // Drop grants of this course that are not part of the backup
+            let existing: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get::<DataKey, CourseUsers>(&DataKey::CourseUsers(course_id.clone()))
+                .map(|course_users| course_users.users)
+                .unwrap_or(Vec::new(&env));
+            for user in existing.iter() {
+                if !users.contains(&user) {
+                    revoke_and_publish(&env, course_id.clone(), user, Some(caller.clone()), RevokeReason::Unspecified, None);
+                }
+            }
+        }
+
+        for mut access in entries.iter() {
+            let user: Address = access.user.clone();
+            let access_key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
+            let existing: Option<CourseAccess> = load_access(&env, &access_key);
+            if mode == AccessImportMode::Merge && existing.is_some() {
+                skipped += 1;
+                continue;
+            }
+
+            let backed_up_id: u64 = access.enrollment_id;
+            access.enrollment_id = restore_enrollment_id(&env, &course_id, &user, backed_up_id);
+
+            // The replaced entry's payment no longer backs any access
+            if let Some(existing) = existing {
+                if existing.enrollment_id != access.enrollment_id {
+                    remove_purchase(&env, existing.enrollment_id);
+                }
+            }
+
+            write_access_record(&env, &access);
+            if let Some(purchase) = backup_data.purchases.get(backed_up_id) {
+                record_purchase(&env, access.enrollment_id, &purchase.payer, purchase.amount);
+            }
+            if access.is_trial {
+                mark_trial_used(&env, &course_id, &user);
+            }
+
+            let history_key: DataKey = DataKey::EnrollmentHistory(course_id.clone(), user.clone());
+            let keep_local_history: bool =
+                mode == AccessImportMode::Merge && env.storage().persistent().has(&history_key);
+            match history.get(history_key.clone()) {
+                Some(records) if !keep_local_history => {
+                    // Follow the entry if it had to take a fresh enrollment ID
+                    let mut restored: Vec<EnrollmentRecord> = Vec::new(&env);
+                    for mut record in records.iter() {
+                        if record.enrollment_id == backed_up_id {
+                            record.enrollment_id = access.enrollment_id;
+                        }
+                        restored.push_back(record);
+                    }
+                    env.storage().persistent().set(&history_key, &restored);
+                    env.storage().persistent().extend_ttl(&history_key, 100, 1000);
+                }
+                _ => record_grant(&env, &course_id, &user, access.enrollment_id),
+            }
+
+            imported += 1;
+        }
+    }
+
+    for (user, profile) in backup_data.profiles.iter() {
+        let profile_key: DataKey = DataKey::UserProfile(user.clone());
+        if mode == AccessImportMode::Merge && env.storage().persistent().has(&profile_key) {
+            continue;
+        }
+        env.storage().persistent().set(&profile_key, &profile);
+        add_profile_user(&env, &user);
+    }
+
+    env.events()
+        .publish((ACCESS_IMPORT_EVENT, caller), (imported, skipped, next_access_event_seq(&env)));
+
+    imported
+}
+
+/// Check that a backup may be imported by `caller`
+///
+/// Returns the backed-up access entries grouped by course and the backed-up
+/// history grouped by (course, user), so the import does not have to regroup
+/// them.
+fn validate_backup(
+    env: &Env,
+    caller: &Address,
+    backup_data: &AccessBackupData,
+) -> (Map<String, Vec<CourseAccess>>, Map<DataKey, Vec<EnrollmentRecord>>) {
+    caller.require_auth();
+
+    if !is_admin(env, caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if backup_data.backup_version != String::from_str(env, ACCESS_BACKUP_VERSION)
+        || !is_version_compatible(
+            env,
+            backup_data.source_version.clone(),
+            String::from_str(env, crate::VERSION),
+        )
+    {
+        handle_error(env, Error::InvalidBackupData)
+    }
+
+    // Reject corrupted or tampered backups
+    if compute_backup_checksum(env, backup_data) != backup_data.checksum {
+        handle_error(env, Error::BackupChecksumMismatch)
+    }
+
+    let mut course_users: Map<String, Vec<Address>> = Map::new(env);
+    let mut course_entries: Map<String, Vec<CourseAccess>> = Map::new(env);
+    let mut enrollment_ids: Map<u64, bool> = Map::new(env);
+    for chunk in backup_data.grants.iter() {
+        if chunk.course.is_empty()
+            || chunk.course.len() > 100
+            || chunk.entries.is_empty()
+            || chunk.entries.len() > ACCESS_BACKUP_CHUNK_SIZE
+        {
+            handle_error(env, Error::InvalidBackupData)
+        }
+
+        let mut users: Vec<Address> = course_users
+            .get(chunk.course.clone())
+            .unwrap_or(Vec::new(env));
+        let mut entries: Vec<CourseAccess> = course_entries
+            .get(chunk.course.clone())
+            .unwrap_or(Vec::new(env));
+        for access in chunk.entries.iter() {
+            if access.course_id != chunk.course
+                || users.contains(&access.user)
+                || !is_valid_access_entry(backup_data, &access)
+            {
+                handle_error(env, Error::InvalidBackupData)
+            }
+            // Enrollment IDs are unique; 0 marks entries that never had one
+            if access.enrollment_id != 0 {
+                if enrollment_ids.contains_key(access.enrollment_id) {
+                    handle_error(env, Error::InvalidBackupData)
+                }
+                enrollment_ids.set(access.enrollment_id, true);
+            }
+            users.push_back(access.user.clone());
+            entries.push_back(access);
+        }
+        course_users.set(chunk.course.clone(), users);
+        course_entries.set(chunk.course, entries);
+    }
+
+    // Payments are only restored alongside the entry they paid for
+    for (enrollment_id, purchase) in backup_data.purchases.iter() {
+        if !enrollment_ids.contains_key(enrollment_id) || purchase.amount <= 0 {
+            handle_error(env, Error::InvalidBackupData)
+        }
+    }
+
+    for (_user, profile) in backup_data.profiles.iter() {
+        if profile.name.is_empty() || profile.email.is_empty() || profile.country.is_empty() {
+            handle_error(env, Error::InvalidBackupData)
+        }
+    }
+
+    let mut history: Map<DataKey, Vec<EnrollmentRecord>> = Map::new(env);
+    for record in backup_data.history.iter() {
+        // History is only restored alongside the grant it belongs to
+        let has_grant: bool = course_users
+            .get(record.course_id.clone())
+            .is_some_and(|users| users.contains(&record.user));
+        let is_ordered: bool = record
+            .revoked_at
+            .is_none_or(|revoked_at| revoked_at >= record.granted_at);
+        if !has_grant || !is_ordered {
+            handle_error(env, Error::InvalidBackupData)
+        }
+
+        let key: DataKey = DataKey::EnrollmentHistory(record.course_id.clone(), record.user.clone());
+        let mut records: Vec<EnrollmentRecord> = history.get(key.clone()).unwrap_or(Vec::new(env));
+        records.push_back(record);
+        history.set(key, records);
+    }
+
+    (course_entries, history)
+}
+
+/// Whether a backed-up access entry is internally consistent
+fn is_valid_access_entry(backup_data: &AccessBackupData, access: &CourseAccess) -> bool {
+    access.granted_at <= backup_data.exported_at
+        && access
+            .expires_at
+            .is_none_or(|expires_at| expires_at > access.granted_at)
+        && (!access.is_trial || access.expires_at.is_some())
+        && (access.suspended || access.suspension_reason.is_none())
+        && access.transferred_from.is_some() == access.transferred_at.is_some()
+}
+
+/// Compute the checksum of a backup
+///
+/// The checksum is the hex-encoded SHA-256 of the XDR serialization of every
+/// backup field except the checksum itself.
+fn compute_backup_checksum(env: &Env, backup_data: &AccessBackupData) -> String {
+    let contents = (
+        backup_data.grants.clone(),
+        backup_data.purchases.clone(),
+        backup_data.profiles.clone(),
+        backup_data.history.clone(),
+        backup_data.backup_version.clone(),
+        backup_data.source_version.clone(),
+        backup_data.exported_at,
+    );
+    let hash: [u8; 32] = env.crypto().sha256(&contents.to_xdr(env)).to_array();
+
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut hex: [u8; 64] = [0u8; 64];
+    for (i, byte) in hash.iter().enumerate() {
+        hex[i * 2] = HEX_DIGITS[(byte >> 4) as usize];
+        hex[i * 2 + 1] = HEX_DIGITS[(byte & 0x0f) as usize];
+    }
+    String::from_bytes(env, &hex)
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, InvokeError, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::config::require_owner;
+use crate::schema::{BanCheckConfig, KEY_BAN_CHECK};
+
+const UPDATE_BAN_CHECK_EVENT: Symbol = symbol_short!("updBanChk");
+
+/// Sets or clears the user profile contract asked whether users are banned.
+/// This function can only be called by the contract owner.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment object.
+/// * `caller` - The address of the caller. Must be the current owner of the contract.
+/// * `profile_contract` - The contract exposing `is_banned(user) -> bool`, or `None` to disable the check.
+/// * `fail_open` - Whether grants go ahead when the profile contract cannot answer.
+///
+/// # Panics
+///
+/// * This function will call `handle_error` with `Error::Unauthorized` if the caller is not the owner.
+pub fn set_ban_check(env: Env, caller: Address, profile_contract: Option<Address>, fail_open: bool) {
+    caller.require_auth();
+    require_owner(&env, &caller);
+
+    match profile_contract.clone() {
+        Some(profile_contract) => env.storage().instance().set(
+            &(KEY_BAN_CHECK,),
+            &BanCheckConfig {
+                profile_contract,
+                fail_open,
+            },
+        ),
+        None => env.storage().instance().remove(&(KEY_BAN_CHECK,)),
+    }
+    env.events()
+        .publish((UPDATE_BAN_CHECK_EVENT,), (caller, profile_contract, fail_open));
+}
+
+/// Returns the ban check configuration, if the check is enabled.
+pub fn get_ban_check(env: &Env) -> Option<BanCheckConfig> {
+    env.storage().instance().get(&(KEY_BAN_CHECK,))
+}
+
+/// Fail with `Error::UserBanned` if the user profile contract reports the user as banned
+///
+/// If the profile contract cannot answer, the grant goes ahead when the check
+/// is configured to fail open and is refused the same way otherwise.
+pub fn require_not_banned(env: &Env, user: &Address) {
+    if is_banned(env, user) {
+        handle_error(env, Error::UserBanned)
+    }
+}
+
+/// Returns `true` if grants to the user must be refused under the ban check
+///
+/// An unanswered query counts as banned unless the check fails open.
+pub fn is_banned(env: &Env, user: &Address) -> bool {
+    let config: BanCheckConfig = match get_ban_check(env) {
+        Some(config) => config,
+        None => return false,
+    };
+
+    match env.try_invoke_contract::<bool, InvokeError>(
+        &config.profile_contract,
+        &Symbol::new(env, "is_banned"),
+        (user.clone(),).into_val(env),
+    ) {
+        Ok(Ok(banned)) => banned,
+        _ => !config.fail_open,
+    }
+}
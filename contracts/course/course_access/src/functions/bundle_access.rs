@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, try_get_course};
+use crate::functions::grant_access::{can_store_access, full_access, has_access, store_access, validate_course_id};
+use crate::functions::granters::is_granter;
+use crate::schema::{BundleGrantResult, Course, CourseAccess, MAX_BUNDLE_SIZE};
+
+const BUNDLE_GRANT_EVENT: Symbol = symbol_short!("bndlGrant");
+
+/// Grant a user access to several courses in one call
+///
+/// The caller authorizes once and must be an admin, or the creator,
+/// a co-creator or a granting delegate of each course. Each course must
+/// exist in the registry, be published and not archived, and the grant must
+/// pass the usual checks (denylist, enrollment limit, rate limit, ...).
+///
+/// By default courses that fail are skipped and reported in the result. With
+/// `atomic` the first failure aborts the call with its error and nothing is
+/// granted. Courses the user already has access to count as failed.
+///
+/// Fails with `Error::InvalidLimit` if more than `MAX_BUNDLE_SIZE` courses
+/// are given.
+pub fn grant_bundle_access(
+    env: Env,
+    caller: Address,
+    course_ids: Vec<String>,
+    user: Address,
+    atomic: bool,
+) -> BundleGrantResult {
+    caller.require_auth();
+
+    if course_ids.len() > MAX_BUNDLE_SIZE {
+        handle_error(&env, Error::InvalidLimit)
+    }
+
+    let caller_is_admin: bool = is_admin(&env, &caller);
+    let mut granted: u32 = 0;
+    let mut failed: Vec<String> = Vec::new(&env);
+
+    for course_id in course_ids.iter() {
+        if atomic {
+            grant_bundle_course(&env, &caller, caller_is_admin, &course_id, &user);
+            granted += 1;
+        } else if try_grant_bundle_course(&env, &caller, caller_is_admin, &course_id, &user) {
+            granted += 1;
+        } else {
+            failed.push_back(course_id);
+        }
+    }
+
+    env.events()
+        .publish((BUNDLE_GRANT_EVENT, user), (caller, granted, failed.clone()));
+
+    BundleGrantResult { granted, failed }
+}
+
+/// Grant one course of an atomic bundle, failing on the first problem
+fn grant_bundle_course(env: &Env, caller: &Address, caller_is_admin: bool, course_id: &String, user: &Address) {
+    validate_course_id(env, course_id);
+
+    let course: Course = match try_get_course(env, course_id) {
+        Some(course) if course.published && !course.is_archived => course,
+        _ => handle_error(env, Error::CourseNotFoundInRegistry),
+    };
+    if !caller_is_admin && !can_grant_course(env, caller, &course) {
+        handle_error(env, Error::Unauthorized)
+    }
+    if has_access(env, course_id, user) {
+        handle_error(env, Error::UserAlreadyHasAccess)
+    }
+
+    store_access(env, full_access(env, course_id.clone(), user.clone(), Some(caller.clone())));
+}
+
+/// Grant one course of a partial bundle, returning `false` instead of failing
+fn try_grant_bundle_course(env: &Env, caller: &Address, caller_is_admin: bool, course_id: &String, user: &Address) -> bool {
+    if course_id.is_empty() || course_id.len() > 100 {
+        return false;
+    }
+
+    let course: Course = match try_get_course(env, course_id) {
+        Some(course) if course.published && !course.is_archived => course,
+        _ => return false,
+    };
+    if !caller_is_admin && !can_grant_course(env, caller, &course) {
+        return false;
+    }
+    if has_access(env, course_id, user) {
+        return false;
+    }
+
+    let access: CourseAccess = full_access(env, course_id.clone(), user.clone(), Some(caller.clone()));
+    if !can_store_access(env, &access) {
+        return false;
+    }
+    store_access(env, access);
+    true
+}
+
+/// Returns `true` if the caller is the creator, a co-creator or a granting delegate of the course
+fn can_grant_course(env: &Env, caller: &Address, course: &Course) -> bool {
+    course.creator == *caller || course.co_creators.contains(caller) || is_granter(env, &course.id, caller)
+}
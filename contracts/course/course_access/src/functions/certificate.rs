@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::grant_access::validate_course_id;
+use crate::schema::{
+    CertificateAnchor, DataKey, MAX_CERTIFICATE_HISTORY, MAX_COMPLETION_HASH_LENGTH,
+};
+
+const CERTIFICATE_ANCHORED_EVENT: Symbol = symbol_short!("certAnchr");
+
+/// Anchor the hash of an off-chain certificate to a recorded completion
+///
+/// The first anchoring may be done by the course creator or an admin.
+/// Replacing an anchored hash is admin-only; the replaced hash is kept in a
+/// bounded history.
+pub fn anchor_certificate(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    student: Address,
+    certificate_hash: String,
+) -> CertificateAnchor {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if certificate_hash.is_empty() || certificate_hash.len() > MAX_COMPLETION_HASH_LENGTH {
+        handle_error(&env, Error::InvalidHash)
+    }
+
+    if !env
+        .storage()
+        .persistent()
+        .has(&DataKey::Completion(course_id.clone(), student.clone()))
+    {
+        handle_error(&env, Error::CompletionNotFound)
+    }
+
+    let key: DataKey = DataKey::Certificate(course_id.clone(), student.clone());
+    let existing: Option<CertificateAnchor> = env.storage().persistent().get(&key);
+
+    let caller_is_admin: bool = is_admin(&env, &caller);
+    let authorized: bool = match existing {
+        Some(_) => caller_is_admin,
+        None => caller_is_admin || is_course_creator(&env, &course_id, &caller),
+    };
+    if !authorized {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let previous_hashes: Vec<String> = match existing {
+        Some(anchor) => {
+            let mut history: Vec<String> = anchor.previous_hashes;
+            history.push_back(anchor.certificate_hash);
+            // Keep only the most recent replaced hashes
+            while history.len() > MAX_CERTIFICATE_HISTORY {
+                history.pop_front();
+            }
+            history
+        }
+        None => Vec::new(&env),
+    };
+
+    let anchor: CertificateAnchor = CertificateAnchor {
+        course_id,
+        student: student.clone(),
+        certificate_hash,
+        issued_at: env.ledger().timestamp(),
+        issued_by: caller,
+        previous_hashes,
+    };
+    env.storage().persistent().set(&key, &anchor);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events().publish(
+        (CERTIFICATE_ANCHORED_EVENT, student),
+        (
+            anchor.course_id.clone(),
+            anchor.certificate_hash.clone(),
+            anchor.issued_by.clone(),
+        ),
+    );
+
+    anchor
+}
+
+/// Check whether `certificate_hash` is the currently anchored certificate
+pub fn verify_certificate(
+    env: Env,
+    course_id: String,
+    student: Address,
+    certificate_hash: String,
+) -> bool {
+    match get_certificate(env, course_id, student) {
+        Some(anchor) => anchor.certificate_hash == certificate_hash,
+        None => false,
+    }
+}
+
+/// Get the certificate anchored to a completion, if any
+pub fn get_certificate(env: Env, course_id: String, student: Address) -> Option<CertificateAnchor> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Certificate(course_id, student))
+}
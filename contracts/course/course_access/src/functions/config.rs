@@ -5,14 +5,20 @@ use soroban_sdk::{Address, Env, Symbol, symbol_short};
 use soroban_sdk::storage::Instance;
 
 use crate::error::{Error, handle_error};
-use crate::schema::{KEY_COURSE_REG_ADDR, KEY_USER_MGMT_ADDR};
+use crate::functions::contract_versioning::record_deployed_version;
+use crate::schema::{KEY_COURSE_REG_ADDR, KEY_NOTIFY_ADDR, KEY_PAYMENT_TOKEN, KEY_USER_MGMT_ADDR};
 
-const INIT_EVENT: Symbol = symbol_short!("initialz");
-const UPDATE_ADDRESS_EVENT: Symbol = symbol_short!("updAddr");
+const INIT_EVENT: Symbol = symbol_short!("init");
+const CONFIG_CHANGED_EVENT: Symbol = symbol_short!("cfgChange");
+const UPDATE_NOTIFY_EVENT: Symbol = symbol_short!("updNotify");
+const UPDATE_PAY_TOKEN_EVENT: Symbol = symbol_short!("updPayTok");
+const DECOMMISSION_EVENT: Symbol = symbol_short!("decomm");
 
 
 const KEY_INIT: &str = "init";
 
+const KEY_DECOMMISSIONED: &str = "decommissioned";
+
 const KEY_OWNER: &str = "owner";
 
 /// Initializes the contract, setting the owner and dependent contract addresses.
@@ -37,12 +43,7 @@ pub fn initialize(
     caller.require_auth();
 
     // Prevent re-initialization
-    if env
-        .storage()
-        .instance()
-        .get::<_, bool>(&((KEY_INIT,),))
-        .unwrap_or(false)
-    {
+    if is_initialized(&env) {
         handle_error(&env, Error::Initialized);
     }
 
@@ -52,6 +53,8 @@ pub fn initialize(
     inst.set(&(KEY_COURSE_REG_ADDR,), &course_registry_addr);
     inst.set(&(KEY_INIT,), &true);
 
+    record_deployed_version(&env);
+
     env.events()
         .publish((INIT_EVENT,), (caller, user_mgmt_addr, course_registry_addr));
 }
@@ -68,8 +71,10 @@ pub fn initialize(
 ///
 /// # Panics
 ///
-/// * This function will panic with the message "not initialized" if the contract has not been initialized.
-/// * This function will panic with the message "only owner" if the caller is not the owner.
+/// * This function will call `handle_error` with `Error::NotInitialized` if the contract has not been initialized.
+/// * This function will call `handle_error` with `Error::Unauthorized` if the caller is not the owner.
+/// * This function will call `handle_error` with `Error::InvalidConfig` if both addresses are the same
+///   or either one is this contract's own address.
 pub fn set_contract_addrs(
     env: Env,
     caller: Address,
@@ -79,30 +84,168 @@ pub fn set_contract_addrs(
     caller.require_auth();
 
     // Require initialized
-    if !env
-        .storage()
-        .instance()
-        .get::<_, bool>(&((KEY_INIT,),))
-        .unwrap_or(false)
-    {
-        panic!("not initialized");
+    if !is_initialized(&env) {
+        handle_error(&env, Error::NotInitialized);
     }
 
+    require_owner(&env, &caller);
 
-    let owner: Address = env
-        .storage()
-        .instance()
-        .get(&((KEY_OWNER,),))
-        .expect("owner missing");
-    if caller != owner {
-        panic!("only owner");
+    // Catch copy-paste mistakes and self-references before they break every lookup
+    let self_addr: Address = env.current_contract_address();
+    if user_mgmt_addr == course_registry_addr
+        || user_mgmt_addr == self_addr
+        || course_registry_addr == self_addr
+    {
+        handle_error(&env, Error::InvalidConfig);
     }
 
     let inst: Instance = env.storage().instance();
+    let old_user_mgmt_addr: Option<Address> = inst.get(&(KEY_USER_MGMT_ADDR,));
+    let old_course_registry_addr: Option<Address> = inst.get(&(KEY_COURSE_REG_ADDR,));
     inst.set(&(KEY_USER_MGMT_ADDR,), &user_mgmt_addr);
     inst.set(&(KEY_COURSE_REG_ADDR,), &course_registry_addr);
+    env.events().publish(
+        (CONFIG_CHANGED_EVENT,),
+        (caller, old_user_mgmt_addr, old_course_registry_addr, user_mgmt_addr, course_registry_addr),
+    );
+}
+
+/// Sets or clears the contract notified of new enrollments.
+/// This function can only be called by the contract owner.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment object.
+/// * `caller` - The address of the caller. Must be the current owner of the contract.
+/// * `notify_addr` - The contract to notify, or `None` to stop notifications.
+///
+/// # Panics
+///
+/// * This function will call `handle_error` with `Error::Unauthorized` if the caller is not the owner.
+pub fn set_notify_addr(env: Env, caller: Address, notify_addr: Option<Address>) {
+    caller.require_auth();
+    require_owner(&env, &caller);
+
+    let inst: Instance = env.storage().instance();
+    match notify_addr.clone() {
+        Some(addr) => inst.set(&(KEY_NOTIFY_ADDR,), &addr),
+        None => inst.remove(&(KEY_NOTIFY_ADDR,)),
+    }
+    env.events()
+        .publish((UPDATE_NOTIFY_EVENT,), (caller, notify_addr));
+}
+
+/// Rejects callers other than the contract owner set by `initialize`.
+///
+/// # Panics
+///
+/// * This function will call `handle_error` with `Error::Unauthorized` if the caller is not the owner.
+pub fn require_owner(env: &Env, caller: &Address) {
+    if !is_owner(env, caller) {
+        handle_error(env, Error::Unauthorized);
+    }
+}
+
+/// Returns `true` if `who` is the contract owner set by `initialize`.
+pub fn is_owner(env: &Env, who: &Address) -> bool {
+    let owner: Option<Address> = env.storage().instance().get(&(KEY_OWNER,));
+    owner.as_ref() == Some(who)
+}
+
+/// Returns the contract owner set by `initialize`.
+///
+/// # Panics
+///
+/// * This function will call `handle_error` with `Error::NotInitialized` if the contract has not been initialized.
+pub fn get_owner(env: Env) -> Address {
+    match env.storage().instance().get(&(KEY_OWNER,)) {
+        Some(owner) => owner,
+        None => handle_error(&env, Error::NotInitialized),
+    }
+}
+
+/// Returns `true` once `initialize` has run.
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get::<_, bool>(&(KEY_INIT,))
+        .unwrap_or(false)
+}
+
+/// Marks the contract as decommissioned, ahead of a move to a new deployment.
+/// This function can only be called by the contract owner, and cannot be undone.
+///
+/// Afterwards every write to access records and profiles fails with
+/// `Error::Decommissioned`, while reads keep working.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment object.
+/// * `caller` - The address of the caller. Must be the current owner of the contract.
+///
+/// # Panics
+///
+/// * This function will call `handle_error` with `Error::Unauthorized` if the caller is not the owner.
+pub fn decommission(env: Env, caller: Address) {
+    caller.require_auth();
+    require_owner(&env, &caller);
+
+    env.storage().instance().set(&(KEY_DECOMMISSIONED,), &true);
+    env.events().publish((DECOMMISSION_EVENT,), caller);
+}
+
+/// Returns `true` once `decommission` has run.
+pub fn is_decommissioned(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get::<_, bool>(&(KEY_DECOMMISSIONED,))
+        .unwrap_or(false)
+}
+
+/// Rejects writes once the contract has been decommissioned.
+///
+/// # Panics
+///
+/// * This function will call `handle_error` with `Error::Decommissioned` if `decommission` has run.
+pub fn require_not_decommissioned(env: &Env) {
+    if is_decommissioned(env) {
+        handle_error(env, Error::Decommissioned)
+    }
+}
+
+/// Returns the contract notified of new enrollments, if configured.
+pub fn get_notify_addr(env: Env) -> Option<Address> {
+    env.storage().instance().get(&(KEY_NOTIFY_ADDR,))
+}
+
+/// Sets or clears the token contract that course payments are made in.
+/// This function can only be called by the contract owner.
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment object.
+/// * `caller` - The address of the caller. Must be the current owner of the contract.
+/// * `token` - The token contract, or `None` to disable paid enrollment.
+///
+/// # Panics
+///
+/// * This function will call `handle_error` with `Error::Unauthorized` if the caller is not the owner.
+pub fn set_payment_token(env: Env, caller: Address, token: Option<Address>) {
+    caller.require_auth();
+    require_owner(&env, &caller);
+
+    let inst: Instance = env.storage().instance();
+    match token.clone() {
+        Some(addr) => inst.set(&(KEY_PAYMENT_TOKEN,), &addr),
+        None => inst.remove(&(KEY_PAYMENT_TOKEN,)),
+    }
     env.events()
-        .publish((UPDATE_ADDRESS_EVENT,), (caller, user_mgmt_addr, course_registry_addr));
+        .publish((UPDATE_PAY_TOKEN_EVENT,), (caller, token));
+}
+
+/// Returns the token contract that course payments are made in, if configured.
+pub fn get_payment_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&(KEY_PAYMENT_TOKEN,))
 }
 
 /* /// TTL configuration constants for persistent storage entries
@@ -1,7 +1,13 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracterror, Address, Env, String, Vec, vec};
+use soroban_sdk::{contracterror, symbol_short, Address, BytesN, Env, String, Symbol, Vec, vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::functions::config::{is_owner, require_owner};
+use crate::functions::grant_access::{is_legacy_access, load_access};
+use crate::schema::{CourseUsers, DataKey};
 
 /// Errors that can occur during contract versioning operations
 #[contracterror]
@@ -23,11 +29,33 @@ pub enum VersioningError {
 }
 
 
+/// Storage keys for versioning data
 const VERSION_HISTORY_KEY: &str = "version_history";
-const MIGRATION_STATUS_KEY: &str = "migration_status";
+const LAST_MIGRATED_VERSION_KEY: &str = "last_migrated_version";
+const MIGRATION_IN_PROGRESS_KEY: &str = "migration_in_progress";
+
+const MIGRATION_EVENT: Symbol = symbol_short!("accMigr");
+const MIGRATION_PROGRESS_EVENT: Symbol = symbol_short!("accMigPrg");
+const UPGRADE_EVENT: Symbol = symbol_short!("accUpgrd");
 
+const MIGRATED_STATUS_PREFIX: &[u8] = b"Migrated to ";
 
+/// Get the version history of the contract
+///
+/// After an upgrade the new wasm's version is only known to the new code, so
+/// the running `VERSION` is reported even before it has been stored. It is
+/// stored by the next upgrade.
 pub fn get_version_history(env: &Env) -> Vec<String> {
+    let mut history: Vec<String> = stored_version_history(env);
+    let running: String = String::from_str(env, crate::VERSION);
+    if !history.is_empty() && !history.contains(&running) {
+        history.push_back(running);
+    }
+    history
+}
+
+/// Get the versions stored in the history
+fn stored_version_history(env: &Env) -> Vec<String> {
     let key = String::from_str(env, VERSION_HISTORY_KEY);
     env.storage()
         .instance()
@@ -35,17 +63,18 @@ pub fn get_version_history(env: &Env) -> Vec<String> {
         .unwrap_or_else(|| vec![env])
 }
 
+/// Store a new version in the history
 fn store_version_in_history(env: &Env, version: String) {
-    let mut history: Vec<String> = get_version_history(env);
+    let mut history: Vec<String> = stored_version_history(env);
     history.push_back(version.clone());
     
     let key: String = String::from_str(env, VERSION_HISTORY_KEY);
     env.storage().instance().set(&key, &history);
 }
 
-
+/// Check if a version exists in the history
 fn version_exists_in_history(env: &Env, version: &String) -> bool {
-    let history: Vec<String> = get_version_history(env);
+    let history: Vec<String> = stored_version_history(env);
     for v in history.iter() {
         if &v == version {
             return true;
@@ -54,129 +83,264 @@ fn version_exists_in_history(env: &Env, version: &String) -> bool {
     false
 }
 
+/// Record the running contract version in the history
+///
+/// Called on initialization and before upgrades and migrations; does nothing
+/// if the version is already recorded.
+pub fn record_deployed_version(env: &Env) {
+    let version: String = String::from_str(env, crate::VERSION);
+    if !version_exists_in_history(env, &version) {
+        store_version_in_history(env, version);
+    }
+}
 
+/// Get migration status information
+///
+/// Reports whether a migration is in progress, otherwise the last version
+/// access data was successfully migrated to.
 pub fn get_migration_status(env: &Env) -> String {
-    let key: String = String::from_str(env, MIGRATION_STATUS_KEY);
+    if is_migration_in_progress(env) {
+        return String::from_str(env, "Migration in progress");
+    }
+
+    let key: String = String::from_str(env, LAST_MIGRATED_VERSION_KEY);
+    match env.storage().instance().get::<String, String>(&key) {
+        Some(version) => migrated_status(env, &version),
+        None => String::from_str(env, "No migrations completed"),
+    }
+}
+
+/// Mark a migration as in progress, or as finished. Owner only.
+///
+/// Upgrades are refused while a migration is in progress.
+pub fn set_migration_in_progress(env: &Env, caller: Address, in_progress: bool) {
+    caller.require_auth();
+    require_owner(env, &caller);
+
+    let key: String = String::from_str(env, MIGRATION_IN_PROGRESS_KEY);
+    if in_progress {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+
+    env.events()
+        .publish((MIGRATION_PROGRESS_EVENT, caller), in_progress);
+}
+
+/// Returns `true` if a migration is marked as in progress
+pub fn is_migration_in_progress(env: &Env) -> bool {
     env.storage()
         .instance()
-        .get::<String, String>(&key)
-        .unwrap_or_else(|| String::from_str(env, "No migrations pending"))
+        .get::<String, bool>(&String::from_str(env, MIGRATION_IN_PROGRESS_KEY))
+        .unwrap_or(false)
 }
 
+/// Replace the contract's wasm. Owner only.
+///
+/// Fails with `Error::MigrationInProgress` while a migration is in progress.
+/// The version being replaced is stored in the version history before the
+/// wasm is swapped; the new wasm reports its own version from then on. If
+/// the swap fails, e.g. because the wasm was never uploaded, nothing is
+/// recorded.
+pub fn upgrade(env: &Env, caller: Address, new_wasm_hash: BytesN<32>) {
+    caller.require_auth();
+    require_owner(env, &caller);
+
+    if is_migration_in_progress(env) {
+        handle_error(env, Error::MigrationInProgress)
+    }
 
-fn set_migration_status(env: &Env, status: String) {
-    let key = String::from_str(env, MIGRATION_STATUS_KEY);
-    env.storage().instance().set(&key, &status);
+    record_upgrade(env, &caller, &new_wasm_hash);
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
 }
 
+/// Store the running version in the history and emit the upgrade event
+fn record_upgrade(env: &Env, caller: &Address, new_wasm_hash: &BytesN<32>) {
+    record_deployed_version(env);
 
-pub fn is_version_compatible(_env: &Env, _from_version: String, _to_version: String) -> bool {
-    // Simple compatibility check - for now, assume all versions are compatible
-    // In a real implementation, you would parse semantic versions properly
-    true
+    env.events().publish(
+        (UPGRADE_EVENT, caller.clone()),
+        (new_wasm_hash.clone(), String::from_str(env, crate::VERSION)),
+    );
 }
 
+/// Build the "Migrated to <version>" status message
+fn migrated_status(env: &Env, version: &String) -> String {
+    let prefix_len: usize = MIGRATED_STATUS_PREFIX.len();
+    let version_len: usize = version.len() as usize;
+    let mut buffer: [u8; 64] = [0u8; 64];
+    if prefix_len + version_len > buffer.len() {
+        return String::from_str(env, "Migrated");
+    }
+    buffer[..prefix_len].copy_from_slice(MIGRATED_STATUS_PREFIX);
+    version.copy_into_slice(&mut buffer[prefix_len..prefix_len + version_len]);
+    String::from_bytes(env, &buffer[..prefix_len + version_len])
+}
 
-fn is_authorized_for_migration(_env: &Env, _caller: Address) -> bool {
-    // For now, we'll allow any authenticated user
-    // In a real implementation, you would check against user management contract
-    // or implement your own authorization logic
-    
-    // You could call the user management contract to check if the caller is admin
-    // let user_mgmt_addr = get_user_mgmt_addr(env);
-    // let client = UserManagementClient::new(env, &user_mgmt_addr);
-    // client.is_admin(&caller)
-    
-    true // Placeholder - allow all authenticated users
+/// Check compatibility between two versions
+///
+/// Versions are compatible when both are valid `MAJOR.MINOR.PATCH` strings
+/// sharing the same major version, the same rule the course registry uses.
+pub fn is_version_compatible(_env: &Env, from_version: String, to_version: String) -> bool {
+    match (parse_version(&from_version), parse_version(&to_version)) {
+        (Some((from_major, _, _)), Some((to_major, _, _))) => from_major == to_major,
+        _ => false,
+    }
 }
 
+/// Parse a `MAJOR.MINOR.PATCH` version string into its components
+fn parse_version(version: &String) -> Option<(u32, u32, u32)> {
+    let len: usize = version.len() as usize;
+    let mut buffer: [u8; 32] = [0u8; 32];
+    if len == 0 || len > buffer.len() {
+        return None;
+    }
+    version.copy_into_slice(&mut buffer[..len]);
+
+    let mut parts: [u32; 3] = [0; 3];
+    let mut dots: usize = 0;
+    let mut digits_in_part: u32 = 0;
+    for byte in buffer[..len].iter() {
+        match *byte {
+            b'0'..=b'9' => {
+                parts[dots] = parts[dots].checked_mul(10)?.checked_add((*byte - b'0') as u32)?;
+                digits_in_part += 1;
+            }
+            b'.' => {
+                if digits_in_part == 0 || dots == 2 {
+                    return None;
+                }
+                dots += 1;
+                digits_in_part = 0;
+            }
+            _ => return None,
+        }
+    }
+
+    if dots != 2 || digits_in_part == 0 {
+        return None;
+    }
+    Some((parts[0], parts[1], parts[2]))
+}
+
+
+/// Only the contract owner and admins may migrate access data
+fn is_authorized_for_migration(env: &Env, caller: &Address) -> bool {
+    is_owner(env, caller) || is_admin(env, caller)
+}
+
+/// Migrate access data between contract versions
+///
+/// Requires the caller's authorization and fails with `Unauthorized` unless
+/// the caller is the owner or an admin. Panics with `InvalidVersion` if
+/// either version is not a valid `MAJOR.MINOR.PATCH` string. Returns `false` if `from_version` is not in
+/// the version history or the versions are not compatible.
 pub fn migrate_access_data(
     env: &Env,
     caller: Address,
     from_version: String,
     to_version: String,
 ) -> bool {
+    caller.require_auth();
 
-    if !is_authorized_for_migration(env, caller.clone()) {
-        set_migration_status(env, String::from_str(env, "Migration failed: Unauthorized"));
-        return false;
+    if !is_authorized_for_migration(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if parse_version(&from_version).is_none() || parse_version(&to_version).is_none() {
+        handle_error(env, Error::InvalidVersion)
     }
-    
 
-    if !version_exists_in_history(env, &from_version) {
-        set_migration_status(env, String::from_str(env, "Migration failed: Source version not found"));
+    // Contracts initialized before deployments were recorded have no
+    // history to check against
+    let first_migration: bool = stored_version_history(env).is_empty();
+    if !first_migration && !version_exists_in_history(env, &from_version) {
         return false;
     }
-    
 
     if !is_version_compatible(env, from_version.clone(), to_version.clone()) {
-        set_migration_status(env, String::from_str(env, "Migration failed: Versions not compatible"));
         return false;
     }
-    
 
     let migration_result: bool = perform_access_data_migration(env, &from_version, &to_version);
     
     if migration_result {
         // Update version history with new version
-        store_version_in_history(env, to_version.clone());
-        
-        // Set successful migration status
-        let status: String = String::from_str(env, "Migration completed successfully");
-        set_migration_status(env, status);
-        
-        // Emit migration event
-        emit_migration_event(env, &from_version, &to_version, true);
+        if first_migration {
+            store_version_in_history(env, from_version.clone());
+        }
+        if !version_exists_in_history(env, &to_version) {
+            store_version_in_history(env, to_version.clone());
+        }
+
+        let key: String = String::from_str(env, LAST_MIGRATED_VERSION_KEY);
+        env.storage().instance().set(&key, &to_version);
+
+        emit_migration_event(env, &caller, &from_version, &to_version, true);
         
         true
     } else {
-        set_migration_status(env, String::from_str(env, "Migration failed: Data transformation error"));
-        emit_migration_event(env, &from_version, &to_version, false);
+        emit_migration_event(env, &caller, &from_version, &to_version, false);
         false
     }
 }
 
 /// Perform the actual access data migration between versions
 fn perform_access_data_migration(env: &Env, _from_version: &String, _to_version: &String) -> bool {
-    // This is a placeholder for actual access data migration logic
-    // In a real implementation, this would:
-    // 1. Read existing access control data structures
-    // 2. Transform them according to the new version schema
-    // 3. Write the transformed data back to storage
-    
-    // For now, we'll simulate a successful migration
-    migrate_access_v1_0_0_to_v1_1_0(env)
-}
-
-/// Migrate access data from version 1.0.0 to 1.1.0
-fn migrate_access_v1_0_0_to_v1_1_0(_env: &Env) -> bool {
-    // Placeholder for access migration logic
-    // This would typically involve:
-    // 1. Reading existing course access data
-    // 2. Adding new fields with default values (e.g., access levels, timestamps)
-    // 3. Saving updated access data
-    
-    // For now, return true to indicate successful migration
+    migrate_access_v1_0_0_to_v1_1_0(env);
     true
 }
 
+/// Rewrite access entries stored in the 1.0.0 layout in the current layout
+///
+/// Only entries of courses in the `AccessCourses` index can be enumerated.
+/// Unindexed legacy entries stay readable through `load_access` and are
+/// rewritten the next time they are updated.
+///
+/// Returns the number of entries rewritten.
+fn migrate_access_v1_0_0_to_v1_1_0(env: &Env) -> u32 {
+    let courses: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AccessCourses)
+        .unwrap_or(Vec::new(env));
+
+    let mut migrated: u32 = 0;
+    for course_id in courses.iter() {
+        let users: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CourseUsers>(&DataKey::CourseUsers(course_id.clone()))
+            .map(|course_users| course_users.users)
+            .unwrap_or(Vec::new(env));
+
+        for user in users.iter() {
+            let key: DataKey = DataKey::CourseAccess(course_id.clone(), user);
+            if !is_legacy_access(env, &key) {
+                continue;
+            }
+            if let Some(access) = load_access(env, &key) {
+                env.storage().persistent().set(&key, &access);
+                migrated += 1;
+            }
+        }
+    }
+    migrated
+}
 
 /// Emit a migration event
-fn emit_migration_event(_env: &Env, _from_version: &String, _to_version: &String, _success: bool) {
-    // In a real implementation, you would emit events here
-    // For now, we'll just set a status message
-    
-    let _event_type = if _success { "success" } else { "failure" };
-    // In a real implementation, you would emit actual events here
-    // For now, we'll just store a simple status message
-    
-    // You could emit actual events here using env.events()
-    // env.events().publish(("access_migration", event_type), (from_version, to_version, success));
+fn emit_migration_event(env: &Env, caller: &Address, from_version: &String, to_version: &String, success: bool) {
+    env.events().publish(
+        (MIGRATION_EVENT, caller.clone()),
+        (from_version.clone(), to_version.clone(), success),
+    );
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use soroban_sdk::testutils::Address as _;
 
     #[test]
     fn test_version_history() {
@@ -194,23 +358,145 @@ mod test {
     fn test_version_compatibility() {
         let env = Env::default();
         
-        // All versions are compatible in our simplified implementation
+        // Same major version is compatible
         assert!(is_version_compatible(&env, 
             String::from_str(&env, "1.0.0"), 
             String::from_str(&env, "1.1.0")));
         
-        // All versions are compatible in our simplified implementation
-        assert!(is_version_compatible(&env, 
+        // A major version bump is not compatible
+        assert!(!is_version_compatible(&env, 
             String::from_str(&env, "1.0.0"), 
             String::from_str(&env, "2.0.0")));
+
+        // Malformed versions are never compatible
+        assert!(!is_version_compatible(&env, 
+            String::from_str(&env, "1.0"), 
+            String::from_str(&env, "1.0.0")));
+        assert!(!is_version_compatible(&env, 
+            String::from_str(&env, "v1.0.0"), 
+            String::from_str(&env, "1.0.0")));
+    }
+
+    #[test]
+    fn test_version_history_records_deployment_and_migration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, admin) = setup_owned_contract(&env);
+
+        assert_eq!(client.get_version_history(), vec![&env, String::from_str(&env, crate::VERSION)]);
+        assert_eq!(client.get_migration_status(), String::from_str(&env, "No migrations completed"));
+
+        assert!(client.migrate_access_data(&admin, &String::from_str(&env, "1.0.0"), &String::from_str(&env, "1.1.0")));
+        assert_eq!(
+            client.get_version_history(),
+            vec![&env, String::from_str(&env, "1.0.0"), String::from_str(&env, "1.1.0")]
+        );
+        assert_eq!(client.get_migration_status(), String::from_str(&env, "Migrated to 1.1.0"));
     }
 
     #[test]
-    fn test_migration_authorization() {
+    fn test_migrate_access_data_rejects_major_bump_and_unknown_source() {
         let env = Env::default();
+        env.mock_all_auths();
+        let (client, admin) = setup_owned_contract(&env);
+
+        assert!(!client.migrate_access_data(&admin, &String::from_str(&env, "1.0.0"), &String::from_str(&env, "2.0.0")));
+        assert!(!client.migrate_access_data(&admin, &String::from_str(&env, "1.5.0"), &String::from_str(&env, "1.6.0")));
+        assert_eq!(client.get_migration_status(), String::from_str(&env, "No migrations completed"));
+        assert_eq!(client.get_version_history().len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #38)")]
+    fn test_migrate_access_data_rejects_malformed_version() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, owner) = setup_owned_contract(&env);
+
+        client.migrate_access_data(&owner, &String::from_str(&env, "1.0.0"), &String::from_str(&env, "1.x"));
+    }
+
+    fn setup_owned_contract(env: &Env) -> (crate::CourseAccessContractClient<'static>, Address) {
         let contract_id = env.register(crate::CourseAccessContract, ());
-        
-        // For now, all users are authorized (placeholder implementation)
-        assert!(is_authorized_for_migration(&env, contract_id));
+        let client = crate::CourseAccessContractClient::new(env, &contract_id);
+        let owner = Address::generate(env);
+        client.initialize(&owner, &Address::generate(env), &Address::generate(env));
+        (client, owner)
+    }
+
+    #[test]
+    fn test_upgrade_requires_owner() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, _) = setup_owned_contract(&env);
+
+        let result = client.try_upgrade(&Address::generate(&env), &BytesN::from_array(&env, &[1; 32]));
+        assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(3));
+    }
+
+    #[test]
+    fn test_upgrade_blocked_while_migration_in_progress() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, owner) = setup_owned_contract(&env);
+
+        client.set_migration_in_progress(&owner, &true);
+        assert_eq!(client.get_migration_status(), String::from_str(&env, "Migration in progress"));
+
+        let result = client.try_upgrade(&owner, &BytesN::from_array(&env, &[1; 32]));
+        assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(47));
+
+        client.set_migration_in_progress(&owner, &false);
+        assert_eq!(client.get_migration_status(), String::from_str(&env, "No migrations completed"));
+    }
+
+    #[test]
+    fn test_upgrade_to_unknown_wasm_records_nothing() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, owner) = setup_owned_contract(&env);
+
+        // No wasm with this hash was uploaded, so the swap fails
+        let result = client.try_upgrade(&owner, &BytesN::from_array(&env, &[1; 32]));
+        assert!(result.is_err());
+        assert_eq!(client.get_version_history(), vec![&env, String::from_str(&env, crate::VERSION)]);
+    }
+
+    #[test]
+    fn test_record_upgrade_appends_version_history() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, owner) = setup_owned_contract(&env);
+        let hash: BytesN<32> = BytesN::from_array(&env, &[7; 32]);
+
+        let previous: String = String::from_str(&env, "0.9.0");
+        let running: String = String::from_str(&env, crate::VERSION);
+
+        // As left behind by an older wasm that was upgraded to this one
+        env.as_contract(&client.address, || {
+            let key: String = String::from_str(&env, VERSION_HISTORY_KEY);
+            env.storage().instance().set(&key, &vec![&env, previous.clone()]);
+        });
+
+        // The running version is reported before it is stored
+        assert_eq!(client.get_version_history(), vec![&env, previous.clone(), running.clone()]);
+
+        env.as_contract(&client.address, || {
+            record_upgrade(&env, &owner, &hash);
+            record_upgrade(&env, &owner, &hash);
+            assert_eq!(stored_version_history(&env), vec![&env, previous.clone(), running.clone()]);
+        });
+        assert_eq!(client.get_version_history(), vec![&env, previous, running]);
+    }
+
+    #[test]
+    fn test_migrate_access_data_requires_caller_auth() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, owner) = setup_owned_contract(&env);
+        env.set_auths(&[]);
+
+        let result = client.try_migrate_access_data(&owner, &String::from_str(&env, "1.0.0"), &String::from_str(&env, "1.1.0"));
+        assert!(result.is_err());
     }
 }
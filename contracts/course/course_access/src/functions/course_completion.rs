@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::enrollment_history::was_ever_enrolled;
+use crate::functions::grant_access::validate_course_id;
+use crate::schema::{Completion, DataKey, MAX_COMPLETION_HASH_LENGTH};
+
+const COURSE_COMPLETED_EVENT: Symbol = symbol_short!("crsComplt");
+
+/// Default page size for completion listings
+const DEFAULT_COMPLETIONS_LIMIT: u32 = 10;
+
+/// Largest page size for completion listings
+const MAX_COMPLETIONS_LIMIT: u32 = 100;
+
+/// Attest that a student completed a course
+///
+/// The student must have, or have had, access to the course. A completion
+/// is recorded once and cannot be overwritten.
+/// The caller must be an admin or the creator of the course.
+pub fn mark_course_completed(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    student: Address,
+    completion_hash: String,
+) -> Completion {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !is_admin(&env, &caller) && !is_course_creator(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    if completion_hash.is_empty() || completion_hash.len() > MAX_COMPLETION_HASH_LENGTH {
+        handle_error(&env, Error::InvalidHash)
+    }
+
+    // Every grant leaves an enrollment record, even after revocation
+    if !was_ever_enrolled(env.clone(), course_id.clone(), student.clone()) {
+        handle_error(&env, Error::UserNoAccessCourse)
+    }
+
+    let key: DataKey = DataKey::Completion(course_id.clone(), student.clone());
+    if env.storage().persistent().has(&key) {
+        handle_error(&env, Error::CourseAlreadyCompleted)
+    }
+
+    let completion: Completion = Completion {
+        course_id,
+        student: student.clone(),
+        completion_hash,
+        completed_at: env.ledger().timestamp(),
+        attested_by: caller,
+    };
+    env.storage().persistent().set(&key, &completion);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    let course_key: DataKey = DataKey::CourseCompletions(completion.course_id.clone());
+    let mut students: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&course_key)
+        .unwrap_or(Vec::new(&env));
+    students.push_back(student.clone());
+    env.storage().persistent().set(&course_key, &students);
+    env.storage().persistent().extend_ttl(&course_key, 100, 1000);
+
+    let user_key: DataKey = DataKey::UserCompletions(student.clone());
+    let mut courses: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&user_key)
+        .unwrap_or(Vec::new(&env));
+    courses.push_back(completion.course_id.clone());
+    env.storage().persistent().set(&user_key, &courses);
+    env.storage().persistent().extend_ttl(&user_key, 100, 1000);
+
+    env.events()
+        .publish((COURSE_COMPLETED_EVENT, student), completion.clone());
+
+    completion
+}
+
+/// Get the completion record of a student for a course, if any
+pub fn get_completion(env: Env, course_id: String, student: Address) -> Option<Completion> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Completion(course_id, student))
+}
+
+/// Check whether a student has completed a course
+pub fn has_completed(env: Env, course_id: String, student: Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Completion(course_id, student))
+}
+
+/// List the completions of a course, oldest first
+///
+/// Never panics: `limit` defaults to 10 and is capped at 100, and an
+/// out-of-range `offset` returns an empty list.
+pub fn list_completions_for_course(
+    env: Env,
+    course_id: String,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> Vec<Completion> {
+    let limit_value: u32 = limit
+        .unwrap_or(DEFAULT_COMPLETIONS_LIMIT)
+        .min(MAX_COMPLETIONS_LIMIT);
+    let offset_value: u32 = offset.unwrap_or(0);
+
+    let students: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseCompletions(course_id.clone()))
+        .unwrap_or(Vec::new(&env));
+
+    let mut results: Vec<Completion> = Vec::new(&env);
+    let end: u32 = offset_value.saturating_add(limit_value).min(students.len());
+    for index in offset_value..end {
+        let student: Address = students.get_unchecked(index);
+        if let Some(completion) = get_completion(env.clone(), course_id.clone(), student) {
+            results.push_back(completion);
+        }
+    }
+    results
+}
+
+/// List every completion of a student, oldest first
+pub fn list_user_completions(env: Env, student: Address) -> Vec<Completion> {
+    let courses: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UserCompletions(student.clone()))
+        .unwrap_or(Vec::new(&env));
+
+    let mut results: Vec<Completion> = Vec::new(&env);
+    for course_id in courses.iter() {
+        if let Some(completion) = get_completion(env.clone(), course_id, student.clone()) {
+            results.push_back(completion);
+        }
+    }
+    results
+}
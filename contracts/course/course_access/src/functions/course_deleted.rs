@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::config::require_not_decommissioned;
+use crate::functions::grant_access::validate_course_id;
+use crate::functions::revoke_all_access::revoke_course_roster;
+use crate::schema::{DataKey, KEY_COURSE_REG_ADDR};
+
+const COURSE_DELETED_EVENT: Symbol = symbol_short!("crsDelete");
+
+/// Clean up the access records of a course deleted in the registry
+///
+/// Revokes every user's access and removes the course roster. Only the
+/// configured course registry contract may call this.
+pub fn handle_course_deleted(env: Env, caller: Address, course_id: String) -> u32 {
+    caller.require_auth();
+
+    let course_registry_addr: Address = match env.storage().instance().get(&(KEY_COURSE_REG_ADDR,)) {
+        Some(addr) => addr,
+        None => handle_error(&env, Error::NotInitialized),
+    };
+    if caller != course_registry_addr {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    clean_up_deleted_course(&env, caller, course_id)
+}
+
+/// Clean up the access records of a course deleted in the registry
+///
+/// Same as `handle_course_deleted`, but the registry is authenticated as the
+/// direct invoker instead of being passed in, so only a cross-contract call
+/// from the configured course registry contract is accepted.
+pub fn on_course_deleted(env: Env, course_id: String) -> u32 {
+    let course_registry_addr: Address = match env.storage().instance().get(&(KEY_COURSE_REG_ADDR,)) {
+        Some(addr) => addr,
+        None => handle_error(&env, Error::NotInitialized),
+    };
+    course_registry_addr.require_auth();
+
+    clean_up_deleted_course(&env, course_registry_addr, course_id)
+}
+
+/// Revoke all access to a course and remove its roster
+fn clean_up_deleted_course(env: &Env, registry: Address, course_id: String) -> u32 {
+    require_not_decommissioned(env);
+    validate_course_id(env, &course_id);
+
+    let count: u32 = revoke_course_roster(env, &course_id, Some(registry), None);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseUsers(course_id.clone()));
+
+    env.events()
+        .publish((COURSE_DELETED_EVENT, course_id), (count, next_access_event_seq(env)));
+
+    count
+}
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Symbol, Vec, symbol_short};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::functions::config::require_not_decommissioned;
+use crate::schema::DataKey;
+
+const DELETE_USER_PROFILE_EVENT: Symbol = symbol_short!("delUsPrl");
+
+/// Delete the profile stored for `user`
+///
+/// The user may delete their own profile; admins may delete any profile.
+/// The emitted event records which address performed the deletion.
+pub fn delete_user_profile(env: Env, caller: Address, user: Address) {
+    caller.require_auth();
+    require_not_decommissioned(&env);
+
+    if caller != user && !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let key: DataKey = DataKey::UserProfile(user.clone());
+    if !env.storage().persistent().has(&key) {
+        handle_error(&env, Error::UserProfileNotFound)
+    }
+
+    env.storage().persistent().remove(&key);
+    remove_profile_user(&env, &user);
+
+    env.events()
+        .publish((DELETE_USER_PROFILE_EVENT, user), caller);
+}
+
+/// Remove a user from the list of users with a saved profile
+fn remove_profile_user(env: &Env, user: &Address) {
+    let mut users: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ProfileUsers)
+        .unwrap_or(Vec::new(env));
+    if let Some(index) = users.first_index_of(user) {
+        users.remove(index);
+        env.storage().persistent().set(&DataKey::ProfileUsers, &users);
+        env.storage().persistent().extend_ttl(&DataKey::ProfileUsers, 100, 1000);
+    }
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::enrollment_history::validate_reason_hash;
+use crate::functions::grant_access::validate_course_id;
+use crate::schema::{DataKey, DenylistEntry, MAX_DENYLIST_SIZE};
+
+const DENYLIST_ADD_EVENT: Symbol = symbol_short!("denyAdd");
+const DENYLIST_REMOVE_EVENT: Symbol = symbol_short!("denyRm");
+
+/// Block a user from being granted access to a course
+///
+/// Every grant path and transfers into the course fail with
+/// `Error::UserDenylisted` until the user is removed again. Access the user
+/// already has is left alone; revoke it separately if needed.
+/// Adding a denylisted user again only replaces the reason.
+/// The caller must be an admin or the creator of the course.
+pub fn add_to_denylist(env: Env, caller: Address, course_id: String, user: Address, reason_hash: Option<String>) {
+    require_denylist_manager(&env, &caller, &course_id);
+    validate_reason_hash(&env, &reason_hash);
+
+    let list_key: DataKey = DataKey::CourseDenylist(course_id.clone());
+    let mut users: Vec<Address> = get_denylist(env.clone(), course_id.clone());
+    if !users.contains(&user) {
+        if users.len() >= MAX_DENYLIST_SIZE {
+            handle_error(&env, Error::DenylistFull)
+        }
+        users.push_back(user.clone());
+        env.storage().persistent().set(&list_key, &users);
+        env.storage().persistent().extend_ttl(&list_key, 100, 1000);
+    }
+
+    let entry_key: DataKey = DataKey::Denylisted(course_id.clone(), user.clone());
+    env.storage().persistent().set(
+        &entry_key,
+        &DenylistEntry {
+            reason_hash,
+            added_by: caller.clone(),
+            added_at: env.ledger().timestamp(),
+        },
+    );
+    env.storage().persistent().extend_ttl(&entry_key, 100, 1000);
+
+    env.events()
+        .publish((DENYLIST_ADD_EVENT, course_id, user), caller);
+}
+
+/// Allow a denylisted user to be granted access to a course again
+///
+/// Returns `false` if the user was not denylisted.
+/// The caller must be an admin or the creator of the course.
+pub fn remove_from_denylist(env: Env, caller: Address, course_id: String, user: Address) -> bool {
+    require_denylist_manager(&env, &caller, &course_id);
+
+    let entry_key: DataKey = DataKey::Denylisted(course_id.clone(), user.clone());
+    if !env.storage().persistent().has(&entry_key) {
+        return false;
+    }
+    env.storage().persistent().remove(&entry_key);
+
+    let list_key: DataKey = DataKey::CourseDenylist(course_id.clone());
+    let mut users: Vec<Address> = get_denylist(env.clone(), course_id.clone());
+    if let Some(index) = users.first_index_of(&user) {
+        users.remove(index);
+        env.storage().persistent().set(&list_key, &users);
+    }
+
+    env.events()
+        .publish((DENYLIST_REMOVE_EVENT, course_id, user), caller);
+    true
+}
+
+/// Returns `true` if the user is denylisted for the course
+pub fn is_denylisted(env: &Env, course_id: &String, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::Denylisted(course_id.clone(), user.clone()))
+}
+
+/// Get the denylist entry of a user for a course, if any
+pub fn get_denylist_entry(env: Env, course_id: String, user: Address) -> Option<DenylistEntry> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Denylisted(course_id, user))
+}
+
+/// List the denylisted users of a course, at most `MAX_DENYLIST_SIZE`
+pub fn get_denylist(env: Env, course_id: String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseDenylist(course_id))
+        .unwrap_or(Vec::new(&env))
+}
+
+/// Fail with `Error::UserDenylisted` if the user is denylisted for the course
+pub fn require_not_denylisted(env: &Env, course_id: &String, user: &Address) {
+    if is_denylisted(env, course_id, user) {
+        handle_error(env, Error::UserDenylisted)
+    }
+}
+
+fn require_denylist_manager(env: &Env, caller: &Address, course_id: &String) {
+    caller.require_auth();
+    validate_course_id(env, course_id);
+
+    if !is_admin(env, caller) && !is_course_creator(env, course_id, caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String};
+
+use crate::schema::{
+    AccessCounters, AccessStats, CourseAccessStats, CourseUsers, DataKey, UserCourses, KEY_ACCESS_COUNTERS,
+};
+
+/// Returns the number of courses the user has access to, or 0 for an
+/// unknown user.
+pub fn get_user_course_count(env: &Env, user: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get::<DataKey, UserCourses>(&DataKey::UserCourses(user.clone()))
+        .map(|user_courses| user_courses.courses.len())
+        .unwrap_or(0)
+}
+
+/// Returns the number of access entries currently stored across all courses.
+///
+/// Expired entries are counted until they are purged or replaced.
+pub fn get_total_enrollments(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TotalEnrollments)
+        .unwrap_or(0)
+}
+
+/// Count a newly created access entry.
+pub fn increment_total_enrollments(env: &Env) {
+    set_total_enrollments(env, get_total_enrollments(env).saturating_add(1));
+}
+
+/// Stop counting `removed` access entries, never going below zero.
+pub fn decrement_total_enrollments(env: &Env, removed: u64) {
+    if removed > 0 {
+        set_total_enrollments(env, get_total_enrollments(env).saturating_sub(removed));
+    }
+}
+
+fn set_total_enrollments(env: &Env, total: u64) {
+    env.storage().persistent().set(&DataKey::TotalEnrollments, &total);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::TotalEnrollments, 100, 1000);
+}
+
+/// Returns platform-wide access statistics.
+pub fn get_platform_access_stats(env: &Env) -> AccessStats {
+    let counters: AccessCounters = env
+        .storage()
+        .instance()
+        .get(&(KEY_ACCESS_COUNTERS,))
+        .unwrap_or_default();
+
+    AccessStats {
+        active: get_total_enrollments(env),
+        total_granted: counters.granted,
+        total_revoked: counters.revoked,
+    }
+}
+
+/// Returns the access statistics of a course; all zero for an unknown course.
+pub fn get_course_access_stats(env: &Env, course_id: &String) -> CourseAccessStats {
+    let counters: AccessCounters = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseAccessCounters(course_id.clone()))
+        .unwrap_or_default();
+    let active: u32 = env
+        .storage()
+        .persistent()
+        .get::<DataKey, CourseUsers>(&DataKey::CourseUsers(course_id.clone()))
+        .map(|course_users| course_users.users.len())
+        .unwrap_or(0);
+
+    CourseAccessStats {
+        course_id: course_id.clone(),
+        active,
+        total_granted: counters.granted,
+        total_revoked: counters.revoked,
+    }
+}
+
+/// Count an access entry written for a course.
+///
+/// `replaced` is set when the entry overwrote an existing one, which then
+/// counts as ended.
+pub fn count_access_granted(env: &Env, course_id: &String, replaced: bool) {
+    let revoked: u64 = if replaced { 1 } else { 0 };
+    update_access_counters(env, course_id, 1, revoked);
+}
+
+/// Count `removed` access entries of a course as ended.
+pub fn count_access_revoked(env: &Env, course_id: &String, removed: u64) {
+    if removed > 0 {
+        update_access_counters(env, course_id, 0, removed);
+    }
+}
+
+fn update_access_counters(env: &Env, course_id: &String, granted: u64, revoked: u64) {
+    let mut platform: AccessCounters = env
+        .storage()
+        .instance()
+        .get(&(KEY_ACCESS_COUNTERS,))
+        .unwrap_or_default();
+    platform.granted = platform.granted.saturating_add(granted);
+    platform.revoked = platform.revoked.saturating_add(revoked);
+    env.storage().instance().set(&(KEY_ACCESS_COUNTERS,), &platform);
+
+    let key: DataKey = DataKey::CourseAccessCounters(course_id.clone());
+    let mut course: AccessCounters = env.storage().persistent().get(&key).unwrap_or_default();
+    course.granted = course.granted.saturating_add(granted);
+    course.revoked = course.revoked.saturating_add(revoked);
+    env.storage().persistent().set(&key, &course);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+}
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{DataKey, EnrollmentRecord, RevokeReason, MAX_ENROLLMENT_HISTORY, MAX_REASON_HASH_LENGTH};
+
+/// Returns every enrollment period recorded for a user in a course, oldest first.
+///
+/// The history is kept even after access is revoked, so it can be used
+/// for refund disputes and certificate audits.
+pub fn get_enrollment_history(env: Env, course_id: String, user: Address) -> Vec<EnrollmentRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EnrollmentHistory(course_id, user))
+        .unwrap_or(Vec::new(&env))
+}
+
+/// Returns `true` if the user has been granted access to the course at least once,
+/// regardless of whether that access is still active.
+pub fn was_ever_enrolled(env: Env, course_id: String, user: Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::EnrollmentHistory(course_id, user))
+}
+
+/// Appends a new open enrollment record for the (course, user) pair.
+pub fn record_grant(env: &Env, course_id: &String, user: &Address, enrollment_id: u64) {
+    let key: DataKey = DataKey::EnrollmentHistory(course_id.clone(), user.clone());
+    let mut history: Vec<EnrollmentRecord> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+
+    if history.len() >= MAX_ENROLLMENT_HISTORY {
+        history.pop_front();
+    }
+
+    history.push_back(EnrollmentRecord {
+        course_id: course_id.clone(),
+        user: user.clone(),
+        granted_at: env.ledger().timestamp(),
+        revoked_at: None,
+        revoked_by: None,
+        revocation_reason: None,
+        revoke_reason: RevokeReason::Unspecified,
+        enrollment_id,
+    });
+
+    env.storage().persistent().set(&key, &history);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+}
+
+/// Rejects an empty or overly long revocation reason hash.
+pub fn validate_reason_hash(env: &Env, reason_hash: &Option<String>) {
+    if let Some(reason_hash) = reason_hash {
+        if reason_hash.is_empty() || reason_hash.len() > MAX_REASON_HASH_LENGTH {
+            handle_error(env, Error::InvalidHash)
+        }
+    }
+}
+
+/// Closes the most recent open enrollment record for the (course, user) pair.
+///
+/// Does nothing if there is no open record.
+pub fn record_revocation(
+    env: &Env,
+    course_id: &String,
+    user: &Address,
+    revoked_by: Option<Address>,
+    reason: RevokeReason,
+    reason_hash: Option<String>,
+) {
+    let key: DataKey = DataKey::EnrollmentHistory(course_id.clone(), user.clone());
+    let mut history: Vec<EnrollmentRecord> = match env.storage().persistent().get(&key) {
+        Some(history) => history,
+        None => return,
+    };
+
+    let last_index: u32 = match history.len().checked_sub(1) {
+        Some(index) => index,
+        None => return,
+    };
+
+    let mut record: EnrollmentRecord = history.get(last_index).unwrap();
+    if record.revoked_at.is_some() {
+        return;
+    }
+
+    record.revoked_at = Some(env.ledger().timestamp());
+    record.revoked_by = revoked_by;
+    record.revoke_reason = reason;
+    record.revocation_reason = reason_hash;
+    history.set(last_index, record);
+
+    env.storage().persistent().set(&key, &history);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+}
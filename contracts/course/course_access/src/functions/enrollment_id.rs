@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String};
+
+use crate::schema::{CourseAccess, DataKey};
+use crate::functions::grant_access::load_access;
+
+/// Allocates the next enrollment ID and indexes it to the (course, user) pair.
+///
+/// IDs start at 1 and are never reused, so every grant gets a stable handle
+/// even when the same user is granted the same course again.
+pub fn assign_enrollment_id(env: &Env, course_id: &String, user: &Address) -> u64 {
+    let id: u64 = env
+        .storage()
+        .persistent()
+        .get::<DataKey, u64>(&DataKey::EnrollmentSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage().persistent().set(&DataKey::EnrollmentSeq, &id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::EnrollmentSeq, 100, 1000);
+
+    let index_key: DataKey = DataKey::EnrollmentIndex(id);
+    env.storage()
+        .persistent()
+        .set(&index_key, &(course_id.clone(), user.clone()));
+    env.storage().persistent().extend_ttl(&index_key, 100, 1000);
+
+    id
+}
+
+/// Indexes a restored enrollment ID to the (course, user) pair.
+///
+/// Keeps `id` unless it is unknown (0) or already indexed to another pair,
+/// in which case a fresh ID is assigned. The sequence is moved past `id` so
+/// later grants never reuse it. Returns the ID the entry is stored under.
+pub fn restore_enrollment_id(env: &Env, course_id: &String, user: &Address, id: u64) -> u64 {
+    let index_key: DataKey = DataKey::EnrollmentIndex(id);
+    let indexed: Option<(String, Address)> = env.storage().persistent().get(&index_key);
+    if id == 0 || indexed.is_some_and(|pair| pair != (course_id.clone(), user.clone())) {
+        return assign_enrollment_id(env, course_id, user);
+    }
+
+    let seq: u64 = env
+        .storage()
+        .persistent()
+        .get::<DataKey, u64>(&DataKey::EnrollmentSeq)
+        .unwrap_or(0);
+    if id > seq {
+        env.storage().persistent().set(&DataKey::EnrollmentSeq, &id);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::EnrollmentSeq, 100, 1000);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&index_key, &(course_id.clone(), user.clone()));
+    env.storage().persistent().extend_ttl(&index_key, 100, 1000);
+
+    id
+}
+
+/// Returns the access record created by the grant with this enrollment ID.
+///
+/// Returns `None` once that enrollment has ended, i.e. the access was
+/// revoked, purged, transferred away or replaced by a newer grant.
+pub fn get_access_by_enrollment_id(env: &Env, enrollment_id: u64) -> Option<CourseAccess> {
+    let (course_id, user): (String, Address) = env
+        .storage()
+        .persistent()
+        .get(&DataKey::EnrollmentIndex(enrollment_id))?;
+
+    load_access(env, &DataKey::CourseAccess(course_id, user))
+        .filter(|access| access.enrollment_id == enrollment_id)
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::schema::{DataKey, UserCourses, KEY_USER_COURSE_LIMIT};
+
+const ENROLLMENT_LIMIT_EVENT: Symbol = symbol_short!("enrlLimit");
+
+/// Set the maximum number of courses a user can be enrolled in
+///
+/// A `limit` of 0 removes the cap. The caller must be an admin.
+pub fn set_user_enrollment_limit(env: Env, caller: Address, limit: u32) {
+    caller.require_auth();
+
+    if !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let old_limit: u32 = get_user_enrollment_limit(env.clone());
+    env.storage().instance().set(&(KEY_USER_COURSE_LIMIT,), &limit);
+
+    env.events()
+        .publish((ENROLLMENT_LIMIT_EVENT,), (caller, old_limit, limit));
+}
+
+/// The maximum number of courses per user, or 0 if unlimited
+pub fn get_user_enrollment_limit(env: Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&(KEY_USER_COURSE_LIMIT,))
+        .unwrap_or(0)
+}
+
+/// Fail with `Error::UserEnrollmentLimitReached` if granting the course would
+/// take the user over the enrollment limit
+pub fn check_user_enrollment_limit(env: &Env, course_id: &String, user: &Address) {
+    if is_over_user_enrollment_limit(env, course_id, user) {
+        handle_error(env, Error::UserEnrollmentLimitReached)
+    }
+}
+
+/// Returns `true` if granting the course would take the user over the enrollment limit
+///
+/// Every course in the user's list counts, including expired and suspended
+/// access. Re-granting a course already in the list never adds to it.
+pub fn is_over_user_enrollment_limit(env: &Env, course_id: &String, user: &Address) -> bool {
+    let limit: u32 = get_user_enrollment_limit(env.clone());
+    if limit == 0 {
+        return false;
+    }
+
+    let user_courses: Option<UserCourses> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UserCourses(user.clone()));
+    user_courses.is_some_and(|user_courses| {
+        !user_courses.courses.contains(course_id) && user_courses.courses.len() >= limit
+    })
+}
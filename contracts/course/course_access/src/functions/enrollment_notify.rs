@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, IntoVal, String, Symbol};
+
+use crate::schema::KEY_NOTIFY_ADDR;
+
+/// Call `on_enrollment(course_id, user)` on the configured notification contract
+///
+/// Best effort: failures of the receiver are swallowed so the enrollment
+/// itself always succeeds.
+pub fn notify_enrollment(env: &Env, course_id: &String, user: &Address) {
+    let notify_addr: Option<Address> = env.storage().instance().get(&(KEY_NOTIFY_ADDR,));
+    if let Some(addr) = notify_addr {
+        let _ = env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &addr,
+            &Symbol::new(env, "on_enrollment"),
+            (course_id.clone(), user.clone()).into_val(env),
+        );
+    }
+}
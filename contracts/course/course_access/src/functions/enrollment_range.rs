@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::grant_access::{load_access, validate_course_id};
+use crate::schema::{CourseAccess, CourseUsers, DataKey};
+
+/// Maximum page size of `list_enrollments_between`
+const MAX_RANGE_LIMIT: u32 = 100;
+
+/// Count the access entries of a course granted between `start_ts` and `end_ts`, inclusive
+pub fn count_enrollments_between(env: Env, course_id: String, start_ts: u64, end_ts: u64) -> u32 {
+    let mut count: u32 = 0;
+    for_each_enrollment_between(&env, &course_id, start_ts, end_ts, |_| {
+        count += 1;
+        true
+    });
+    count
+}
+
+/// List the access entries of a course granted between `start_ts` and `end_ts`, inclusive
+///
+/// Entries are in roster order. `offset` skips that many matching entries.
+pub fn list_enrollments_between(
+    env: Env,
+    course_id: String,
+    start_ts: u64,
+    end_ts: u64,
+    limit: u32,
+    offset: u32,
+) -> Vec<CourseAccess> {
+    if limit == 0 || limit > MAX_RANGE_LIMIT {
+        handle_error(&env, Error::InvalidLimit)
+    }
+
+    let mut results: Vec<CourseAccess> = Vec::new(&env);
+    let mut skipped: u32 = 0;
+    for_each_enrollment_between(&env, &course_id, start_ts, end_ts, |access| {
+        if skipped < offset {
+            skipped += 1;
+        } else {
+            results.push_back(access);
+        }
+        results.len() < limit
+    });
+    results
+}
+
+/// Call `f` with every access entry of the course whose `granted_at` is in
+/// range, stopping early once `f` returns `false`
+///
+/// Expired and suspended entries are included: they were still enrollments.
+fn for_each_enrollment_between<F>(env: &Env, course_id: &String, start_ts: u64, end_ts: u64, mut f: F)
+where
+    F: FnMut(CourseAccess) -> bool,
+{
+    validate_course_id(env, course_id);
+    if start_ts > end_ts {
+        handle_error(env, Error::InvalidTimeRange)
+    }
+
+    let users: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get::<DataKey, CourseUsers>(&DataKey::CourseUsers(course_id.clone()))
+        .map(|course_users| course_users.users)
+        .unwrap_or(Vec::new(env));
+
+    for user in users.iter() {
+        let access: CourseAccess = match load_access(env, &DataKey::CourseAccess(course_id.clone(), user)) {
+            Some(access) => access,
+            None => continue,
+        };
+
+        if access.granted_at < start_ts || access.granted_at > end_ts {
+            continue;
+        }
+        if !f(access) {
+            return;
+        }
+    }
+}
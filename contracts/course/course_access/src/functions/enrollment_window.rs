@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::grant_access::validate_course_id;
+use crate::schema::{DataKey, EnrollmentWindow};
+
+const ENROLLMENT_WINDOW_EVENT: Symbol = symbol_short!("enrlWin");
+
+/// Only accept enrollments into a course between `opens_at` and `closes_at`
+///
+/// The caller must be an admin or the creator of the course. Fails with
+/// `Error::InvalidTimeRange` unless `opens_at < closes_at`.
+pub fn set_enrollment_window(env: Env, caller: Address, course_id: String, opens_at: u64, closes_at: u64) {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+    require_window_manager(&env, &caller, &course_id);
+
+    if opens_at >= closes_at {
+        handle_error(&env, Error::InvalidTimeRange)
+    }
+
+    let key: DataKey = DataKey::EnrollmentWindow(course_id.clone());
+    env.storage().persistent().set(&key, &EnrollmentWindow { opens_at, closes_at });
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events()
+        .publish((ENROLLMENT_WINDOW_EVENT, course_id), (caller, Some((opens_at, closes_at))));
+}
+
+/// Remove the enrollment window of a course, so enrollment is always open
+///
+/// The caller must be an admin or the creator of the course.
+pub fn clear_enrollment_window(env: Env, caller: Address, course_id: String) {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+    require_window_manager(&env, &caller, &course_id);
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::EnrollmentWindow(course_id.clone()));
+
+    env.events()
+        .publish((ENROLLMENT_WINDOW_EVENT, course_id), (caller, None::<(u64, u64)>));
+}
+
+/// Get the enrollment window of a course, if any
+pub fn get_enrollment_window(env: &Env, course_id: &String) -> Option<EnrollmentWindow> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EnrollmentWindow(course_id.clone()))
+}
+
+/// Fail with `Error::EnrollmentClosed` if the course has an enrollment window
+/// and the current ledger timestamp is outside it
+///
+/// The window includes `opens_at` and excludes `closes_at`.
+pub fn require_enrollment_open(env: &Env, course_id: &String) {
+    if let Some(window) = get_enrollment_window(env, course_id) {
+        let now: u64 = env.ledger().timestamp();
+        if now < window.opens_at || now >= window.closes_at {
+            handle_error(env, Error::EnrollmentClosed)
+        }
+    }
+}
+
+/// Reject callers that are neither an admin nor the creator of the course
+fn require_window_manager(env: &Env, caller: &Address, course_id: &String) {
+    if !is_admin(env, caller) && !is_course_creator(env, course_id, caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
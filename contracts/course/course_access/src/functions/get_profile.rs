@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env};
+
+use crate::schema::{DataKey, UserProfile};
+
+/// Get the profile stored for `user`, or `None` if no profile exists
+pub fn get_user_profile(env: Env, user: Address) -> Option<UserProfile> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UserProfile(user))
+}
+
+/// Check whether a profile is stored for `user`
+pub fn has_user_profile(env: &Env, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::UserProfile(user.clone()))
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::try_get_course;
+use crate::functions::denylist::require_not_denylisted;
+use crate::functions::enrollment_window::require_enrollment_open;
+use crate::functions::grant_access::{full_access, has_access, store_purchased_access, validate_course_id};
+use crate::functions::payment::{collect_course_payment, record_purchase};
+use crate::schema::Course;
+
+const GIFT_ENROLLMENT_EVENT: Symbol = symbol_short!("giftEnrl");
+
+/// Buy a course for another user
+///
+/// The payer pays the course's effective price to the course creator and the
+/// recipient is granted full access with `granted_by = payer`. Paid gifts
+/// are recorded so the payer can be refunded if the recipient drops the
+/// course within its refund window. Rejects
+/// courses that are unpublished or archived, and recipients who already
+/// have access or are on the course denylist, before any tokens move. The payer is a customer rather than a granter, so gifts
+/// do not count against the grant rate limit.
+pub fn gift_enrollment(env: Env, payer: Address, course_id: String, recipient: Address) -> i128 {
+    payer.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    let course: Course = match try_get_course(&env, &course_id) {
+        Some(course) if course.published && !course.is_archived => course,
+        _ => handle_error(&env, Error::CourseNotFoundInRegistry),
+    };
+
+    if has_access(&env, &course_id, &recipient) {
+        handle_error(&env, Error::UserAlreadyHasAccess)
+    }
+    require_not_denylisted(&env, &course_id, &recipient);
+    require_enrollment_open(&env, &course_id);
+
+    let amount: i128 = collect_course_payment(&env, &payer, &course);
+
+    let enrollment_id: u64 =
+        store_purchased_access(&env, full_access(&env, course_id.clone(), recipient.clone(), Some(payer.clone())));
+    if amount > 0 {
+        record_purchase(&env, enrollment_id, &payer, amount);
+    }
+
+    env.events().publish(
+        (GIFT_ENROLLMENT_EVENT, recipient.clone()),
+        (course_id, payer, recipient, amount),
+    );
+
+    amount
+}
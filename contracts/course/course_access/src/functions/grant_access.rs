@@ -1,41 +1,300 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Address, Env, String, Vec, Symbol, symbol_short};
+use soroban_sdk::{Address, Env, Map, String, TryFromVal, Val, Vec, Symbol, symbol_short};
 
-use crate::schema::{CourseAccess, DataKey, UserCourses, CourseUsers};
+use crate::schema::{AccessGrantedEvent, AccessRole, CourseAccess, DataKey, LegacyCourseAccessV1, RevokeReason, UserCourses, CourseUsers};
 use crate::error::{Error, handle_error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::ban_check::{is_banned, require_not_banned};
+use crate::functions::config::require_not_decommissioned;
+use crate::functions::denylist::{is_denylisted, require_not_denylisted};
+use crate::functions::access_ttl::bump_access_ttl;
+use crate::functions::enrollment_counts::{count_access_granted, increment_total_enrollments};
+use crate::functions::enrollment_history::{record_grant, record_revocation};
+use crate::functions::enrollment_id::assign_enrollment_id;
+use crate::functions::enrollment_limit::{check_user_enrollment_limit, is_over_user_enrollment_limit};
+use crate::functions::enrollment_notify::notify_enrollment;
+use crate::functions::grant_rate_limit::{check_grant_rate_limit, is_grant_rate_limited};
+use crate::functions::granters::{can_grant_access, is_granter};
+use crate::functions::profile_requirement::{check_profile_required, is_missing_required_profile};
+use crate::functions::purge_expired_access::is_access_expired;
+use crate::functions::recent_grants::record_recent_grant;
 
 const COURSE_ACCESS_EVENT: Symbol = symbol_short!("crsAccess");
 
+/// Field present in every access entry written after version 1.0.0
+const ENROLLMENT_ID_FIELD: &str = "enrollment_id";
+
 /// Grant access to a specific user for a given course
-pub fn course_access_grant_access(env: Env, course_id: String, user: Address) {
+///
+/// The caller must be an admin, the creator of the course or one of its
+/// granting delegates, and is recorded as the granter.
+/// Fails with `Error::UserAlreadyHasAccess` if the user already has access.
+pub fn course_access_grant_access(env: Env, caller: Address, course_id: String, user: Address) {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !can_grant_access(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    // Check if access already exists to prevent duplicates
+    if has_access(&env, &course_id, &user) {
+        handle_error(&env, Error::UserAlreadyHasAccess)
+    }
+
+    store_access(&env, full_access(&env, course_id, user, Some(caller)));
+}
+
+/// Grant access to a user for a course unless the user already has it
+///
+/// This is the retry-safe variant of `course_access_grant_access`: a duplicate
+/// grant leaves storage untouched and returns `false` instead of failing.
+/// The caller must be an admin, the creator of the course or one of its
+/// granting delegates. Admins may set `bypass_limit` to grant access beyond
+/// the per-user enrollment limit and to users without a saved profile.
+///
+/// # Returns
+///
+/// `true` if access was granted, `false` if the user already had access.
+pub fn grant_access_if_absent(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    user: Address,
+    bypass_limit: bool,
+) -> bool {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    let caller_is_admin: bool = is_admin(&env, &caller);
+    if !caller_is_admin
+        && (bypass_limit
+            || !(is_granter(&env, &course_id, &caller) || is_course_creator(&env, &course_id, &caller)))
+    {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    if has_access(&env, &course_id, &user) {
+        return false;
+    }
+
+    let access: CourseAccess = full_access(&env, course_id, user, Some(caller));
+    if bypass_limit {
+        store_access_over_limit(&env, access, true);
+    } else {
+        store_access(&env, access);
+    }
+    true
+}
+
+/// Build the access entry of a regular, non-expiring student grant
+///
+/// The enrollment ID is assigned when the entry is written.
+pub fn full_access(env: &Env, course_id: String, user: Address, granted_by: Option<Address>) -> CourseAccess {
+    CourseAccess {
+        course_id,
+        user,
+        role: AccessRole::Student,
+        granted_at: env.ledger().timestamp(),
+        granted_by,
+        expires_at: None,
+        is_trial: false,
+        enrollment_id: 0,
+        suspended: false,
+        suspension_reason: None,
+        transferred_from: None,
+        transferred_at: None,
+    }
+}
+
+/// Reject empty or overly long course IDs
+pub fn validate_course_id(env: &Env, course_id: &String) {
     // Validate input parameters
     if course_id.is_empty() {
-        handle_error(&env, Error::EmptyCourseId);
+        handle_error(env, Error::EmptyCourseId);
     }
-    
+
     // Check course_id length to prevent extremely long IDs
     if course_id.len() > 100 {
-        handle_error(&env, Error::InvalidCourseId);
+        handle_error(env, Error::InvalidCourseId);
     }
+}
 
+/// Returns `true` if the user currently has access to the course
+///
+/// Expired entries, including expired trials, count as no access, so they
+/// can be granted again. Suspended entries also count as no access.
+/// Reading an entry extends its TTL when it is close to expiring.
+pub fn has_access(env: &Env, course_id: &String, user: &Address) -> bool {
     let key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
+    match load_access(env, &key) {
+        Some(access) => {
+            bump_access_ttl(env, &key);
+            !is_access_expired(env, &access) && !access.suspended
+        }
+        None => false,
+    }
+}
 
-    // Check if access already exists to prevent duplicates
-    if env.storage().persistent().has(&key) {
-        handle_error(&env, Error::UserAlreadyHasAccess)
+/// Returns `true` if the user has unexpired access that is suspended
+pub fn is_access_suspended(env: &Env, course_id: &String, user: &Address) -> bool {
+    load_access(env, &DataKey::CourseAccess(course_id.clone(), user.clone()))
+        .is_some_and(|access| !is_access_expired(env, &access) && access.suspended)
+}
+
+/// Read a stored access entry, including entries in the 1.0.0 layout
+///
+/// Entries written by version 1.0.0 only hold the course and the user, and
+/// decoding them as `CourseAccess` would trap. They are read through
+/// `LegacyCourseAccessV1` and returned as a permanent student grant with
+/// unknown grant time, granter and enrollment ID. Storage is not modified;
+/// the entry is written in the current layout the next time it is updated.
+pub fn load_access(env: &Env, key: &DataKey) -> Option<CourseAccess> {
+    // Read the raw field map, as decoding into the wrong layout traps
+    let stored: Map<Symbol, Val> = env.storage().persistent().get(key)?;
+    if !is_legacy_layout(env, &stored) {
+        return CourseAccess::try_from_val(env, stored.as_val()).ok();
     }
 
-    // Create the course access entry
-    let course_access: CourseAccess = CourseAccess {
-        course_id: course_id.clone(),
-        user: user.clone(),
-    };
+    let legacy: LegacyCourseAccessV1 = LegacyCourseAccessV1::try_from_val(env, stored.as_val()).ok()?;
+    Some(CourseAccess {
+        granted_at: 0,
+        ..full_access(env, legacy.course_id, legacy.user, None)
+    })
+}
+
+/// Whether the access entry under `key` is stored in the 1.0.0 layout
+pub fn is_legacy_access(env: &Env, key: &DataKey) -> bool {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Map<Symbol, Val>>(key)
+        .is_some_and(|stored| is_legacy_layout(env, &stored))
+}
+
+fn is_legacy_layout(env: &Env, stored: &Map<Symbol, Val>) -> bool {
+    !stored.contains_key(Symbol::new(env, ENROLLMENT_ID_FIELD))
+}
+
+/// Write the access entry, record the enrollment and emit the grant event
+///
+/// Fails with `Error::UserDenylisted` if the user is denylisted for the
+/// course, with `Error::UserBanned` if the user profile contract has banned
+/// the user, with `Error::UserEnrollmentLimitReached` if the user is at the
+/// enrollment limit, with `AccessError::ProfileRequired` if profiles are
+/// required and the user has none, and with `Error::AccessSuspended` rather
+/// than replace suspended access.
+pub fn store_access(env: &Env, access: CourseAccess) {
+    check_user_enrollment_limit(env, &access.course_id, &access.user);
+    check_profile_required(env, &access.user);
+    store_access_over_limit(env, access, true);
+}
+
+/// `store_access` for access bought by `granted_by`
+///
+/// A paying customer is not a granter, so the purchase does not count
+/// against the grant rate limit. All other checks of `store_access` apply.
+/// Returns the enrollment ID of the stored entry.
+pub fn store_purchased_access(env: &Env, access: CourseAccess) -> u64 {
+    check_user_enrollment_limit(env, &access.course_id, &access.user);
+    check_profile_required(env, &access.user);
+    store_access_over_limit(env, access, false)
+}
+
+/// Returns `true` if `store_access` would accept the access entry
+///
+/// Mirrors the checks of `store_access` without failing, so callers can skip
+/// a grant instead of aborting the whole call.
+pub fn can_store_access(env: &Env, access: &CourseAccess) -> bool {
+    !is_over_user_enrollment_limit(env, &access.course_id, &access.user)
+        && !is_missing_required_profile(env, &access.user)
+        && !is_denylisted(env, &access.course_id, &access.user)
+        && !is_banned(env, &access.user)
+        && !is_access_suspended(env, &access.course_id, &access.user)
+        && !access
+            .granted_by
+            .as_ref()
+            .is_some_and(|granter| is_grant_rate_limited(env, granter))
+}
+
+/// `store_access` without the per-user enrollment limit and profile checks
+///
+/// With `rate_limited` set, grants with a known granter count against that
+/// granter's grant rate limit. Returns the enrollment ID of the stored entry.
+fn store_access_over_limit(env: &Env, mut access: CourseAccess, rate_limited: bool) -> u64 {
+    require_not_denylisted(env, &access.course_id, &access.user);
+    require_not_banned(env, &access.user);
+    if is_access_suspended(env, &access.course_id, &access.user) {
+        handle_error(env, Error::AccessSuspended)
+    }
+    if let (true, Some(granter)) = (rate_limited, access.granted_by.as_ref()) {
+        check_grant_rate_limit(env, granter);
+    }
+
+    let user_count: u32 = write_access_entry(env, &mut access);
+    let course_id: String = access.course_id;
+    let user: Address = access.user;
+    let enrollment_id: u64 = access.enrollment_id;
+
+    // Close the record of an expired grant being replaced, then keep a
+    // permanent record of this enrollment
+    record_revocation(env, &course_id, &user, None, RevokeReason::Expiry, None);
+    record_grant(env, &course_id, &user, enrollment_id);
+
+    env.events().publish(
+        (COURSE_ACCESS_EVENT, user.clone()),
+        AccessGrantedEvent {
+            course_id: course_id.clone(),
+            user: user.clone(),
+            actor: access.granted_by,
+            user_count,
+            enrollment_id,
+            seq: next_access_event_seq(env),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+
+    notify_enrollment(env, &course_id, &user);
+
+    enrollment_id
+}
+
+/// Write the access entry and update the user and course indexes
+///
+/// A fresh enrollment ID is assigned to `access` before it is stored.
+/// Returns the number of users with access to the course afterwards.
+pub fn write_access_entry(env: &Env, access: &mut CourseAccess) -> u32 {
+    access.enrollment_id = assign_enrollment_id(env, &access.course_id, &access.user);
+    write_access_record(env, access)
+}
+
+/// `write_access_entry` that stores `access` unchanged, keeping its
+/// enrollment ID
+///
+/// Used to restore backed-up entries; the enrollment ID must already be
+/// indexed to the entry's course and user.
+pub fn write_access_record(env: &Env, access: &CourseAccess) -> u32 {
+    require_not_decommissioned(env);
+
+    let course_id: &String = &access.course_id;
+    let user: &Address = &access.user;
+    let key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
+
+    // Replacing an expired entry does not add an enrollment
+    let replaced: bool = env.storage().persistent().has(&key);
+    if !replaced {
+        increment_total_enrollments(env);
+    }
+    count_access_granted(env, course_id, replaced);
 
     // Store the access entry
-    env.storage().persistent().set(&key, &course_access);
-    env.storage().persistent().extend_ttl(&key, 100, 1000);
+    env.storage().persistent().set(&key, access);
+    bump_access_ttl(env, &key);
+    record_recent_grant(env, access);
 
     // Update UserCourses
     let user_courses_key: DataKey = DataKey::UserCourses(user.clone());
@@ -45,29 +304,45 @@ pub fn course_access_grant_access(env: Env, course_id: String, user: Address) {
         .get(&user_courses_key)
         .unwrap_or(UserCourses {
             user: user.clone(),
-            courses: Vec::new(&env),
+            courses: Vec::new(env),
         });
-    if !user_courses.courses.contains(&course_id) {
+    if !user_courses.courses.contains(course_id) {
         user_courses.courses.push_back(course_id.clone());
         env.storage().persistent().set(&user_courses_key, &user_courses);
-        env.storage().persistent().extend_ttl(&user_courses_key, 100, 1000);
     }
+    bump_access_ttl(env, &user_courses_key);
 
-    // Update CourseUsers
+    // Update CourseUsers, registering the course the first time it is granted
     let course_users_key: DataKey = DataKey::CourseUsers(course_id.clone());
-    let mut course_users: CourseUsers = env
-        .storage()
-        .persistent()
-        .get(&course_users_key)
-        .unwrap_or(CourseUsers {
-            course: course_id.clone(),
-            users: Vec::new(&env),
-        });
-    if !course_users.users.contains(&user) {
+    let mut course_users: CourseUsers = match env.storage().persistent().get(&course_users_key) {
+        Some(course_users) => course_users,
+        None => {
+            register_access_course(env, course_id);
+            CourseUsers {
+                course: course_id.clone(),
+                users: Vec::new(env),
+            }
+        }
+    };
+    if !course_users.users.contains(user) {
         course_users.users.push_back(user.clone());
         env.storage().persistent().set(&course_users_key, &course_users);
-        env.storage().persistent().extend_ttl(&course_users_key, 100, 1000);
     }
-    env.events()
-        .publish((COURSE_ACCESS_EVENT, &user.clone()), (course_id, user, course_users.users.len(),));
+    bump_access_ttl(env, &course_users_key);
+
+    course_users.users.len()
+}
+
+/// Add a course to the list of courses that have had access granted
+pub fn register_access_course(env: &Env, course_id: &String) {
+    let mut courses: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AccessCourses)
+        .unwrap_or(Vec::new(env));
+    if !courses.contains(course_id) {
+        courses.push_back(course_id.clone());
+        env.storage().persistent().set(&DataKey::AccessCourses, &courses);
+        env.storage().persistent().extend_ttl(&DataKey::AccessCourses, 100, 1000);
+    }
 }
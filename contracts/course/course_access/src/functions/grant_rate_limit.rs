@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::schema::{DataKey, GrantRateLimitConfig, GrantRateLimitData, GrantRateLimitStatus, KEY_GRANT_RATE_LIMIT};
+
+const GRANT_RATE_LIMIT_EVENT: Symbol = symbol_short!("grntLimit");
+const GRANT_RATE_EXEMPT_EVENT: Symbol = symbol_short!("grntExmpt");
+
+/// Set the grant rate limit applied to every granter. Admin only.
+///
+/// Grants are not rate limited until this is called.
+/// Fails with `Error::InvalidLimit` if either value is 0.
+pub fn set_grant_rate_limit(env: Env, caller: Address, window_seconds: u64, max_grants_per_window: u32) {
+    caller.require_auth();
+
+    if !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    if window_seconds == 0 || max_grants_per_window == 0 {
+        handle_error(&env, Error::InvalidLimit)
+    }
+
+    env.storage().instance().set(
+        &(KEY_GRANT_RATE_LIMIT,),
+        &GrantRateLimitConfig {
+            window_seconds,
+            max_grants_per_window,
+        },
+    );
+
+    env.events()
+        .publish((GRANT_RATE_LIMIT_EVENT,), (caller, window_seconds, max_grants_per_window));
+}
+
+/// Get the grant rate limit, or `None` if grants are not rate limited
+pub fn get_grant_rate_limit_config(env: &Env) -> Option<GrantRateLimitConfig> {
+    env.storage().instance().get(&(KEY_GRANT_RATE_LIMIT,))
+}
+
+/// Exempt a granter from the grant rate limit, or lift the exemption. Admin only.
+pub fn set_grant_rate_limit_exempt(env: Env, caller: Address, granter: Address, exempt: bool) {
+    caller.require_auth();
+
+    if !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let key: DataKey = DataKey::GrantRateLimitExempt(granter.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 100, 1000);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events()
+        .publish((GRANT_RATE_EXEMPT_EVENT, granter), (caller, exempt));
+}
+
+/// Returns `true` if the granter is exempt from the grant rate limit
+pub fn is_grant_rate_limit_exempt(env: &Env, granter: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GrantRateLimitExempt(granter.clone()))
+        .unwrap_or(false)
+}
+
+/// Count a grant against the granter's window
+///
+/// The window restarts once `window_seconds` have passed since it began.
+/// Fails with `Error::GrantRateLimitExceeded` if the granter has already made
+/// `max_grants_per_window` grants in the current window. Does nothing while
+/// no limit is configured.
+pub fn check_grant_rate_limit(env: &Env, granter: &Address) {
+    if is_grant_rate_limit_exempt(env, granter) {
+        return;
+    }
+
+    let config: GrantRateLimitConfig = match get_grant_rate_limit_config(env) {
+        Some(config) => config,
+        None => return,
+    };
+    let now: u64 = env.ledger().timestamp();
+    let key: DataKey = DataKey::GrantRateLimit(granter.clone());
+    let mut data: GrantRateLimitData = current_window(env, &key, &config, now);
+
+    if data.count >= config.max_grants_per_window {
+        handle_error(env, Error::GrantRateLimitExceeded)
+    }
+
+    data.count += 1;
+    env.storage().persistent().set(&key, &data);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+}
+
+/// Returns `true` if the granter cannot make another grant in the current window
+pub fn is_grant_rate_limited(env: &Env, granter: &Address) -> bool {
+    if is_grant_rate_limit_exempt(env, granter) {
+        return false;
+    }
+
+    let config: GrantRateLimitConfig = match get_grant_rate_limit_config(env) {
+        Some(config) => config,
+        None => return false,
+    };
+    let key: DataKey = DataKey::GrantRateLimit(granter.clone());
+    current_window(env, &key, &config, env.ledger().timestamp()).count >= config.max_grants_per_window
+}
+
+/// Report where a granter stands against the grant rate limit, or `None` if no limit is configured
+pub fn get_grant_rate_limit_status(env: &Env, granter: &Address) -> Option<GrantRateLimitStatus> {
+    let config: GrantRateLimitConfig = get_grant_rate_limit_config(env)?;
+    let now: u64 = env.ledger().timestamp();
+    let data: GrantRateLimitData = current_window(env, &DataKey::GrantRateLimit(granter.clone()), &config, now);
+
+    Some(GrantRateLimitStatus {
+        count: data.count,
+        max_grants_per_window: config.max_grants_per_window,
+        window_resets_at: data.window_start.saturating_add(config.window_seconds),
+        exempt: is_grant_rate_limit_exempt(env, granter),
+    })
+}
+
+/// The granter's usage in the window containing `now`, starting a new window if the last one elapsed
+fn current_window(env: &Env, key: &DataKey, config: &GrantRateLimitConfig, now: u64) -> GrantRateLimitData {
+    match env.storage().persistent().get::<DataKey, GrantRateLimitData>(key) {
+        Some(data) if now < data.window_start.saturating_add(config.window_seconds) => data,
+        _ => GrantRateLimitData {
+            count: 0,
+            window_start: now,
+        },
+    }
+}
@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::grant_access::validate_course_id;
+use crate::schema::{DataKey, MAX_COURSE_GRANTERS};
+
+const GRANTER_ADD_EVENT: Symbol = symbol_short!("grntrAdd");
+const GRANTER_REMOVE_EVENT: Symbol = symbol_short!("grntrRm");
+
+/// Let `delegate` grant and revoke access to one course
+///
+/// Delegates act on that course only and gain no rights in the registry.
+/// Adding an existing delegate is a no-op. Only the course creator can
+/// manage the list.
+pub fn add_granter(env: Env, creator: Address, course_id: String, delegate: Address) {
+    require_granter_manager(&env, &creator, &course_id);
+
+    let key: DataKey = DataKey::CourseGranters(course_id.clone());
+    let mut granters: Vec<Address> = list_granters(&env, &course_id);
+    if granters.contains(&delegate) {
+        return;
+    }
+    if granters.len() >= MAX_COURSE_GRANTERS {
+        handle_error(&env, Error::TooManyGranters)
+    }
+    granters.push_back(delegate.clone());
+    env.storage().persistent().set(&key, &granters);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events()
+        .publish((GRANTER_ADD_EVENT, course_id, delegate), creator);
+}
+
+/// Take away a delegate's granting rights for a course
+///
+/// Returns `false` if `delegate` was not a granter.
+/// Only the course creator can manage the list.
+pub fn remove_granter(env: Env, creator: Address, course_id: String, delegate: Address) -> bool {
+    require_granter_manager(&env, &creator, &course_id);
+
+    let key: DataKey = DataKey::CourseGranters(course_id.clone());
+    let mut granters: Vec<Address> = list_granters(&env, &course_id);
+    let index: u32 = match granters.first_index_of(&delegate) {
+        Some(index) => index,
+        None => return false,
+    };
+    granters.remove(index);
+    if granters.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &granters);
+        env.storage().persistent().extend_ttl(&key, 100, 1000);
+    }
+
+    env.events()
+        .publish((GRANTER_REMOVE_EVENT, course_id, delegate), creator);
+    true
+}
+
+/// Get the delegates allowed to grant and revoke access to a course
+pub fn list_granters(env: &Env, course_id: &String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseGranters(course_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns `true` if `who` is a granting delegate of the course
+pub fn is_granter(env: &Env, course_id: &String, who: &Address) -> bool {
+    list_granters(env, course_id).contains(who)
+}
+
+/// Returns `true` if `who` may grant and revoke access to the course:
+/// an admin, the course creator or one of its delegates
+pub fn can_grant_access(env: &Env, course_id: &String, who: &Address) -> bool {
+    is_granter(env, course_id, who) || is_admin(env, who) || is_course_creator(env, course_id, who)
+}
+
+fn require_granter_manager(env: &Env, creator: &Address, course_id: &String) {
+    creator.require_auth();
+
+    validate_course_id(env, course_id);
+
+    if !is_course_creator(env, course_id, creator) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::enrollment_window::require_enrollment_open;
+use crate::functions::grant_access::{full_access, has_access, store_access, validate_course_id};
+use crate::schema::{DataKey, Invite};
+
+const CREATE_INVITE_EVENT: Symbol = symbol_short!("invCreate");
+const ACCEPT_INVITE_EVENT: Symbol = symbol_short!("invAccept");
+const REVOKE_INVITE_EVENT: Symbol = symbol_short!("invRevoke");
+
+/// Invite a user to a course
+///
+/// The invitee gets access once they accept the invite, which they can do
+/// until `expires_at`. Inviting the same user again only renews the invite.
+/// The caller must be an admin or the creator of the course.
+pub fn create_invite(env: Env, caller: Address, course_id: String, invitee: Address, expires_at: u64) {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !is_admin(&env, &caller) && !is_course_creator(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    if expires_at <= env.ledger().timestamp() {
+        handle_error(&env, Error::InvalidTimeRange)
+    }
+
+    if has_access(&env, &course_id, &invitee) {
+        handle_error(&env, Error::UserAlreadyHasAccess)
+    }
+
+    let key: DataKey = DataKey::Invite(course_id.clone(), invitee.clone());
+    let invite: Invite = Invite {
+        course_id: course_id.clone(),
+        invitee: invitee.clone(),
+        inviter: caller.clone(),
+        expires_at,
+    };
+    env.storage().persistent().set(&key, &invite);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events()
+        .publish((CREATE_INVITE_EVENT, invitee), (course_id, caller, expires_at));
+}
+
+/// Accept an invite and get access to the course
+///
+/// The invite is consumed. Fails if there is no invite for the caller or it
+/// has expired.
+pub fn accept_invite(env: Env, invitee: Address, course_id: String) {
+    invitee.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    let key: DataKey = DataKey::Invite(course_id.clone(), invitee.clone());
+    let invite: Invite = match env.storage().persistent().get(&key) {
+        Some(invite) => invite,
+        None => handle_error(&env, Error::InviteNotFound),
+    };
+
+    if env.ledger().timestamp() >= invite.expires_at {
+        handle_error(&env, Error::InviteExpired)
+    }
+    require_enrollment_open(&env, &course_id);
+
+    if has_access(&env, &course_id, &invitee) {
+        handle_error(&env, Error::UserAlreadyHasAccess)
+    }
+
+    env.storage().persistent().remove(&key);
+
+    store_access(
+        &env,
+        full_access(&env, course_id.clone(), invitee.clone(), Some(invite.inviter)),
+    );
+
+    env.events()
+        .publish((ACCEPT_INVITE_EVENT, invitee), course_id);
+}
+
+/// Withdraw a pending invite
+///
+/// The caller must be an admin or the creator of the course.
+pub fn revoke_invite(env: Env, caller: Address, course_id: String, invitee: Address) {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !is_admin(&env, &caller) && !is_course_creator(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let key: DataKey = DataKey::Invite(course_id.clone(), invitee.clone());
+    if !env.storage().persistent().has(&key) {
+        handle_error(&env, Error::InviteNotFound)
+    }
+    env.storage().persistent().remove(&key);
+
+    env.events()
+        .publish((REVOKE_INVITE_EVENT, invitee), (course_id, caller));
+}
+
+/// Get the pending invite of a user for a course
+pub fn get_invite(env: Env, course_id: String, invitee: Address) -> Option<Invite> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Invite(course_id, invitee))
+}
@@ -1,10 +1,18 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Env, String, Vec};
+use soroban_sdk::{Address, Env, String, Vec};
 
-use crate::schema::{CourseUsers, DataKey};
+use crate::schema::{AccessRole, CourseAccess, CourseUsers, DataKey};
 use crate::error::{Error, handle_error};
+use crate::functions::access_ttl::bump_access_ttl;
+use crate::functions::grant_access::{is_access_suspended, load_access};
+use crate::functions::purge_expired_access::is_access_expired;
+
+/// Default page size of `list_course_access_detailed`
+const DEFAULT_DETAILED_LIMIT: u32 = 10;
+/// Maximum page size of `list_course_access_detailed`
+const MAX_DETAILED_LIMIT: u32 = 100;
 
 pub fn course_access_list_course_access(env: Env, course_id: String) -> CourseUsers {
     // Validate input parameters
@@ -19,13 +27,68 @@ pub fn course_access_list_course_access(env: Env, course_id: String) -> CourseUs
     
     let key: DataKey = DataKey::CourseUsers(course_id.clone());
 
-    let res: CourseUsers = env.storage()
+    let mut res: CourseUsers = env.storage()
         .persistent()
         .get(&key)
         .unwrap_or(CourseUsers {
             course: course_id.clone(),
             users: Vec::new(&env),
         });
+    bump_access_ttl(&env, &key);
+
+    // Suspended access is inactive and left out
+    let mut active: Vec<Address> = Vec::new(&env);
+    for user in res.users.iter() {
+        if !is_access_suspended(&env, &course_id, &user) {
+            active.push_back(user);
+        }
+    }
+    res.users = active;
 
     return res
 }
+
+/// List the full access records of a course, optionally filtered by role
+///
+/// Expired and suspended access is left out. `offset` skips that many matching records.
+pub fn list_course_access_detailed(
+    env: Env,
+    course_id: String,
+    role_filter: Option<AccessRole>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> Vec<CourseAccess> {
+    let limit_value: u32 = limit.unwrap_or(DEFAULT_DETAILED_LIMIT);
+    if limit_value == 0 || limit_value > MAX_DETAILED_LIMIT {
+        handle_error(&env, Error::InvalidLimit)
+    }
+    let offset_value: u32 = offset.unwrap_or(0);
+
+    let users: Vec<Address> = course_access_list_course_access(env.clone(), course_id.clone()).users;
+
+    let mut results: Vec<CourseAccess> = Vec::new(&env);
+    let mut skipped: u32 = 0;
+
+    for user in users.iter() {
+        if results.len() >= limit_value {
+            break;
+        }
+
+        let access: CourseAccess = match load_access(&env, &DataKey::CourseAccess(course_id.clone(), user)) {
+            Some(access) => access,
+            None => continue,
+        };
+
+        if is_access_expired(&env, &access) || role_filter.is_some_and(|role| role != access.role) {
+            continue;
+        }
+
+        if skipped < offset_value {
+            skipped += 1;
+        } else {
+            results.push_back(access);
+        }
+    }
+
+    results
+}
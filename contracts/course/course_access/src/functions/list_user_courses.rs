@@ -1,21 +1,93 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{Address, Env, String, Vec};
 
-use crate::schema::{DataKey, UserCourses};
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::try_get_course;
+use crate::functions::access_ttl::bump_access_ttl;
+use crate::functions::grant_access::{has_access, load_access};
+use crate::schema::{Course, DataKey, UserCourses};
 
+/// Maximum number of courses resolved by `list_user_courses_detailed`
+const MAX_DETAILED_USER_COURSES: u32 = 50;
 
-pub fn list_user_courses(env: Env, user: Address) -> UserCourses {
+/// Maximum page size of `list_user_courses_by_recency`
+const MAX_RECENT_USER_COURSES: u32 = 50;
+
+/// List the courses of a user
+///
+/// Only currently usable access is listed unless `include_inactive` is set,
+/// in which case expired and suspended access is included as well.
+pub fn list_user_courses(env: Env, user: Address, include_inactive: bool) -> UserCourses {
     let key: DataKey = DataKey::UserCourses(user.clone());
-    let res: UserCourses = env.storage().persistent().get(&key).unwrap_or(UserCourses {
+    let mut res: UserCourses = env.storage().persistent().get(&key).unwrap_or(UserCourses {
         user: user.clone(),
         courses: Vec::new(&env),
     });
+    bump_access_ttl(&env, &key);
+
+    if include_inactive {
+        return res;
+    }
+
+    // Expired and suspended access is inactive and left out
+    let mut active: Vec<String> = Vec::new(&env);
+    for course_id in res.courses.iter() {
+        if has_access(&env, &course_id, &user) {
+            active.push_back(course_id);
+        }
+    }
+    res.courses = active;
 
     return res
 }
 
+/// Resolve the courses a user has access to through the course registry
+///
+/// Courses the registry can no longer return, such as deleted ones, are
+/// skipped. At most `MAX_DETAILED_USER_COURSES` course IDs are resolved.
+pub fn list_user_courses_detailed(env: Env, user: Address) -> Vec<Course> {
+    let course_ids: Vec<String> = list_user_courses(env.clone(), user, false).courses;
+
+    let mut courses: Vec<Course> = Vec::new(&env);
+    for course_id in course_ids.iter().take(MAX_DETAILED_USER_COURSES as usize) {
+        if let Some(course) = try_get_course(&env, &course_id) {
+            courses.push_back(course);
+        }
+    }
+
+    courses
+}
+
+/// List the IDs of a user's active courses, most recently granted first
+///
+/// Courses granted at the same time keep the order of the user's course list.
+pub fn list_user_courses_by_recency(env: Env, user: Address, limit: u32) -> Vec<String> {
+    if limit == 0 || limit > MAX_RECENT_USER_COURSES {
+        handle_error(&env, Error::InvalidLimit)
+    }
+
+    let course_ids: Vec<String> = list_user_courses(env.clone(), user.clone(), false).courses;
+
+    // Insertion sort by grant time, newest first
+    let mut granted_at: Vec<u64> = Vec::new(&env);
+    let mut sorted: Vec<String> = Vec::new(&env);
+    for course_id in course_ids.iter() {
+        let timestamp: u64 = load_access(&env, &DataKey::CourseAccess(course_id.clone(), user.clone()))
+            .map(|access| access.granted_at)
+            .unwrap_or(0);
+        let index: u32 = granted_at
+            .iter()
+            .position(|other| other < timestamp)
+            .map_or(granted_at.len(), |index| index as u32);
+        granted_at.insert(index, timestamp);
+        sorted.insert(index, course_id);
+    }
+
+    sorted.slice(0..limit.min(sorted.len()))
+}
+
 #[cfg(test)]
 mod test {
     use crate::schema::DataKey;
@@ -39,7 +111,7 @@ mod test {
         // Set up initial course data and perform test within contract context
         env.clone().as_contract(&contract_id, || {
             env.storage().persistent().set(&key, &user_courses);
-            let result: UserCourses = list_user_courses(env, user.clone());
+            let result: UserCourses = list_user_courses(env, user.clone(), true);
             assert_eq!(result, user_courses);
         });
     }
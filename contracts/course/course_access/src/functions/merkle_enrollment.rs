@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::enrollment_window::require_enrollment_open;
+use crate::functions::grant_access::{full_access, has_access, store_access, validate_course_id};
+use crate::schema::DataKey;
+
+const ENROLLMENT_ROOT_EVENT: Symbol = symbol_short!("enrlRoot");
+const CLAIM_ENROLLMENT_EVENT: Symbol = symbol_short!("enrlClaim");
+
+/// Set the Merkle root of the cohort allowed to claim a seat in a course
+///
+/// The caller must be an admin or the creator of the course. Replacing the
+/// root invalidates every proof against the old one; seats already claimed
+/// stay claimed.
+pub fn set_enrollment_merkle_root(env: Env, caller: Address, course_id: String, root: BytesN<32>) {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !is_admin(&env, &caller) && !is_course_creator(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let key: DataKey = DataKey::EnrollmentMerkleRoot(course_id.clone());
+    env.storage().persistent().set(&key, &root);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events()
+        .publish((ENROLLMENT_ROOT_EVENT, course_id), (caller, root));
+}
+
+/// Get the Merkle root of the cohort of a course, if any
+pub fn get_enrollment_merkle_root(env: &Env, course_id: &String) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EnrollmentMerkleRoot(course_id.clone()))
+}
+
+/// Claim a seat in a course with a proof of membership in its cohort
+///
+/// The leaf is the SHA-256 of the student's address in XDR. Each proof
+/// element is combined with the running hash as the SHA-256 of the two
+/// hashes concatenated in ascending byte order, and the result must equal
+/// the course's root. Fails with `Error::InvalidEnrollmentProof` if the
+/// course has no root, the proof does not match it, or the leaf was already
+/// claimed.
+pub fn claim_enrollment(env: Env, student: Address, course_id: String, proof: Vec<BytesN<32>>) {
+    student.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    let root: BytesN<32> = match get_enrollment_merkle_root(&env, &course_id) {
+        Some(root) => root,
+        None => handle_error(&env, Error::InvalidEnrollmentProof),
+    };
+
+    let leaf: BytesN<32> = env.crypto().sha256(&student.clone().to_xdr(&env)).into();
+    let claim_key: DataKey = DataKey::EnrollmentClaimed(course_id.clone(), leaf.clone());
+    if env.storage().persistent().has(&claim_key) {
+        handle_error(&env, Error::InvalidEnrollmentProof)
+    }
+    if fold_proof(&env, leaf, &proof) != root {
+        handle_error(&env, Error::InvalidEnrollmentProof)
+    }
+
+    require_enrollment_open(&env, &course_id);
+    if has_access(&env, &course_id, &student) {
+        handle_error(&env, Error::UserAlreadyHasAccess)
+    }
+
+    env.storage().persistent().set(&claim_key, &true);
+    env.storage().persistent().extend_ttl(&claim_key, 100, 1000);
+
+    store_access(&env, full_access(&env, course_id.clone(), student.clone(), None));
+
+    env.events()
+        .publish((CLAIM_ENROLLMENT_EVENT, student), course_id);
+}
+
+/// Hash the leaf up the proof, pairing hashes in ascending byte order
+pub fn fold_proof(env: &Env, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> BytesN<32> {
+    let mut hash: BytesN<32> = leaf;
+    for sibling in proof.iter() {
+        let (first, second) = if hash.to_array() <= sibling.to_array() {
+            (hash, sibling)
+        } else {
+            (sibling, hash)
+        };
+        let mut pair: Bytes = Bytes::from_array(env, &first.to_array());
+        pair.append(&Bytes::from_array(env, &second.to_array()));
+        hash = env.crypto().sha256(&pair).into();
+    }
+    hash
+}
@@ -1,12 +1,50 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+pub mod access_consistency;
+pub mod access_control;
+pub mod access_event_seq;
+pub mod access_role;
+pub mod access_ttl;
+pub mod admin_list;
+pub mod backup_recovery;
+pub mod ban_check;
+pub mod bundle_access;
+pub mod certificate;
 pub mod config;
 pub mod contract_versioning;
+pub mod course_completion;
+pub mod course_deleted;
+pub mod delete_profile;
+pub mod denylist;
+pub mod enrollment_counts;
+pub mod enrollment_history;
+pub mod enrollment_id;
+pub mod enrollment_limit;
+pub mod enrollment_window;
+pub mod enrollment_range;
+pub mod enrollment_notify;
+pub mod get_profile;
+pub mod gift_enrollment;
 pub mod grant_access;
+pub mod grant_rate_limit;
+pub mod granters;
+pub mod invite;
 pub mod list_course_access;
 pub mod list_user_courses;
+pub mod merkle_enrollment;
+pub mod module_progress;
+pub mod payment;
+pub mod prerequisite_check;
+pub mod profile_requirement;
+pub mod purge_expired_access;
+pub mod recent_grants;
+pub mod remove_user_courses;
 pub mod revoke_access;
 pub mod revoke_all_access;
 pub mod save_profile;
+pub mod suspend_access;
 pub mod transfer_course_access;
+pub mod trial_access;
+pub mod unenroll;
+pub mod voucher;
@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::get_course_module_ids;
+use crate::functions::grant_access::{has_access, validate_course_id};
+use crate::schema::{DataKey, ProgressSummary};
+
+const MODULE_COMPLETED_EVENT: Symbol = symbol_short!("modComplt");
+
+/// Record that a student completed a module of a course
+///
+/// The student must authorize the call and have active access. Completing
+/// the same module again changes nothing.
+pub fn mark_module_completed(
+    env: Env,
+    student: Address,
+    course_id: String,
+    module_id: String,
+) -> ProgressSummary {
+    student.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !has_access(&env, &course_id, &student) {
+        handle_error(&env, Error::UserNoAccessCourse)
+    }
+
+    let module_ids: Vec<String> = get_course_module_ids(&env, &course_id);
+    if !module_ids.contains(&module_id) {
+        handle_error(&env, Error::ModuleNotFound)
+    }
+
+    let key: DataKey = DataKey::ModuleProgress(course_id.clone(), student.clone());
+    let mut completed: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(&env));
+
+    if !completed.contains(&module_id) {
+        // Drop modules removed from the course so the set stays bounded
+        // by the course's module count
+        let mut retained: Vec<String> = Vec::new(&env);
+        for id in completed.iter() {
+            if module_ids.contains(&id) {
+                retained.push_back(id);
+            }
+        }
+        retained.push_back(module_id.clone());
+        completed = retained;
+
+        env.storage().persistent().set(&key, &completed);
+        env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+        env.events()
+            .publish((MODULE_COMPLETED_EVENT, student), (course_id, module_id));
+    }
+
+    summarize(&completed, &module_ids)
+}
+
+/// Get a student's module progress in a course
+///
+/// The total is read from the course registry, so modules added or removed
+/// after a student's progress was recorded are reflected.
+pub fn get_progress(env: Env, course_id: String, student: Address) -> ProgressSummary {
+    let module_ids: Vec<String> = get_course_module_ids(&env, &course_id);
+    let completed: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ModuleProgress(course_id, student))
+        .unwrap_or(Vec::new(&env));
+
+    summarize(&completed, &module_ids)
+}
+
+fn summarize(completed: &Vec<String>, module_ids: &Vec<String>) -> ProgressSummary {
+    let mut count: u32 = 0;
+    for id in completed.iter() {
+        if module_ids.contains(&id) {
+            count += 1;
+        }
+    }
+    ProgressSummary {
+        completed: count,
+        total: module_ids.len(),
+    }
+}
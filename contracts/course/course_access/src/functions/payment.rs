@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{token, Address, Env};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::get_effective_price;
+use crate::functions::access_ttl::bump_access_ttl;
+use crate::functions::config::get_payment_token;
+use crate::schema::{Course, DataKey, Purchase};
+
+/// Transfer the course's effective price from `payer` to the course creator
+///
+/// The effective price, including any active sale, comes from the course
+/// registry. Free courses transfer nothing. The payer must have authorized
+/// the call. Returns the amount transferred.
+pub fn collect_course_payment(env: &Env, payer: &Address, course: &Course) -> i128 {
+    let amount: i128 = match i128::try_from(get_effective_price(env, &course.id)) {
+        Ok(amount) => amount,
+        Err(_) => handle_error(env, Error::InvalidConfig),
+    };
+    if amount == 0 {
+        return 0;
+    }
+
+    let token_addr: Address = match get_payment_token(env) {
+        Some(addr) => addr,
+        None => handle_error(env, Error::InvalidConfig),
+    };
+    token::Client::new(env, &token_addr).transfer(payer, &course.creator, &amount);
+
+    amount
+}
+
+/// Record that `payer` paid `amount` for the enrollment `enrollment_id`
+pub fn record_purchase(env: &Env, enrollment_id: u64, payer: &Address, amount: i128) {
+    let key: DataKey = DataKey::Purchase(enrollment_id);
+    env.storage().persistent().set(
+        &key,
+        &Purchase {
+            payer: payer.clone(),
+            amount,
+        },
+    );
+    bump_access_ttl(env, &key);
+}
+
+/// Get the payment made for an enrollment, if it was bought
+pub fn get_purchase(env: &Env, enrollment_id: u64) -> Option<Purchase> {
+    env.storage().persistent().get(&DataKey::Purchase(enrollment_id))
+}
+
+/// Forget the payment made for an enrollment whose access is gone
+pub fn remove_purchase(env: &Env, enrollment_id: u64) {
+    env.storage().persistent().remove(&DataKey::Purchase(enrollment_id));
+}
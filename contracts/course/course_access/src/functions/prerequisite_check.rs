@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::functions::access_control::get_prerequisite_closure;
+use crate::functions::course_completion::has_completed;
+use crate::functions::grant_access::validate_course_id;
+use crate::schema::CourseId;
+
+/// List the prerequisites of a course the user has not completed yet
+///
+/// Prerequisites come from the registry's transitive closure, so
+/// prerequisites of prerequisites are included, nearest first.
+/// Returns an empty list when every prerequisite is completed.
+pub fn check_prerequisites_met(env: Env, course_id: String, user: Address) -> Vec<CourseId> {
+    validate_course_id(&env, &course_id);
+
+    let mut unmet: Vec<CourseId> = Vec::new(&env);
+    for prerequisite in get_prerequisite_closure(&env, &course_id).iter() {
+        if !has_completed(env.clone(), prerequisite.id.clone(), user.clone()) {
+            unmet.push_back(prerequisite);
+        }
+    }
+
+    unmet
+}
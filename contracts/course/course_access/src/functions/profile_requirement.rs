@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::{handle_error, AccessError, Error};
+use crate::functions::access_control::is_admin;
+use crate::functions::get_profile::has_user_profile;
+use crate::schema::KEY_REQUIRE_PROFILE;
+
+const REQUIRE_PROFILE_EVENT: Symbol = symbol_short!("reqProfl");
+
+/// Set whether users need a saved profile before they can be granted access
+///
+/// The caller must be an admin.
+pub fn set_require_profile_for_access(env: Env, caller: Address, required: bool) {
+    caller.require_auth();
+
+    if !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    env.storage().instance().set(&(KEY_REQUIRE_PROFILE,), &required);
+
+    env.events()
+        .publish((REQUIRE_PROFILE_EVENT,), (caller, required));
+}
+
+/// Whether users need a saved profile before they can be granted access
+pub fn get_require_profile_for_access(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&(KEY_REQUIRE_PROFILE,))
+        .unwrap_or(false)
+}
+
+/// Fail with `AccessError::ProfileRequired` if profiles are required and the
+/// user has not saved one
+pub fn check_profile_required(env: &Env, user: &Address) {
+    if is_missing_required_profile(env, user) {
+        handle_error(env, AccessError::ProfileRequired)
+    }
+}
+
+/// Returns `true` if profiles are required and the user has not saved one
+pub fn is_missing_required_profile(env: &Env, user: &Address) -> bool {
+    get_require_profile_for_access(env) && !has_user_profile(env, user)
+}
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::config::require_not_decommissioned;
+use crate::functions::enrollment_counts::{count_access_revoked, decrement_total_enrollments};
+use crate::functions::enrollment_history::record_revocation;
+use crate::schema::{CourseAccess, CourseUsers, DataKey, RevokeReason, UserCourses};
+use crate::functions::grant_access::load_access;
+use crate::functions::payment::remove_purchase;
+
+const PURGE_EXPIRED_EVENT: Symbol = symbol_short!("purgeExp");
+
+/// Returns `true` if the access entry has an expiry that has already passed.
+pub fn is_access_expired(env: &Env, access: &CourseAccess) -> bool {
+    access
+        .expires_at
+        .is_some_and(|expires_at| env.ledger().timestamp() >= expires_at)
+}
+
+/// Remove up to `max_entries` expired access entries of a course
+///
+/// Expired entries are removed from the access records and from both the
+/// user and course indexes, and their enrollment record is closed.
+/// Unexpired access is never touched. Call repeatedly until it returns
+/// less than `max_entries` to clean up a large course.
+///
+/// The caller must be an admin or the creator of the course.
+///
+/// # Returns
+///
+/// The number of entries purged.
+pub fn purge_expired_access(env: Env, caller: Address, course_id: String, max_entries: u32) -> u32 {
+    caller.require_auth();
+
+    if course_id.is_empty() {
+        handle_error(&env, Error::EmptyCourseId)
+    }
+    if course_id.len() > 100 {
+        handle_error(&env, Error::InvalidCourseId)
+    }
+
+    if !is_admin(&env, &caller) && !is_course_creator(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    remove_expired_entries(&env, &course_id, max_entries)
+}
+
+/// Remove up to `limit` expired access entries of a course, as an admin
+///
+/// Same cleanup as `purge_expired_access`, restricted to admins so that
+/// operators can reclaim storage across courses they do not own.
+///
+/// # Returns
+///
+/// The number of entries removed.
+pub fn sweep_expired_access(env: Env, caller: Address, course_id: String, limit: u32) -> u32 {
+    caller.require_auth();
+
+    if course_id.is_empty() {
+        handle_error(&env, Error::EmptyCourseId)
+    }
+    if course_id.len() > 100 {
+        handle_error(&env, Error::InvalidCourseId)
+    }
+
+    if !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    remove_expired_entries(&env, &course_id, limit)
+}
+
+/// Remove up to `max_entries` expired entries and emit the purge event
+///
+/// Callers are responsible for authorization and input validation.
+fn remove_expired_entries(env: &Env, course_id: &String, max_entries: u32) -> u32 {
+    require_not_decommissioned(env);
+
+    let course_users_key: DataKey = DataKey::CourseUsers(course_id.clone());
+    let mut course_users: CourseUsers = match env.storage().persistent().get(&course_users_key) {
+        Some(course_users) => course_users,
+        None => return 0,
+    };
+
+    let mut remaining: Vec<Address> = Vec::new(env);
+    let mut purged: u32 = 0;
+
+    for user in course_users.users.iter() {
+        let access_key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
+        let expired: Option<CourseAccess> = if purged < max_entries {
+            load_access(env, &access_key).filter(|access| is_access_expired(env, access))
+        } else {
+            None
+        };
+
+        let Some(access) = expired else {
+            remaining.push_back(user);
+            continue;
+        };
+
+        env.storage().persistent().remove(&access_key);
+        remove_purchase(env, access.enrollment_id);
+        record_revocation(env, course_id, &user, None, RevokeReason::Expiry, None);
+
+        let user_courses_key: DataKey = DataKey::UserCourses(user.clone());
+        if let Some(mut user_courses) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, UserCourses>(&user_courses_key)
+        {
+            if let Some(index) = user_courses.courses.first_index_of(course_id) {
+                user_courses.courses.remove(index);
+                env.storage().persistent().set(&user_courses_key, &user_courses);
+                env.storage().persistent().extend_ttl(&user_courses_key, 100, 1000);
+            }
+        }
+
+        purged += 1;
+    }
+
+    if purged > 0 {
+        decrement_total_enrollments(env, purged as u64);
+        count_access_revoked(env, course_id, purged as u64);
+        course_users.users = remaining;
+        env.storage().persistent().set(&course_users_key, &course_users);
+        env.storage().persistent().extend_ttl(&course_users_key, 100, 1000);
+    }
+
+    env.events()
+        .publish((PURGE_EXPIRED_EVENT, course_id.clone()), (purged, next_access_event_seq(env)));
+
+    purged
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{CourseAccess, DataKey, RecentGrant, RECENT_GRANTS_CAPACITY};
+
+/// Append a grant to the course's recent-grants feed
+///
+/// Once the feed holds `RECENT_GRANTS_CAPACITY` entries the oldest one is
+/// evicted, so storage stays bounded. Revocations leave the feed alone.
+pub fn record_recent_grant(env: &Env, access: &CourseAccess) {
+    let key: DataKey = DataKey::RecentGrants(access.course_id.clone());
+    let mut grants: Vec<RecentGrant> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+
+    while grants.len() >= RECENT_GRANTS_CAPACITY {
+        grants.pop_front();
+    }
+    grants.push_back(RecentGrant {
+        user: access.user.clone(),
+        granted_at: access.granted_at,
+        granted_by: access.granted_by.clone(),
+    });
+
+    env.storage().persistent().set(&key, &grants);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+}
+
+/// Get up to `count` of the most recent grants of a course, newest first
+///
+/// `count` is capped at `RECENT_GRANTS_CAPACITY`. Fails with
+/// `Error::InvalidLimit` if `count` is 0.
+pub fn get_recent_grants(env: &Env, course_id: &String, count: u32) -> Vec<RecentGrant> {
+    if count == 0 {
+        handle_error(env, Error::InvalidLimit)
+    }
+
+    let grants: Vec<RecentGrant> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RecentGrants(course_id.clone()))
+        .unwrap_or(Vec::new(env));
+
+    let mut newest_first: Vec<RecentGrant> = Vec::new(env);
+    for grant in grants.iter().rev().take(count.min(RECENT_GRANTS_CAPACITY) as usize) {
+        newest_first.push_back(grant);
+    }
+    newest_first
+}
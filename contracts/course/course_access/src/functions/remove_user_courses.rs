@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::revoke_access::remove_access_entry;
+use crate::schema::{DataKey, RevokeReason, UserCourses};
+
+const REMOVE_USER_ALL_EVENT: Symbol = symbol_short!("rmUserAll");
+
+/// Remove up to `max_courses` of a user's course access records
+///
+/// The inverse of `revoke_all_access`, for account deletion and bans.
+/// Courses are taken from the front of the user's course list and each
+/// removal keeps the per-user and per-course indexes consistent. Call
+/// repeatedly until the user's course list is empty.
+/// The caller must be an admin or the user themselves.
+///
+/// # Returns
+///
+/// The number of access records removed.
+pub fn remove_user_from_all_courses(env: Env, caller: Address, user: Address, max_courses: u32) -> u32 {
+    caller.require_auth();
+
+    if caller != user && !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let courses: Vec<String> = env
+        .storage()
+        .persistent()
+        .get::<DataKey, UserCourses>(&DataKey::UserCourses(user.clone()))
+        .map(|user_courses| user_courses.courses)
+        .unwrap_or(Vec::new(&env));
+
+    let mut removed: u32 = 0;
+    let mut processed: u32 = 0;
+    for course_id in courses.iter().take(max_courses as usize) {
+        processed += 1;
+        let access = remove_access_entry(
+            &env,
+            &course_id,
+            &user,
+            Some(caller.clone()),
+            RevokeReason::Unspecified,
+            None,
+        );
+        if access.is_some() {
+            removed += 1;
+        }
+    }
+
+    let remaining: u32 = courses.len() - processed;
+    env.events().publish(
+        (REMOVE_USER_ALL_EVENT, user),
+        (caller, removed, remaining, next_access_event_seq(&env)),
+    );
+
+    removed
+}
@@ -3,8 +3,15 @@
 
 use soroban_sdk::{Address, Env, String, Symbol, symbol_short};
 
-use crate::schema::{DataKey, UserCourses, CourseUsers};
+use crate::schema::{AccessRevokedEvent, CourseAccess, DataKey, RevokeReason, UserCourses, CourseUsers};
 use crate::error::{Error, handle_error};
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::config::require_not_decommissioned;
+use crate::functions::enrollment_counts::{count_access_revoked, decrement_total_enrollments};
+use crate::functions::enrollment_history::{record_revocation, validate_reason_hash};
+use crate::functions::grant_access::{load_access, validate_course_id};
+use crate::functions::granters::can_grant_access;
+use crate::functions::payment::remove_purchase;
 
 const COURSE_ACCESS_REVOKE_EVENT: Symbol = symbol_short!("crsAcRvk");
 
@@ -13,59 +20,150 @@ const COURSE_ACCESS_REVOKE_EVENT: Symbol = symbol_short!("crsAcRvk");
 /// This function removes the course access entry for the specified user, updates the user's
 /// course list by removing the course, and updates the course's user list by removing the user.
 /// It also publishes an event to notify about the access revocation.
+/// The enrollment history is preserved and can be queried afterwards.
 ///
 /// # Arguments
 ///
 /// * `env` - The Soroban environment for accessing storage and publishing events.
+/// * `caller` - The address revoking access: an admin, the course creator or one of its
+///   granting delegates. Recorded as the actor of the revocation.
 /// * `course_id` - The unique identifier of the course from which access is being revoked.
 /// * `user` - The address of the user whose access is being revoked.
+/// * `reason_hash` - Optional hash of the reason for the revocation, stored on the enrollment record.
 ///
 /// # Returns
 ///
 /// * `bool` - Returns `true` if the access was successfully revoked (entry existed and was removed),
 ///   or `false` if no access entry was found for the user-course combination.
-pub fn course_access_revoke_access(env: Env, course_id: String, user: Address) -> bool {
-    // Validate input parameters
-    if course_id.is_empty() {
-        handle_error(&env, Error::EmptyCourseId);
+pub fn course_access_revoke_access(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    user: Address,
+    reason_hash: Option<String>,
+) -> bool {
+    revoke_access_with_reason(env, caller, course_id, user, RevokeReason::Unspecified, reason_hash)
+}
+
+/// Revokes a user's access like `course_access_revoke_access`, recording why
+///
+/// `reason` is stored on the enrollment record and included in the revoke event.
+pub fn revoke_access_with_reason(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    user: Address,
+    reason: RevokeReason,
+    reason_hash: Option<String>,
+) -> bool {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !can_grant_access(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
     }
-    
-    // Check course_id length to prevent extremely long IDs
-    if course_id.len() > 100 {
-        handle_error(&env, Error::InvalidCourseId);
+
+    validate_reason_hash(&env, &reason_hash);
+
+    revoke_and_publish(&env, course_id, user, Some(caller), reason, reason_hash)
+}
+
+/// Remove a user's access entry and publish the revoke event
+///
+/// Callers are responsible for authorization and input validation.
+/// Returns `false` if the user had no access entry.
+pub fn revoke_and_publish(
+    env: &Env,
+    course_id: String,
+    user: Address,
+    actor: Option<Address>,
+    reason: RevokeReason,
+    reason_hash: Option<String>,
+) -> bool {
+    match remove_access_entry(env, &course_id, &user, actor.clone(), reason, reason_hash) {
+        Some(access) => {
+            env.events().publish(
+                (COURSE_ACCESS_REVOKE_EVENT,),
+                AccessRevokedEvent {
+                    course_id,
+                    user,
+                    actor,
+                    reason,
+                    enrollment_id: access.enrollment_id,
+                    seq: next_access_event_seq(env),
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+
+            true
+        }
+        None => false,
     }
-    
+}
+
+/// Remove a user's access entry for a course and update the user and course indexes
+///
+/// The enrollment record is closed with `revoked_by`, `reason` and `reason_hash`. No event is emitted.
+/// The user and course indexes are cleaned up even when the access entry is
+/// already gone, so stale index entries cannot outlive a revocation.
+/// Returns the removed entry, or `None` if there was no access entry to remove.
+pub fn remove_access_entry(
+    env: &Env,
+    course_id: &String,
+    user: &Address,
+    revoked_by: Option<Address>,
+    reason: RevokeReason,
+    reason_hash: Option<String>,
+) -> Option<CourseAccess> {
+    require_not_decommissioned(env);
+
     let key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
 
     // Check if the CourseAccess entry exists in persistent storage
-    if env.storage().persistent().has(&key) {
-        // Remove the CourseAccess entry
+    let access: Option<CourseAccess> = load_access(env, &key);
+
+    if let Some(access) = &access {
+        // Remove the CourseAccess entry and the purchase that paid for it
         env.storage().persistent().remove(&key);
+        remove_purchase(env, access.enrollment_id);
+        decrement_total_enrollments(env, 1);
+        count_access_revoked(env, course_id, 1);
 
-        // Update UserCourses
-        let user_courses_key: DataKey = DataKey::UserCourses(user.clone());
-        if let Some(mut user_courses) = env.storage().persistent().get::<DataKey, UserCourses>(&user_courses_key) {
-            if let Some(index) = user_courses.courses.iter().position(|c| c == course_id) {
-                user_courses.courses.remove(index as u32);
-                env.storage().persistent().set(&user_courses_key, &user_courses);
-                env.storage().persistent().extend_ttl(&user_courses_key, 100, 1000);
-            }
-        }
+        // Close the enrollment record so the history survives revocation
+        record_revocation(env, course_id, user, revoked_by, reason, reason_hash);
+    }
+
+    remove_from_user_courses(env, user, course_id);
+    remove_from_course_users(env, course_id, user);
 
-        // Update CourseUsers
-        let course_users_key: DataKey = DataKey::CourseUsers(course_id.clone());
-        if let Some(mut course_users) = env.storage().persistent().get::<DataKey, CourseUsers>(&course_users_key) {
-            if let Some(index) = course_users.users.iter().position(|u| u == user) {
-                course_users.users.remove(index as u32);
-                env.storage().persistent().set(&course_users_key, &course_users);
-                env.storage().persistent().extend_ttl(&course_users_key, 100, 1000);
-            }
+    access
+}
+
+/// Remove a course from the user's course index, if listed
+///
+/// Returns `true` if the index was changed.
+pub fn remove_from_user_courses(env: &Env, user: &Address, course_id: &String) -> bool {
+    let user_courses_key: DataKey = DataKey::UserCourses(user.clone());
+    if let Some(mut user_courses) = env.storage().persistent().get::<DataKey, UserCourses>(&user_courses_key) {
+        if let Some(index) = user_courses.courses.first_index_of(course_id) {
+            user_courses.courses.remove(index);
+            env.storage().persistent().set(&user_courses_key, &user_courses);
+            env.storage().persistent().extend_ttl(&user_courses_key, 100, 1000);
+            return true;
         }
-    env.events()
-        .publish((COURSE_ACCESS_REVOKE_EVENT,), (course_id, user));
+    }
+    false
+}
 
-        true
-    } else {
-        false
+/// Remove a user from the course's user index, if listed
+pub fn remove_from_course_users(env: &Env, course_id: &String, user: &Address) {
+    let course_users_key: DataKey = DataKey::CourseUsers(course_id.clone());
+    if let Some(mut course_users) = env.storage().persistent().get::<DataKey, CourseUsers>(&course_users_key) {
+        if let Some(index) = course_users.users.first_index_of(user) {
+            course_users.users.remove(index);
+            env.storage().persistent().set(&course_users_key, &course_users);
+            env.storage().persistent().extend_ttl(&course_users_key, 100, 1000);
+        }
     }
 }
@@ -1,22 +1,27 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, IntoVal, String, Symbol, Vec};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
 use crate::error::{handle_error, Error};
-use crate::schema::{DataKey, KEY_COURSE_REG_ADDR, KEY_USER_MGMT_ADDR};
-
-/// Storage key symbol for user data
-const USER_KEY: Symbol = symbol_short!("user");
-
-/// Storage key symbol for courses data
-const COURSES_KEY: Symbol = symbol_short!("courses");
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::config::require_not_decommissioned;
+use crate::functions::enrollment_counts::{count_access_revoked, decrement_total_enrollments};
+use crate::functions::enrollment_history::{record_revocation, validate_reason_hash};
+use crate::functions::grant_access::load_access;
+use crate::functions::payment::remove_purchase;
+use crate::functions::revoke_access::remove_from_user_courses;
+use crate::schema::{AllAccessRevokedEvent, CourseUsers, DataKey, RevokeReason};
 
 /// Event symbol for revoke all access operations
 const REVOKE_ALL_EVENT: Symbol = symbol_short!("revokeAll");
 
-
-pub fn revoke_all_access(env: Env, caller: Address, course_id: String) -> u32 {
+/// Revoke every user's access to a course
+///
+/// `reason_hash`, if given, is stored on the enrollment record of every
+/// affected user. The caller must be an admin or the creator of the course.
+pub fn revoke_all_access(env: Env, caller: Address, course_id: String, reason_hash: Option<String>) -> u32 {
     caller.require_auth();
 
     // Validate input parameters
@@ -29,96 +34,94 @@ pub fn revoke_all_access(env: Env, caller: Address, course_id: String) -> u32 {
         handle_error(&env, Error::InvalidCourseId);
     }
 
-    // Resolve admin via cross-contract if configured
-    let user_mgmt_addr: Address = env
-        .storage()
-        .instance()
-        .get(&(KEY_USER_MGMT_ADDR,))
-        .expect("user_mgmt_addr not configured; call initialize/set_config");
-    let is_admin: bool = env.invoke_contract(
-        &user_mgmt_addr,
-        &Symbol::new(&env, "is_admin"),
-        (caller.clone(),).into_val(&env),
-    );
-
-    // Resolve creator via cross-contract if configured
-    let course_registry_addr: Address = env
-        .storage()
-        .instance()
-        .get(&(KEY_COURSE_REG_ADDR,))
-        .expect("course_registry_addr not configured; call initialize/set_config");
-    let is_creator: bool = env.invoke_contract(
-        &course_registry_addr,
-        &Symbol::new(&env, "is_course_creator"),
-        (course_id.clone(), caller.clone()).into_val(&env),
-    );
+    validate_reason_hash(&env, &reason_hash);
 
     // Authorization: only admin or course creator
-    if !(is_admin || is_creator) {
+    if !is_admin(&env, &caller) && !is_course_creator(&env, &course_id, &caller) {
         handle_error(&env, Error::Unauthorized)
     }
 
+    let count: u32 = revoke_course_roster(&env, &course_id, Some(caller.clone()), reason_hash);
+
+    env.events().publish(
+        (REVOKE_ALL_EVENT, course_id.clone()),
+        AllAccessRevokedEvent {
+            course_id: course_id.clone(),
+            actor: caller,
+            count,
+            seq: next_access_event_seq(&env),
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+
+    count
+}
+
+/// Remove every access entry of a course and empty its roster
+///
+/// Shared by `revoke_all_access` and the course deletion hook; callers are
+/// responsible for authorization and events. Returns the number of access
+/// entries removed, which can be less than the roster size.
+pub fn revoke_course_roster(
+    env: &Env,
+    course_id: &String,
+    revoked_by: Option<Address>,
+    reason_hash: Option<String>,
+) -> u32 {
+    require_not_decommissioned(env);
+
+    let course_id: String = course_id.clone();
+
     // Fetch all users with access to this course
-    let course_key: (Symbol, String) = (COURSES_KEY, course_id.clone());
+    let course_key: DataKey = DataKey::CourseUsers(course_id.clone());
     let affected_users: Vec<Address> = match env.storage().persistent().get(&course_key) {
         Some(course_users) => {
-            let cu: crate::schema::CourseUsers = course_users;
+            let cu: CourseUsers = course_users;
             cu.users
         }
-        None => Vec::new(&env),
+        None => Vec::new(env),
     };
 
     let count: u32 = affected_users.len();
     if count == 0 {
-        env.events()
-            .publish((REVOKE_ALL_EVENT, course_id.clone()), count);
         return 0;
     }
 
     // Remove each user's access entry and update per-user course index if present
+    let mut removed: u32 = 0;
     let mut i: u32 = 0u32;
     while i < count {
         if let Some(user) = affected_users.get(i) {
             let access_key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
-            if env.storage().persistent().has(&access_key) {
+            if let Some(access) = load_access(env, &access_key) {
                 env.storage().persistent().remove(&access_key);
+                remove_purchase(env, access.enrollment_id);
+                record_revocation(
+                    env,
+                    &course_id,
+                    &user,
+                    revoked_by.clone(),
+                    RevokeReason::Unspecified,
+                    reason_hash.clone(),
+                );
+                removed += 1;
             }
 
-            let user_key: (Symbol, String) = (USER_KEY, user.to_string());
-            if let Some(mut uc) = env
-                .storage()
-                .persistent()
-                .get::<_, crate::schema::UserCourses>(&user_key)
-            {
-                let mut new_courses: Vec<String> = Vec::new(&env);
-                let mut j: u32 = 0u32;
-                let total: u32 = uc.courses.len();
-                while j < total {
-                    if let Some(cid) = uc.courses.get(j) {
-                        if cid != course_id {
-                            new_courses.push_back(cid);
-                        }
-                    }
-                    j = j.saturating_add(1);
-                }
-                uc.courses = new_courses;
-                env.storage().persistent().set(&user_key, &uc);
-            }
+            remove_from_user_courses(env, &user, &course_id);
         }
         i = i.saturating_add(1);
     }
 
+    decrement_total_enrollments(env, removed as u64);
+    count_access_revoked(env, &course_id, removed as u64);
+
     // Clear course -> users index
-    let empty: Vec<Address> = Vec::new(&env);
+    let empty: Vec<Address> = Vec::new(env);
     if env.storage().persistent().has(&course_key) {
-        let mut cu: crate::schema::CourseUsers =
-            env.storage().persistent().get(&course_key).unwrap();
+        let mut cu: CourseUsers = env.storage().persistent().get(&course_key).unwrap();
         cu.users = empty;
         env.storage().persistent().set(&course_key, &cu);
     }
 
-    env.events()
-        .publish((REVOKE_ALL_EVENT, course_id.clone()), count);
-
-    count
+    removed
 }
@@ -1,23 +1,37 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Address, Env, String, Symbol, symbol_short};
+use soroban_sdk::{Address, Env, String, Symbol, Vec, symbol_short};
 
 use crate::error::{handle_error, Error};
-use crate::schema::{DataKey, UserProfile};
+use crate::functions::config::require_not_decommissioned;
+use crate::schema::{DataKey, ProfileSavedEvent, UserProfile};
 
 const SAVE_USER_PROFILE_EVENT: Symbol = symbol_short!("saveUsPrl");
+const UPDATE_USER_PROFILE_EVENT: Symbol = symbol_short!("updUsPrl");
 
-
+/// Save or update the profile of `user`
+///
+/// The profile is stored under the user's own address, so the user must
+/// authorize the call. Creating a profile emits `saveUsPrl`; replacing an
+/// existing one emits `updUsPrl`. Both carry a `ProfileSavedEvent` with the
+/// new profile and, for updates, the replaced one.
+///
+/// Profiles keep their fields on-chain rather than an off-chain reference
+/// id, so an update replaces the whole profile and the event carries the old
+/// and new profiles in place of old and new reference ids.
 pub fn save_user_profile(
     env: Env,
+    user: Address,
     name: String,
     email: String,
     profession: Option<String>,
     goals: Option<String>,
     country: String,
-    user: Address,
 ) {
+    user.require_auth();
+    require_not_decommissioned(&env);
+
     // Validate required fields
     if name.is_empty() {
         handle_error(&env, Error::NameRequired)
@@ -31,17 +45,47 @@ pub fn save_user_profile(
     }
 
     let profile: UserProfile = UserProfile {
-        name: name.clone(),
-        email: email.clone(),
-        profession: profession.clone(),
-        goals: goals.clone(),
-        country: country.clone(),
+        name,
+        email,
+        profession,
+        goals,
+        country,
     };
 
-    env.storage()
-        .persistent()
-        .set(&DataKey::UserProfile(user.clone()), &profile);
+    let key: DataKey = DataKey::UserProfile(user.clone());
+    let previous: Option<UserProfile> = env.storage().persistent().get(&key);
+
+    env.storage().persistent().set(&key, &profile);
 
-    env.events()
-        .publish((SAVE_USER_PROFILE_EVENT,), (name, email, profession, goals, country, user));
+    let topic: Symbol = if previous.is_some() {
+        UPDATE_USER_PROFILE_EVENT
+    } else {
+        add_profile_user(&env, &user);
+        SAVE_USER_PROFILE_EVENT
+    };
+
+    env.events().publish(
+        (topic, user.clone()),
+        ProfileSavedEvent {
+            user: user.clone(),
+            actor: user,
+            profile,
+            previous,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+/// Add a user to the list of users with a saved profile
+pub fn add_profile_user(env: &Env, user: &Address) {
+    let mut users: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ProfileUsers)
+        .unwrap_or(Vec::new(env));
+    if !users.contains(user) {
+        users.push_back(user.clone());
+        env.storage().persistent().set(&DataKey::ProfileUsers, &users);
+        env.storage().persistent().extend_ttl(&DataKey::ProfileUsers, 100, 1000);
+    }
 }
@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::config::require_not_decommissioned;
+use crate::functions::enrollment_history::validate_reason_hash;
+use crate::functions::grant_access::{load_access, validate_course_id};
+use crate::functions::purge_expired_access::is_access_expired;
+use crate::schema::{CourseAccess, DataKey};
+
+const SUSPEND_ACCESS_EVENT: Symbol = symbol_short!("accSusp");
+const RESUME_ACCESS_EVENT: Symbol = symbol_short!("accResume");
+
+/// Temporarily freeze a user's access without ending the enrollment
+///
+/// Suspended access counts as no access until it is resumed. `reason_hash`,
+/// if given, is stored on the access record.
+/// The caller must be an admin or the creator of the course.
+pub fn suspend_access(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    user: Address,
+    reason_hash: Option<String>,
+) {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+    validate_reason_hash(&env, &reason_hash);
+
+    if !is_admin(&env, &caller) && !is_course_creator(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    require_not_decommissioned(&env);
+
+    let key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
+    let mut access: CourseAccess = load_unexpired_access(&env, &key);
+    if access.suspended {
+        handle_error(&env, Error::AccessSuspended)
+    }
+
+    access.suspended = true;
+    access.suspension_reason = reason_hash.clone();
+    env.storage().persistent().set(&key, &access);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events().publish(
+        (SUSPEND_ACCESS_EVENT, user),
+        (course_id, caller, reason_hash, next_access_event_seq(&env)),
+    );
+}
+
+/// Lift the suspension of a user's access
+///
+/// The caller must be an admin or the creator of the course.
+pub fn resume_access(env: Env, caller: Address, course_id: String, user: Address) {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !is_admin(&env, &caller) && !is_course_creator(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    require_not_decommissioned(&env);
+
+    let key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
+    let mut access: CourseAccess = load_unexpired_access(&env, &key);
+    if !access.suspended {
+        handle_error(&env, Error::AccessNotSuspended)
+    }
+
+    access.suspended = false;
+    access.suspension_reason = None;
+    env.storage().persistent().set(&key, &access);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events().publish(
+        (RESUME_ACCESS_EVENT, user),
+        (course_id, caller, next_access_event_seq(&env)),
+    );
+}
+
+/// Get a user's access record for a course, including suspended access
+pub fn get_access_details(env: Env, course_id: String, user: Address) -> Option<CourseAccess> {
+    load_access(&env, &DataKey::CourseAccess(course_id, user))
+}
+
+fn load_unexpired_access(env: &Env, key: &DataKey) -> CourseAccess {
+    match load_access(env, key) {
+        Some(access) if !is_access_expired(env, &access) => access,
+        _ => handle_error(env, Error::UserNoAccessCourse),
+    }
+}
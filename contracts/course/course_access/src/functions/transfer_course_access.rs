@@ -1,15 +1,35 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
-use crate::schema::{CourseAccess, DataKey};
+use crate::schema::{AccessTransferredEvent, CourseAccess, DataKey, Purchase, RevokeReason, UserCourses};
 use crate::error::{Error, handle_error};
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::denylist::{is_denylisted, require_not_denylisted};
+use crate::functions::enrollment_history::{record_grant, record_revocation};
+use crate::functions::grant_access::{has_access, is_access_suspended, load_access, write_access_entry};
+use crate::functions::payment::{get_purchase, record_purchase};
+use crate::functions::purge_expired_access::is_access_expired;
+use crate::functions::revoke_access::remove_access_entry;
+use crate::functions::trial_access::mark_trial_used;
 
 const COURSE_TRANSFER_EVENT: Symbol = symbol_short!("transfer");
+const TRANSFER_ALL_EVENT: Symbol = symbol_short!("trnsfrAll");
+const TRANSFER_PROVENANCE_EVENT: Symbol = symbol_short!("xferAcc");
+
+/// Publish the `xferAcc` event recording where a moved access came from
+fn publish_transfer_provenance(env: &Env, course_id: &String, from: &Address, to: &Address, initiator: &Address) {
+    env.events().publish(
+        (TRANSFER_PROVENANCE_EVENT, to.clone()),
+        (course_id.clone(), from.clone(), to.clone(), initiator.clone(), env.ledger().timestamp()),
+    );
+}
 
 // Transfer course access from one user to another
 pub fn transfer_course_access(env: Env, course_id: String, from: Address, to: Address) {
+    from.require_auth();
+
     // Validate input parameters
     if course_id.is_empty() {
         handle_error(&env, Error::EmptyCourseId);
@@ -25,37 +45,175 @@ pub fn transfer_course_access(env: Env, course_id: String, from: Address, to: Ad
         handle_error(&env, Error::SameUserTransfer);
     }
 
-    // Create the storage key for this course and current user combination
-    let key: DataKey = DataKey::CourseAccess(course_id.clone(), from.clone());
-
-    // Check if access exists to transfer
-    if !env.storage().persistent().has(&key) {
+    // Only active access can be transferred; expired access is no access
+    if !has_access(&env, &course_id, &from) {
         handle_error(&env, Error::UserNoAccessCourse);
     }
+    let current_access: CourseAccess = match load_access(&env, &DataKey::CourseAccess(course_id.clone(), from.clone())) {
+        Some(access) => access,
+        None => handle_error(&env, Error::UserNoAccessCourse),
+    };
 
-    // Create the course access entry for the new user
-    let course_access: CourseAccess = CourseAccess {
-        course_id: course_id.clone(),
+    // Never overwrite access the recipient already holds
+    if has_access(&env, &course_id, &to) {
+        handle_error(&env, Error::UserAlreadyHasAccess);
+    }
+    if is_access_suspended(&env, &course_id, &to) {
+        handle_error(&env, Error::AccessSuspended);
+    }
+
+    require_not_denylisted(&env, &course_id, &to);
+
+    // Removing the old access also forgets its purchase, so look it up first
+    let purchase: Option<Purchase> = get_purchase(&env, current_access.enrollment_id);
+
+    // Store the access entry for the new user, keeping role, expiry and the
+    // original grant time so a transfer does not reopen the refund window.
+    // Only the immediate predecessor is recorded, replacing any earlier one.
+    let mut course_access: CourseAccess = CourseAccess {
         user: to.clone(),
+        granted_by: Some(from.clone()),
+        transferred_from: Some(from.clone()),
+        transferred_at: Some(env.ledger().timestamp()),
+        ..current_access
     };
+    write_access_entry(&env, &mut course_access);
+    // The purchase follows the access to its new enrollment id
+    if let Some(purchase) = purchase {
+        record_purchase(&env, course_access.enrollment_id, &purchase.payer, purchase.amount);
+    }
+    // A transferred trial is the recipient's one trial of the course
+    if course_access.is_trial {
+        mark_trial_used(&env, &course_id, &to);
+    }
+
+    // Remove the old user's access, closing their enrollment record
+    remove_access_entry(&env, &course_id, &from, Some(from.clone()), RevokeReason::Unspecified, None);
+
+    // Close the record of an expired grant the recipient held, then open an
+    // enrollment record for the recipient
+    record_revocation(&env, &course_id, &to, None, RevokeReason::Expiry, None);
+    record_grant(&env, &course_id, &to, course_access.enrollment_id);
 
-    // Store the access entry with the composite key for the new user
-    env.storage().persistent().set(
-        &DataKey::CourseAccess(course_id.clone(), to.clone()),
-        &course_access,
+    // emit an event
+    publish_transfer_provenance(&env, &course_id, &from, &to, &from);
+    env.events().publish(
+        (COURSE_TRANSFER_EVENT,),
+        AccessTransferredEvent {
+            course_id,
+            user: to,
+            actor: from,
+            enrollment_id: course_access.enrollment_id,
+            seq: next_access_event_seq(&env),
+            timestamp: env.ledger().timestamp(),
+        },
     );
+}
+
+/// Move up to `max_courses` of `from`'s course access records to `to`
+///
+/// Meant for users rotating wallets, so both addresses must authorize the
+/// call. Courses are taken from the front of `from`'s course list. When `to`
+/// already has access to a course, or has it suspended, `from`'s now
+/// redundant entry is dropped and the destination is left untouched. A
+/// moved trial uses up `to`'s trial of the course, and a purchase moves to
+/// the new enrollment id.
+/// Expired entries of `from` are dropped, not moved. Courses `to` is
+/// denylisted for stay with `from`: they are counted as skipped and moved to
+/// the back of `from`'s course list, so the next call reaches the courses
+/// behind them. Role, expiry, trial flags and the original grant time move
+/// with the access. Call repeatedly until `from`'s course list is empty or
+/// holds only courses `to` is denylisted for.
+///
+/// # Returns
+///
+/// The number of access records moved to `to`.
+pub fn transfer_all_access(env: Env, from: Address, to: Address, max_courses: u32) -> u32 {
+    from.require_auth();
+    to.require_auth();
+
+    if from == to {
+        handle_error(&env, Error::SameUserTransfer);
+    }
+
+    let courses: Vec<String> = env
+        .storage()
+        .persistent()
+        .get::<DataKey, UserCourses>(&DataKey::UserCourses(from.clone()))
+        .map(|user_courses| user_courses.courses)
+        .unwrap_or(Vec::new(&env));
 
-    // Remove the old user's access
-    env.storage().persistent().remove(&key);
+    let mut moved: u32 = 0;
+    let mut skipped: u32 = 0;
+    let mut kept: Vec<String> = Vec::new(&env);
 
-    // Extend the TTL for the new user's storage entry
-    env.storage().persistent().extend_ttl(
-        &DataKey::CourseAccess(course_id.clone(), to.clone()),
-        100,
-        1000,
+    for course_id in courses.iter().take(max_courses as usize) {
+        if is_denylisted(&env, &course_id, &to) {
+            kept.push_back(course_id);
+            skipped += 1;
+            continue;
+        }
+
+        let purchase: Option<Purchase> = load_access(&env, &DataKey::CourseAccess(course_id.clone(), from.clone()))
+            .and_then(|access| get_purchase(&env, access.enrollment_id));
+        let access: Option<CourseAccess> =
+            remove_access_entry(&env, &course_id, &from, Some(from.clone()), RevokeReason::Unspecified, None);
+
+        let access: CourseAccess = match access {
+            Some(access)
+                if !is_access_expired(&env, &access)
+                    && !has_access(&env, &course_id, &to)
+                    && !is_access_suspended(&env, &course_id, &to) =>
+            {
+                access
+            }
+            _ => {
+                skipped += 1;
+                continue;
+            }
+        };
+
+        let mut moved_access: CourseAccess = CourseAccess {
+            user: to.clone(),
+            granted_by: Some(from.clone()),
+            transferred_from: Some(from.clone()),
+            transferred_at: Some(env.ledger().timestamp()),
+            ..access
+        };
+        write_access_entry(&env, &mut moved_access);
+        if let Some(purchase) = purchase {
+            record_purchase(&env, moved_access.enrollment_id, &purchase.payer, purchase.amount);
+        }
+        if moved_access.is_trial {
+            mark_trial_used(&env, &course_id, &to);
+        }
+        record_revocation(&env, &course_id, &to, None, RevokeReason::Expiry, None);
+        record_grant(&env, &course_id, &to, moved_access.enrollment_id);
+        publish_transfer_provenance(&env, &course_id, &from, &to, &from);
+        moved += 1;
+    }
+
+    let courses_key: DataKey = DataKey::UserCourses(from.clone());
+    let mut remaining: u32 = 0;
+    if let Some(mut user_courses) = env.storage().persistent().get::<DataKey, UserCourses>(&courses_key) {
+        if !kept.is_empty() {
+            let mut reordered: Vec<String> = Vec::new(&env);
+            for course_id in user_courses.courses.iter() {
+                if !kept.contains(&course_id) {
+                    reordered.push_back(course_id);
+                }
+            }
+            reordered.append(&kept);
+            user_courses.courses = reordered;
+            env.storage().persistent().set(&courses_key, &user_courses);
+            env.storage().persistent().extend_ttl(&courses_key, 100, 1000);
+        }
+        remaining = user_courses.courses.len();
+    }
+    env.events().publish(
+        (TRANSFER_ALL_EVENT, from, to),
+        (moved, skipped, remaining, next_access_event_seq(&env)),
     );
 
-    // emit an event
-    env.events()
-        .publish((COURSE_TRANSFER_EVENT,), (course_id, from, to));
+    moved
 }
@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::config::require_not_decommissioned;
+use crate::functions::grant_access::{full_access, has_access, load_access, store_access, validate_course_id};
+use crate::functions::purge_expired_access::is_access_expired;
+use crate::schema::{CourseAccess, DataKey};
+
+const CONVERT_TRIAL_EVENT: Symbol = symbol_short!("trialConv");
+
+/// Grant a user free trial access to a course for `trial_seconds`
+///
+/// The trial is a regular access entry flagged `is_trial` that expires
+/// `trial_seconds` after the current ledger timestamp. Each user gets at most
+/// one trial per course, even after the trial has expired.
+/// The caller must be an admin or the creator of the course.
+pub fn grant_trial_access(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    user: Address,
+    trial_seconds: u64,
+) {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !is_admin(&env, &caller) && !is_course_creator(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    if trial_seconds == 0 {
+        handle_error(&env, Error::InvalidTimeRange)
+    }
+
+    if has_access(&env, &course_id, &user) {
+        handle_error(&env, Error::UserAlreadyHasAccess)
+    }
+
+    let trial_key: DataKey = DataKey::TrialUsed(course_id.clone(), user.clone());
+    if env.storage().persistent().has(&trial_key) {
+        handle_error(&env, Error::TrialAlreadyUsed)
+    }
+
+    let expires_at: u64 = match env.ledger().timestamp().checked_add(trial_seconds) {
+        Some(expires_at) => expires_at,
+        None => handle_error(&env, Error::InvalidTimeRange),
+    };
+
+    mark_trial_used(&env, &course_id, &user);
+
+    store_access(
+        &env,
+        CourseAccess {
+            expires_at: Some(expires_at),
+            is_trial: true,
+            ..full_access(&env, course_id, user, Some(caller))
+        },
+    );
+}
+
+/// Record that the user has had their one trial of the course
+pub fn mark_trial_used(env: &Env, course_id: &String, user: &Address) {
+    let trial_key: DataKey = DataKey::TrialUsed(course_id.clone(), user.clone());
+    env.storage().persistent().set(&trial_key, &true);
+    env.storage().persistent().extend_ttl(&trial_key, 100, 1000);
+}
+
+/// Turn an active trial into full access
+///
+/// Clears the trial flag and the expiry, so the access no longer lapses.
+/// Meant to be called by the payment path once the course has been paid for.
+/// The caller must be an admin or the creator of the course.
+pub fn convert_trial_to_full(env: Env, caller: Address, course_id: String, user: Address) {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !is_admin(&env, &caller) && !is_course_creator(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    require_not_decommissioned(&env);
+
+    let key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
+    let mut access: CourseAccess = match load_access(&env, &key) {
+        Some(access) => access,
+        None => handle_error(&env, Error::UserNoAccessCourse),
+    };
+
+    // An expired trial is no access at all; it has to be granted again
+    if is_access_expired(&env, &access) {
+        handle_error(&env, Error::UserNoAccessCourse)
+    }
+
+    if !access.is_trial {
+        handle_error(&env, Error::NotTrialAccess)
+    }
+
+    access.is_trial = false;
+    access.expires_at = None;
+    env.storage().persistent().set(&key, &access);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events()
+        .publish((CONVERT_TRIAL_EVENT, user.clone()), (course_id, user, next_access_event_seq(&env)));
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::access_event_seq::next_access_event_seq;
+use crate::functions::grant_access::{load_access, validate_course_id};
+use crate::functions::payment::get_purchase;
+use crate::functions::purge_expired_access::is_access_expired;
+use crate::functions::revoke_access::remove_access_entry;
+use crate::schema::{AccessRevokedEvent, CourseAccess, DataKey, Purchase, RevokeReason};
+
+const REFUND_WINDOW_EVENT: Symbol = symbol_short!("rfndWin");
+const COURSE_ACCESS_REVOKE_EVENT: Symbol = symbol_short!("crsAcRvk");
+const REFUND_EVENT: Symbol = symbol_short!("refund");
+
+/// Set how long after enrolling a user can drop the course for a refund
+///
+/// `0` disables refunds. The caller must be an admin or the creator of the course.
+pub fn set_refund_window(env: Env, caller: Address, course_id: String, refund_window_seconds: u64) {
+    caller.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !is_admin(&env, &caller) && !is_course_creator(&env, &course_id, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let key: DataKey = DataKey::RefundWindow(course_id.clone());
+    if refund_window_seconds == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &refund_window_seconds);
+        env.storage().persistent().extend_ttl(&key, 100, 1000);
+    }
+
+    env.events()
+        .publish((REFUND_WINDOW_EVENT,), (course_id, caller, refund_window_seconds));
+}
+
+/// Get the refund window of a course in seconds (`0` if refunds are disabled)
+pub fn get_refund_window(env: &Env, course_id: &String) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RefundWindow(course_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Drop a course the student is enrolled in
+///
+/// Removes the access entry like a revocation, recording `RevokeReason::Unenrolled`
+/// and keeping the enrollment history. When the student drops purchased
+/// access within the course's refund window, a `refund` event naming the
+/// payer is emitted for the payment side to act on. Free grants, trials,
+/// and suspended or expired access are never refunded.
+/// Returns `true` if a refund was triggered.
+pub fn unenroll(env: Env, student: Address, course_id: String) -> bool {
+    student.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    // Removing the access also forgets its purchase, so look it up first
+    let purchase: Option<Purchase> = load_access(&env, &DataKey::CourseAccess(course_id.clone(), student.clone()))
+        .and_then(|access| get_purchase(&env, access.enrollment_id));
+
+    let access: CourseAccess = match remove_access_entry(
+        &env,
+        &course_id,
+        &student,
+        Some(student.clone()),
+        RevokeReason::Unenrolled,
+        None,
+    ) {
+        Some(access) => access,
+        None => handle_error(&env, Error::UserNoAccessCourse),
+    };
+
+    let now: u64 = env.ledger().timestamp();
+    env.events().publish(
+        (COURSE_ACCESS_REVOKE_EVENT,),
+        AccessRevokedEvent {
+            course_id: course_id.clone(),
+            user: student.clone(),
+            actor: Some(student.clone()),
+            reason: RevokeReason::Unenrolled,
+            enrollment_id: access.enrollment_id,
+            seq: next_access_event_seq(&env),
+            timestamp: now,
+        },
+    );
+
+    let purchase: Purchase = match purchase {
+        Some(purchase) if !access.is_trial && !access.suspended && !is_access_expired(&env, &access) => purchase,
+        _ => return false,
+    };
+    let refund_window: u64 = get_refund_window(&env, &course_id);
+    if now.saturating_sub(access.granted_at) >= refund_window {
+        return false;
+    }
+
+    env.events().publish(
+        (REFUND_EVENT, purchase.payer.clone()),
+        (course_id, student, purchase.payer, purchase.amount, access.granted_at),
+    );
+
+    true
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::enrollment_window::require_enrollment_open;
+use crate::functions::grant_access::{full_access, has_access, store_access, validate_course_id};
+use crate::schema::{DataKey, Voucher};
+
+const CREATE_VOUCHER_EVENT: Symbol = symbol_short!("vchCreate");
+const REDEEM_VOUCHER_EVENT: Symbol = symbol_short!("vchRedeem");
+const REVOKE_VOUCHER_EVENT: Symbol = symbol_short!("vchRevoke");
+
+/// Create a voucher that grants access to a course
+///
+/// The voucher can be redeemed `max_uses` times, by different students,
+/// until `expires_at`. Only the SHA-256 hash of the code is stored.
+/// The caller must be an admin or the creator of the course.
+pub fn create_voucher(
+    env: Env,
+    creator: Address,
+    course_id: String,
+    code_hash: BytesN<32>,
+    max_uses: u32,
+    expires_at: u64,
+) {
+    creator.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    if !is_admin(&env, &creator) && !is_course_creator(&env, &course_id, &creator) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    if max_uses == 0 || expires_at <= env.ledger().timestamp() {
+        handle_error(&env, Error::InvalidVoucher)
+    }
+
+    let key: DataKey = DataKey::Voucher(course_id.clone(), code_hash.clone());
+    if env.storage().persistent().has(&key) {
+        handle_error(&env, Error::VoucherAlreadyExists)
+    }
+
+    let voucher: Voucher = Voucher {
+        course_id: course_id.clone(),
+        code_hash: code_hash.clone(),
+        creator: creator.clone(),
+        max_uses,
+        remaining_uses: max_uses,
+        expires_at,
+        revoked: false,
+    };
+    env.storage().persistent().set(&key, &voucher);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events()
+        .publish((CREATE_VOUCHER_EVENT, course_id), (creator, code_hash, max_uses, expires_at));
+}
+
+/// Redeem a voucher to get access to a course
+///
+/// The code is hashed with SHA-256 and must match a voucher of the course.
+/// Each student can redeem a given voucher once, and only while it is not
+/// revoked, expired or used up.
+pub fn redeem_voucher(env: Env, student: Address, course_id: String, code_preimage: Bytes) {
+    student.require_auth();
+
+    validate_course_id(&env, &course_id);
+
+    let code_hash: BytesN<32> = env.crypto().sha256(&code_preimage).into();
+    let key: DataKey = DataKey::Voucher(course_id.clone(), code_hash.clone());
+    let mut voucher: Voucher = match env.storage().persistent().get(&key) {
+        Some(voucher) => voucher,
+        None => handle_error(&env, Error::VoucherNotFound),
+    };
+
+    if voucher.revoked {
+        handle_error(&env, Error::VoucherRevoked)
+    }
+    if env.ledger().timestamp() >= voucher.expires_at {
+        handle_error(&env, Error::VoucherExpired)
+    }
+    if voucher.remaining_uses == 0 {
+        handle_error(&env, Error::VoucherExhausted)
+    }
+    require_enrollment_open(&env, &course_id);
+
+    let redemption_key: DataKey =
+        DataKey::VoucherRedemption(course_id.clone(), code_hash.clone(), student.clone());
+    if env.storage().persistent().has(&redemption_key) {
+        handle_error(&env, Error::VoucherAlreadyRedeemed)
+    }
+
+    if has_access(&env, &course_id, &student) {
+        handle_error(&env, Error::UserAlreadyHasAccess)
+    }
+
+    voucher.remaining_uses -= 1;
+    env.storage().persistent().set(&key, &voucher);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.storage().persistent().set(&redemption_key, &true);
+    env.storage().persistent().extend_ttl(&redemption_key, 100, 1000);
+
+    store_access(
+        &env,
+        full_access(&env, course_id.clone(), student.clone(), Some(voucher.creator)),
+    );
+
+    env.events().publish(
+        (REDEEM_VOUCHER_EVENT, course_id),
+        (student, code_hash, voucher.remaining_uses),
+    );
+}
+
+/// Revoke a voucher so it can no longer be redeemed
+///
+/// Access already granted through the voucher is kept.
+/// The caller must be the creator of the voucher or an admin.
+pub fn revoke_voucher(env: Env, caller: Address, course_id: String, code_hash: BytesN<32>) {
+    caller.require_auth();
+
+    let key: DataKey = DataKey::Voucher(course_id.clone(), code_hash.clone());
+    let mut voucher: Voucher = match env.storage().persistent().get(&key) {
+        Some(voucher) => voucher,
+        None => handle_error(&env, Error::VoucherNotFound),
+    };
+
+    if voucher.creator != caller && !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    voucher.revoked = true;
+    env.storage().persistent().set(&key, &voucher);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events()
+        .publish((REVOKE_VOUCHER_EVENT, course_id), (caller, code_hash));
+}
+
+/// Get a voucher of a course by its code hash
+pub fn get_voucher(env: Env, course_id: String, code_hash: BytesN<32>) -> Option<Voucher> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Voucher(course_id, code_hash))
+}
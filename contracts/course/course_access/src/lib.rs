@@ -13,10 +13,10 @@ mod schema;
 #[cfg(test)]
 mod test;
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String, Vec};
 
-use functions::{config::initialize,config::set_contract_addrs, grant_access::course_access_grant_access, revoke_access::course_access_revoke_access, revoke_all_access::revoke_all_access, save_profile::save_user_profile, list_user_courses::list_user_courses, list_course_access::course_access_list_course_access, contract_versioning::{is_version_compatible, get_migration_status, get_version_history, migrate_access_data}, transfer_course_access::transfer_course_access};
-use schema::{CourseUsers, UserCourses};
+use functions::{access_consistency::repair_access_consistency, access_event_seq::get_access_event_seq, access_ttl::extend_access_ttl, backup_recovery::{export_access_data, import_access_data}, ban_check::{get_ban_check, set_ban_check}, bundle_access::grant_bundle_access, certificate::{anchor_certificate, get_certificate, verify_certificate}, config::initialize,config::set_contract_addrs, config::{decommission, get_notify_addr, get_owner, get_payment_token, is_decommissioned, is_initialized, set_notify_addr, set_payment_token}, enrollment_limit::{get_user_enrollment_limit, set_user_enrollment_limit}, enrollment_window::{clear_enrollment_window, get_enrollment_window, set_enrollment_window}, enrollment_range::{count_enrollments_between, list_enrollments_between}, grant_access::{course_access_grant_access, grant_access_if_absent, has_access}, profile_requirement::{get_require_profile_for_access, set_require_profile_for_access}, grant_rate_limit::{get_grant_rate_limit_config, get_grant_rate_limit_status, set_grant_rate_limit, set_grant_rate_limit_exempt}, granters::{add_granter, list_granters, remove_granter}, revoke_access::{course_access_revoke_access, revoke_access_with_reason}, revoke_all_access::revoke_all_access, remove_user_courses::remove_user_from_all_courses, course_deleted::{handle_course_deleted, on_course_deleted}, suspend_access::{get_access_details, resume_access, suspend_access}, save_profile::save_user_profile, delete_profile::delete_user_profile, denylist::{add_to_denylist, get_denylist, get_denylist_entry, is_denylisted, remove_from_denylist}, get_profile::{get_user_profile, has_user_profile}, gift_enrollment::gift_enrollment, list_user_courses::{list_user_courses, list_user_courses_by_recency, list_user_courses_detailed}, module_progress::{get_progress, mark_module_completed}, prerequisite_check::check_prerequisites_met, list_course_access::{course_access_list_course_access, list_course_access_detailed}, access_role::set_access_role, access_control::is_admin, admin_list::{add_admin, get_admins, remove_admin}, purge_expired_access::{purge_expired_access, sweep_expired_access}, recent_grants::get_recent_grants, contract_versioning::{is_version_compatible, get_migration_status, get_version_history, migrate_access_data, set_migration_in_progress, upgrade}, transfer_course_access::{transfer_all_access, transfer_course_access}, trial_access::{convert_trial_to_full, grant_trial_access}, unenroll::{get_refund_window, set_refund_window, unenroll}, voucher::{create_voucher, get_voucher, redeem_voucher, revoke_voucher}, invite::{accept_invite, create_invite, get_invite, revoke_invite}, merkle_enrollment::{claim_enrollment, get_enrollment_merkle_root, set_enrollment_merkle_root}, enrollment_history::{get_enrollment_history, was_ever_enrolled}, enrollment_id::get_access_by_enrollment_id, enrollment_counts::{get_course_access_stats, get_platform_access_stats, get_total_enrollments, get_user_course_count}, course_completion::{get_completion, has_completed, list_completions_for_course, list_user_completions, mark_course_completed}};
+use schema::{AccessBackupData, BanCheckConfig, BundleGrantResult, AccessImportMode, AccessStats, CourseAccessStats, AccessRole, CertificateAnchor, Completion, Course, CourseAccess, CourseId, DenylistEntry, CourseUsers, EnrollmentRecord, EnrollmentWindow, GrantRateLimitConfig, GrantRateLimitStatus, Invite, ProgressSummary, RecentGrant, RevokeReason, UserCourses, UserProfile, Voucher};
 
 /// Course Access Contract
 ///
@@ -62,6 +62,7 @@ impl CourseAccessContract {
     /// * **Double initialization**: Will panic if called more than once
     /// * **Invalid addresses**: Contract addresses must be valid
     /// * **Deployment only**: Should only be called during contract deployment
+    /// * **Event**: Publishes `init` with the owner and both contract addresses
     pub fn initialize(
         env: Env,
         caller: Address,
@@ -74,18 +75,19 @@ impl CourseAccessContract {
     /// Grant access to a specific user for a given course.
     ///
     /// Allows a user to access a specific course. Only authorized users
-    /// (course creators or admins) can grant access.
+    /// (admins, the course creator or its granting delegates) can grant access.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
+    /// * `caller` - The address granting access (must be admin, course creator or a granting delegate)
     /// * `course_id` - The unique identifier of the course
     /// * `user` - The address of the user to grant access to
     ///
     /// # Panics
     ///
     /// * If course doesn't exist
-    /// * If caller is not authorized (not course creator or admin)
+    /// * If caller is not authorized (not admin, course creator or granting delegate)
     /// * If user already has access
     ///
     /// # Examples
@@ -94,6 +96,7 @@ impl CourseAccessContract {
     /// // Course creator granting access
     /// contract.grant_access(
     ///     env.clone(),
+    ///     creator_address,
     ///     "course_123".try_into().unwrap(),
     ///     student_address
     /// );
@@ -101,6 +104,7 @@ impl CourseAccessContract {
     /// // Admin granting access
     /// contract.grant_access(
     ///     env.clone(),
+    ///     admin_address,
     ///     "course_456".try_into().unwrap(),
     ///     student_address
     /// );
@@ -108,322 +112,2604 @@ impl CourseAccessContract {
     ///
     /// # Edge Cases
     ///
-    /// * **Already has access**: Will panic if user already has access
+    /// * **Already has access**: Will panic with `UserAlreadyHasAccess` (error code 1);
+    ///   use `grant_access_if_absent` for retry-safe grants
+    /// * **Expired or revoked access**: Does not count as access; re-granting succeeds
     /// * **Non-existent course**: Will panic if course doesn't exist
-    /// * **Permission denied**: Only course creators and admins can grant access
+    /// * **Permission denied**: Only admins, the course creator and its granting
+    ///   delegates can grant access
+    /// * **Granter**: The caller is recorded as the granter and counts against
+    ///   the grant rate limit
     /// * **User validation**: User address must be valid
-    pub fn grant_access(env: Env, course_id: String, user: Address) {
-        course_access_grant_access(env, course_id, user)
+    pub fn grant_access(env: Env, caller: Address, course_id: String, user: Address) {
+        course_access_grant_access(env, caller, course_id, user)
     }
 
-    /// Revoke access for a specific user from a course.
+    /// Grant access to a course unless the user already has it.
     ///
-    /// Removes a user's access to a specific course. Only authorized users
-    /// (course creators or admins) can revoke access.
+    /// Retry-safe variant of `grant_access`: when the user already has access,
+    /// storage is left untouched and `false` is returned instead of failing.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
+    /// * `caller` - The address granting access (must be admin, course creator or a granting delegate)
     /// * `course_id` - The unique identifier of the course
-    /// * `user` - The address of the user to revoke access from
+    /// * `user` - The address of the user to grant access to
+    /// * `bypass_limit` - Ignore the per-user enrollment limit and profile requirement (admins only)
     ///
     /// # Returns
     ///
-    /// Returns `true` if access was successfully revoked, `false` otherwise.
+    /// Returns `true` if access was granted, `false` if the user already had access.
     ///
     /// # Panics
     ///
-    /// * If course doesn't exist
-    /// * If caller is not authorized (not course creator or admin)
+    /// * If the caller is not an admin, the course creator or a granting delegate of the course
+    /// * If `bypass_limit` is set by a caller who is not an admin
+    /// * If the user is at the enrollment limit and `bypass_limit` is not set
+    /// * If profiles are required, the user has none and `bypass_limit` is not set
+    /// * If the course ID is empty or too long
     ///
     /// # Examples
     ///
     /// ```rust
-    /// // Revoke access from a user
-    /// let success = contract.revoke_access(
+    /// let granted = contract.grant_access_if_absent(
     ///     env.clone(),
+    ///     admin_address,
     ///     "course_123".try_into().unwrap(),
-    ///     student_address
+    ///     student_address,
+    ///     false
     /// );
-    /// 
-    /// if success {
-    ///     println!("Access revoked successfully");
-    /// } else {
-    ///     println!("User didn't have access");
-    /// }
     /// ```
     ///
     /// # Edge Cases
     ///
-    /// * **No access to revoke**: Returns `false` if user didn't have access
-    /// * **Non-existent course**: Will panic if course doesn't exist
-    /// * **Permission denied**: Only course creators and admins can revoke access
-    /// * **Idempotent**: Safe to call multiple times
-    pub fn revoke_access(env: Env, course_id: String, user: Address) -> bool {
-        course_access_revoke_access(env, course_id, user)
+    /// * **Duplicate grant**: Returns `false`, no event or history record is added
+    pub fn grant_access_if_absent(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        user: Address,
+        bypass_limit: bool,
+    ) -> bool {
+        grant_access_if_absent(env, caller, course_id, user, bypass_limit)
     }
 
-    /// Save or update a user's profile on-chain.
+    /// Only accept enrollments into a course between two timestamps.
     ///
-    /// Stores user profile information in the contract storage.
-    /// This includes personal and professional information.
+    /// Applies to enrollments students start themselves: accepting an invite,
+    /// redeeming a voucher and gifted (paid) enrollment. Direct grants by an
+    /// admin, the creator or a granting delegate, including trials and
+    /// bundles, ignore the window.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `name` - The user's full name
-    /// * `email` - The user's email address
-    /// * `profession` - Optional profession/job title
-    /// * `goals` - Optional learning goals or objectives
-    /// * `country` - The user's country of residence
+    /// * `caller` - An admin or the creator of the course
+    /// * `course_id` - The course
+    /// * `opens_at` - Ledger timestamp from which enrollment is open
+    /// * `closes_at` - Ledger timestamp from which enrollment is closed again
     ///
     /// # Panics
     ///
-    /// * If name, email, or country are empty
-    /// * If email format is invalid
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// // Save user profile
-    /// contract.save_user_profile(
-    ///     env.clone(),
-    ///     "John Doe".try_into().unwrap(),
-    ///     "john@example.com".try_into().unwrap(),
-    ///     Some("Software Developer".try_into().unwrap()),
-    ///     Some("Learn Rust programming".try_into().unwrap()),
-    ///     "US".try_into().unwrap()
-    /// );
-    /// 
-    /// // Save minimal profile
-    /// contract.save_user_profile(
-    ///     env.clone(),
-    ///     "Jane Smith".try_into().unwrap(),
-    ///     "jane@example.com".try_into().unwrap(),
-    ///     None,
-    ///     None,
-    ///     "CA".try_into().unwrap()
-    /// );
-    /// ```
+    /// * If the caller is neither an admin nor the course creator
+    /// * If `opens_at` is not before `closes_at` (`InvalidTimeRange`)
     ///
     /// # Edge Cases
     ///
-    /// * **Empty required fields**: Name, email, and country cannot be empty
-    /// * **Invalid email**: Email must be in valid format
-    /// * **Profile updates**: Overwrites existing profile data
-    /// * **Optional fields**: Profession and goals can be None
-    pub fn save_user_profile(
-        env: Env,
-        name: String,
-        email: String,
-        profession: Option<String>,
-        goals: Option<String>,
-        country: String,
-    ) {
-        let user: Address = env.current_contract_address();
-        save_user_profile(env, name, email, profession, goals, country, user);
+    /// * **Outside the window**: Enrollment fails with `EnrollmentClosed`
+    /// * **Existing window**: Replaced
+    pub fn set_enrollment_window(env: Env, caller: Address, course_id: String, opens_at: u64, closes_at: u64) {
+        set_enrollment_window(env, caller, course_id, opens_at, closes_at)
     }
 
-    /// List all courses a user has access to.
+    /// Remove the enrollment window of a course, so enrollment is always open.
     ///
-    /// Retrieves all courses that the specified user is enrolled in
-    /// or has been granted access to.
+    /// # Panics
+    ///
+    /// * If the caller is neither an admin nor the course creator
+    pub fn clear_enrollment_window(env: Env, caller: Address, course_id: String) {
+        clear_enrollment_window(env, caller, course_id)
+    }
+
+    /// Get the enrollment window of a course, or `None` if enrollment is always open.
+    pub fn get_enrollment_window(env: Env, course_id: String) -> Option<EnrollmentWindow> {
+        get_enrollment_window(&env, &course_id)
+    }
+
+    /// Grant a user access to a bundle of courses in one call.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `user` - The address of the user to query
+    /// * `caller` - An admin, or the creator, a co-creator or a granting delegate of every course
+    /// * `course_ids` - The courses of the bundle, at most 20
+    /// * `user` - The user to grant access to
+    /// * `atomic` - Abort on the first failing course instead of skipping it
     ///
     /// # Returns
     ///
-    /// Returns a `UserCourses` struct containing the list of accessible courses.
+    /// Returns how many courses were granted and the IDs of those that were not.
     ///
-    /// # Examples
+    /// # Panics
     ///
-    /// ```rust
-    /// // Get user's accessible courses
-    /// let user_courses = contract.list_user_courses(env.clone(), user_address);
-    /// 
-    /// for course_id in user_courses.course_ids {
-    ///     println!("User has access to course: {}", course_id);
-    /// }
-    /// ```
+    /// * If more than 20 courses are given (`InvalidLimit`)
+    /// * In atomic mode, with the error of the first failing course
     ///
     /// # Edge Cases
     ///
-    /// * **No access**: Returns empty list if user has no course access
-    /// * **Non-existent user**: Returns empty list for non-existent users
-    /// * **Public access**: Anyone can query user courses
-    /// * **Revoked courses**: Only includes currently accessible courses
-    pub fn list_user_courses(env: Env, user: Address) -> UserCourses {
-        list_user_courses(env, user)
+    /// * **Unpublished, archived or unknown courses**: Fail
+    /// * **Existing access**: Counts as failed, and aborts an atomic bundle
+    /// * **Atomic failure**: Nothing is granted, including courses before the failing one
+    pub fn grant_bundle_access(
+        env: Env,
+        caller: Address,
+        course_ids: Vec<String>,
+        user: Address,
+        atomic: bool,
+    ) -> BundleGrantResult {
+        grant_bundle_access(env, caller, course_ids, user, atomic)
     }
 
-    /// List all users who have access to a course.
+    /// Set the maximum number of courses a user can be enrolled in.
     ///
-    /// Retrieves all users who have been granted access to the specified course.
+    /// Enforced by every grant path, including self-enrollment, trials,
+    /// vouchers and invites. Admins can bypass it with `grant_access_if_absent`.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `course_id` - The unique identifier of the course
+    /// * `caller` - The admin setting the limit
+    /// * `limit` - The maximum number of courses per user, or 0 for no limit
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Lowering the limit**: Existing enrollments are kept; only new grants are blocked
+    /// * **Inactive access**: Expired and suspended courses still count until purged
+    pub fn set_user_enrollment_limit(env: Env, caller: Address, limit: u32) {
+        set_user_enrollment_limit(env, caller, limit)
+    }
+
+    /// Get the maximum number of courses per user.
     ///
     /// # Returns
     ///
-    /// Returns a `CourseUsers` struct containing the list of users with access.
+    /// Returns the limit, or 0 if enrollments per user are unlimited.
+    pub fn get_user_enrollment_limit(env: Env) -> u32 {
+        get_user_enrollment_limit(env)
+    }
+
+    /// Set or clear the user profile contract asked whether users are banned.
     ///
-    /// # Examples
+    /// While set, every grant path asks the contract's `is_banned(user)` view
+    /// and refuses banned users platform-wide with `UserBanned`, even for
+    /// admin grants.
     ///
-    /// ```rust
-    /// // Get all users with access to a course
-    /// let course_users = contract.list_course_access(env.clone(), "course_123".try_into().unwrap());
-    /// 
-    /// for user in course_users.users {
-    ///     println!("User with access: {}", user);
-    /// }
-    /// ```
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    /// * `profile_contract` - The user profile contract, or `None` to disable the check
+    /// * `fail_open` - Whether grants go ahead when the profile contract cannot answer
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the contract owner
     ///
     /// # Edge Cases
     ///
-    /// * **No users**: Returns empty list if no users have access
-    /// * **Non-existent course**: Returns empty list for non-existent courses
-    /// * **Public access**: Anyone can query course access
-    /// * **Real-time data**: Always returns current access status
-    pub fn list_course_access(env: Env, course_id: String) -> CourseUsers {
-        course_access_list_course_access(env, course_id)
+    /// * **Unreachable contract**: Grants go ahead if `fail_open`, otherwise they fail with `UserBanned`
+    /// * **Existing access**: Banning a user does not revoke access they already have
+    pub fn set_ban_check(env: Env, caller: Address, profile_contract: Option<Address>, fail_open: bool) {
+        set_ban_check(env, caller, profile_contract, fail_open)
     }
 
-    /// Revoke all user access for a course.
+    /// Get the ban check configuration, or `None` if the check is disabled.
+    pub fn get_ban_check(env: Env) -> Option<BanCheckConfig> {
+        get_ban_check(&env)
+    }
+
+    /// Set whether users need a saved profile before they can be granted access.
     ///
-    /// Removes access for all users from the specified course.
-    /// Only admin or course creator is allowed to perform this operation.
+    /// When enabled, every grant path (grants, trials, vouchers, invites and
+    /// gifts) fails with `ProfileRequired` for users without a profile.
+    /// Admins can bypass the check with the `bypass_limit` flag of
+    /// `grant_access_if_absent`.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `user` - The address of the user requesting the operation
-    /// * `course_id` - The unique identifier of the course
+    /// * `caller` - The admin changing the setting
+    /// * `required` - `true` to require a profile, `false` to stop requiring one
     ///
-    /// # Returns
+    /// # Panics
     ///
-    /// Returns the number of users affected by the revocation and emits an event.
+    /// * If the caller is not an admin
     ///
-    /// # Panics
+    /// # Edge Cases
     ///
-    /// * If course doesn't exist
-    /// * If caller is not authorized (not course creator or admin)
+    /// * **Existing access**: Access granted before the setting was enabled is kept
+    pub fn set_require_profile_for_access(env: Env, caller: Address, required: bool) {
+        set_require_profile_for_access(env, caller, required)
+    }
+
+    /// Get whether users need a saved profile before they can be granted access.
+    pub fn get_require_profile_for_access(env: Env) -> bool {
+        get_require_profile_for_access(&env)
+    }
+
+    /// Set the rate limit on grants made by each granter.
     ///
-    /// # Examples
+    /// Every grant that records a granter counts against that granter:
+    /// delegated grants, trials, voucher redemptions (counted against the
+    /// voucher creator) and invites (against the inviter). Gifts are paid
+    /// for and never count. Grants are not rate limited until this is
+    /// first called.
     ///
-    /// ```rust
-    /// // Revoke all access for a course
-    /// let affected_users = contract.revoke_all_access(
-    ///     env.clone(),
-    ///     admin_address,
-    ///     "course_123".try_into().unwrap()
-    /// );
-    /// 
-    /// println!("Revoked access for {} users", affected_users);
-    /// ```
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The admin setting the limit
+    /// * `window_seconds` - Length of the rate limiting window in seconds
+    /// * `max_grants_per_window` - Maximum grants per granter per window
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    /// * If either value is 0
     ///
     /// # Edge Cases
     ///
-    /// * **No users**: Returns 0 if no users had access
-    /// * **Non-existent course**: Will panic if course doesn't exist
-    /// * **Permission denied**: Only course creators and admins can perform this
-    /// * **Bulk operation**: Efficiently removes all access in one transaction
-    pub fn revoke_all_access(env: Env, user: Address, course_id: String) -> u32 {
-        revoke_all_access(env, user, course_id)
+    /// * **Open windows**: Grants already counted stay counted; the new maximum applies immediately
+    pub fn set_grant_rate_limit(env: Env, caller: Address, window_seconds: u64, max_grants_per_window: u32) {
+        set_grant_rate_limit(env, caller, window_seconds, max_grants_per_window)
     }
 
-    /// Configure external contract addresses used for auth checks.
-    ///
-    /// Updates the addresses of external contracts that this contract
-    /// depends on for authentication and authorization checks.
+    /// Get the rate limit on grants made by each granter, or `None` if grants are not rate limited.
+    pub fn get_grant_rate_limit(env: Env) -> Option<GrantRateLimitConfig> {
+        get_grant_rate_limit_config(&env)
+    }
+
+    /// Exempt a granter from the grant rate limit, or lift the exemption.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `caller` - The address of the user making the configuration change
-    /// * `user_mgmt_addr` - Address of the user management contract
-    /// * `course_registry_addr` - Address of the course registry contract
+    /// * `caller` - The admin changing the exemption
+    /// * `granter` - The granter to exempt
+    /// * `exempt` - `true` to exempt, `false` to lift the exemption
     ///
     /// # Panics
     ///
-    /// * If caller is not the contract owner
-    /// * If any of the provided addresses are invalid
+    /// * If the caller is not an admin
+    pub fn set_grant_rate_limit_exempt(env: Env, caller: Address, granter: Address, exempt: bool) {
+        set_grant_rate_limit_exempt(env, caller, granter, exempt)
+    }
+
+    /// Get where a granter stands against the grant rate limit.
     ///
-    /// # Storage
+    /// # Returns
     ///
-    /// Stores the addresses in instance storage keys: ("user_mgmt_addr",) and ("course_registry_addr",)
+    /// Returns the grants made in the current window, the maximum, when the
+    /// window ends and whether the granter is exempt. Once a window has
+    /// elapsed the count reads 0 and the window is reported from now.
+    /// Returns `None` while no rate limit is configured.
+    pub fn get_grant_rate_limit_status(env: Env, caller: Address) -> Option<GrantRateLimitStatus> {
+        get_grant_rate_limit_status(&env, &caller)
+    }
+
+    /// Grant a user a free trial of a course.
+    ///
+    /// Creates an access entry flagged as a trial that expires `trial_seconds`
+    /// after the current ledger timestamp. Once expired, an unconverted trial
+    /// behaves as no access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address granting the trial (must be admin or course creator)
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user receiving the trial
+    /// * `trial_seconds` - Length of the trial in seconds
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin or the course creator
+    /// * If `trial_seconds` is 0
+    /// * If the user already has access to the course
+    /// * If the user has already had a trial of this course
     ///
     /// # Examples
     ///
     /// ```rust
-    /// // Update contract addresses
-    /// contract.set_config(
+    /// // 7-day free trial
+    /// contract.grant_trial_access(
     ///     env.clone(),
-    ///     contract_owner_address,
-    ///     new_user_mgmt_address,
-    ///     new_course_registry_address
+    ///     admin_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     student_address,
+    ///     7 * 24 * 60 * 60
     /// );
     /// ```
     ///
     /// # Edge Cases
     ///
-    /// * **Owner only**: Only contract owner can update addresses
-    /// * **Invalid addresses**: Will panic if addresses are invalid
-    /// * **Runtime updates**: Can be called after contract deployment
-    /// * **Immediate effect**: Changes take effect immediately
-    pub fn set_config(
-        env: Env,
-        caller: Address,
-        user_mgmt_addr: Address,
-        course_registry_addr: Address,
-    ) {
-        set_contract_addrs(env, caller, user_mgmt_addr, course_registry_addr)
+    /// * **Already has access**: Panics with `UserAlreadyHasAccess` (error code 1)
+    ///   only while that access is active
+    /// * **Expired trial**: A second trial is still rejected; use `grant_access`
+    pub fn grant_trial_access(env: Env, caller: Address, course_id: String, user: Address, trial_seconds: u64) {
+        grant_trial_access(env, caller, course_id, user, trial_seconds)
     }
 
-    /// Get the current contract version
+    /// Convert an active trial into full access.
     ///
-    /// Returns the semantic version of the current contract deployment.
-    /// This is useful for tracking contract upgrades and compatibility.
+    /// Clears the trial flag and the expiry. Meant to be called by the
+    /// payment path once the course has been paid for.
     ///
     /// # Arguments
-    /// * `_env` - The Soroban environment (unused)
     ///
-    /// # Returns
-    /// * `String` - The current contract version
-    pub fn get_contract_version(_env: Env) -> String {
-        String::from_str(&_env, VERSION)
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address converting the trial (must be admin or course creator)
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user whose trial is converted
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin or the course creator
+    /// * If the user has no access or the trial has expired
+    /// * If the user's access is not a trial
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.convert_trial_to_full(env.clone(), admin_address, "course_123".try_into().unwrap(), student_address);
+    /// ```
+    pub fn convert_trial_to_full(env: Env, caller: Address, course_id: String, user: Address) {
+        convert_trial_to_full(env, caller, course_id, user)
     }
 
-    /// Get contract version history
+    /// Buy a course for another user.
     ///
-    /// Returns a list of all versions that have been deployed for this contract.
-    /// This helps track the evolution of the contract over time.
+    /// The payer transfers the course's current price (the sale price while a
+    /// sale is running) in the configured payment token to the course creator,
+    /// and the recipient is granted full access with `granted_by` set to the
+    /// payer. Publishes a `giftEnrl` event with the course, both parties and
+    /// the amount.
     ///
     /// # Arguments
+    ///
     /// * `env` - The Soroban environment
+    /// * `payer` - The address paying for the course (must authorize)
+    /// * `course_id` - The unique identifier of the course
+    /// * `recipient` - The address receiving access
     ///
     /// # Returns
-    /// * `Vec<String>` - Vector of version strings in chronological order
-    pub fn get_version_history(env: Env) -> Vec<String> {
-        get_version_history(&env)
+    ///
+    /// Returns the amount transferred.
+    ///
+    /// # Panics
+    ///
+    /// * If the course does not exist in the registry, is unpublished or is
+    ///   archived (`CourseNotFoundInRegistry`)
+    /// * If the recipient already has access (`UserAlreadyHasAccess`)
+    /// * If the recipient is on the course denylist (`UserDenylisted`)
+    /// * If the course is paid and no payment token is set (`InvalidConfig`)
+    /// * If the payer's balance is too low (raised by the token contract)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.gift_enrollment(env.clone(), payer_address, "course_123".try_into().unwrap(), friend_address);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Free course**: No tokens move and the amount is 0
+    /// * **Enrollment limit**: The recipient's per-user limit still applies
+    pub fn gift_enrollment(env: Env, payer: Address, course_id: String, recipient: Address) -> i128 {
+        gift_enrollment(env, payer, course_id, recipient)
     }
 
-    /// Check compatibility between contract versions
+    /// Create a voucher that students can redeem for access to a course.
     ///
-    /// Determines if data from one version can be safely used with another version.
-    /// This is crucial for migration processes and backward compatibility.
+    /// Vouchers let instructors sell access off-platform: the student
+    /// redeems the code on-chain without the instructor signing each grant.
+    /// Only the SHA-256 hash of the code is stored.
     ///
     /// # Arguments
+    ///
     /// * `env` - The Soroban environment
-    /// * `from_version` - The source version to check compatibility from
-    /// * `to_version` - The target version to check compatibility to
+    /// * `creator` - The address creating the voucher (must be admin or course creator)
+    /// * `course_id` - The unique identifier of the course
+    /// * `code_hash` - SHA-256 hash of the redemption code
+    /// * `max_uses` - Number of students who can redeem the voucher
+    /// * `expires_at` - Ledger timestamp from which the voucher can no longer be redeemed
     ///
-    /// # Returns
-    /// * `bool` - True if the versions are compatible, false otherwise
+    /// # Panics
+    ///
+    /// * If the caller is not an admin or the course creator
+    /// * If `max_uses` is 0 or `expires_at` is not in the future
+    /// * If the course already has a voucher with this code hash
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let code_hash = env.crypto().sha256(&code).into();
+    /// contract.create_voucher(env.clone(), instructor, course_id, code_hash, 100, expires_at);
+    /// ```
+    pub fn create_voucher(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        code_hash: BytesN<32>,
+        max_uses: u32,
+        expires_at: u64,
+    ) {
+        create_voucher(env, creator, course_id, code_hash, max_uses, expires_at)
+    }
+
+    /// Redeem a voucher for access to a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address redeeming the voucher
+    /// * `course_id` - The unique identifier of the course
+    /// * `code_preimage` - The redemption code, hashed with SHA-256 to find the voucher
+    ///
+    /// # Panics
+    ///
+    /// * If no voucher of the course matches the code
+    /// * If the voucher is revoked, expired or has no uses left
+    /// * If the student already redeemed this voucher or already has access
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.redeem_voucher(env.clone(), student, course_id, Bytes::from_slice(&env, b"SPRING-2025"));
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Access revoked after redemption**: The same voucher cannot be redeemed again
+    pub fn redeem_voucher(env: Env, student: Address, course_id: String, code_preimage: Bytes) {
+        redeem_voucher(env, student, course_id, code_preimage)
+    }
+
+    /// Revoke a voucher so it can no longer be redeemed.
+    ///
+    /// Access already granted through the voucher is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address revoking the voucher (must be its creator or an admin)
+    /// * `course_id` - The unique identifier of the course
+    /// * `code_hash` - SHA-256 hash of the redemption code
+    ///
+    /// # Panics
+    ///
+    /// * If the voucher doesn't exist
+    /// * If the caller is neither the voucher's creator nor an admin
+    pub fn revoke_voucher(env: Env, caller: Address, course_id: String, code_hash: BytesN<32>) {
+        revoke_voucher(env, caller, course_id, code_hash)
+    }
+
+    /// Get a voucher of a course by its code hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `code_hash` - SHA-256 hash of the redemption code
+    ///
+    /// # Returns
+    ///
+    /// Returns the voucher, including its remaining uses, or `None` if it doesn't exist.
+    pub fn get_voucher(env: Env, course_id: String, code_hash: BytesN<32>) -> Option<Voucher> {
+        get_voucher(env, course_id, code_hash)
+    }
+
+    /// Invite a user to a course.
+    ///
+    /// Instead of being enrolled directly, the invitee accepts the invite
+    /// on their own schedule with `accept_invite`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address creating the invite (must be admin or course creator)
+    /// * `course_id` - The unique identifier of the course
+    /// * `invitee` - The address invited to the course
+    /// * `expires_at` - Ledger timestamp from which the invite can no longer be accepted
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin or the course creator
+    /// * If `expires_at` is not in the future
+    /// * If the invitee already has access to the course
+    ///
+    /// # Events
+    ///
+    /// Emits an `invCreate` event with the invitee as topic, so the
+    /// invitee's app can watch for invites.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.create_invite(env.clone(), instructor, course_id, student_address, expires_at);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Existing invite**: Its expiry is updated instead of creating a second invite
+    pub fn create_invite(env: Env, caller: Address, course_id: String, invitee: Address, expires_at: u64) {
+        create_invite(env, caller, course_id, invitee, expires_at)
+    }
+
+    /// Accept an invite to a course.
+    ///
+    /// Grants the invitee access and removes the invite.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `invitee` - The invited address (must authorize)
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Panics
+    ///
+    /// * If there is no invite for the invitee
+    /// * If the invite has expired
+    /// * If the invitee already has access to the course
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.accept_invite(env.clone(), student_address, course_id);
+    /// ```
+    pub fn accept_invite(env: Env, invitee: Address, course_id: String) {
+        accept_invite(env, invitee, course_id)
+    }
+
+    /// Set the Merkle root of the cohort allowed to claim a seat in a course.
+    ///
+    /// Lets a cohort enrolled off-chain claim access with `claim_enrollment`
+    /// instead of being granted one by one. Each leaf is the SHA-256 of a
+    /// student's address in XDR, and pairs are hashed in ascending byte order.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - An admin or the creator of the course
+    /// * `course_id` - The unique identifier of the course
+    /// * `root` - The Merkle root of the cohort
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither an admin nor the course creator
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Root rotation**: Proofs against the old root stop working; claimed seats are kept
+    pub fn set_enrollment_merkle_root(env: Env, caller: Address, course_id: String, root: BytesN<32>) {
+        set_enrollment_merkle_root(env, caller, course_id, root)
+    }
+
+    /// Get the Merkle root of the cohort of a course, or `None` if none is set.
+    pub fn get_enrollment_merkle_root(env: Env, course_id: String) -> Option<BytesN<32>> {
+        get_enrollment_merkle_root(&env, &course_id)
+    }
+
+    /// Claim a seat in a course with a Merkle proof of cohort membership.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The student claiming the seat (must authorize)
+    /// * `course_id` - The unique identifier of the course
+    /// * `proof` - The sibling hashes from the student's leaf up to the root
+    ///
+    /// # Panics
+    ///
+    /// * If the course has no root, the proof is wrong or the seat was already claimed (`InvalidEnrollmentProof`)
+    /// * If the course's enrollment window is closed
+    /// * If the student already has access to the course
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Single-student cohort**: The root is the leaf and the proof is empty
+    pub fn claim_enrollment(env: Env, student: Address, course_id: String, proof: Vec<BytesN<32>>) {
+        claim_enrollment(env, student, course_id, proof)
+    }
+
+    /// Withdraw a pending invite.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address revoking the invite (must be admin or course creator)
+    /// * `course_id` - The unique identifier of the course
+    /// * `invitee` - The invited address
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin or the course creator
+    /// * If there is no invite for the invitee
+    pub fn revoke_invite(env: Env, caller: Address, course_id: String, invitee: Address) {
+        revoke_invite(env, caller, course_id, invitee)
+    }
+
+    /// Get the pending invite of a user for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `invitee` - The invited address
+    ///
+    /// # Returns
+    ///
+    /// Returns the invite, or `None` if there is none.
+    pub fn get_invite(env: Env, course_id: String, invitee: Address) -> Option<Invite> {
+        get_invite(env, course_id, invitee)
+    }
+
+    /// Check whether a user currently has access to a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the user has unexpired access to the course.
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Expired trial or access**: Returns `false`
+    pub fn has_access(env: Env, course_id: String, user: Address) -> bool {
+        has_access(&env, &course_id, &user)
+    }
+
+    /// Extend the storage TTL of a user's access to a course.
+    ///
+    /// Extends the access record and the user and course access indexes to
+    /// `ACCESS_TTL_EXTEND_TO` ledgers, so long-running courses do not expire.
+    /// Reads already extend entries that are close to expiring.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user
+    ///
+    /// # Panics
+    ///
+    /// * If the user has no access record for the course
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.extend_access_ttl(env.clone(), course_id, user);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No authorization required**: Anyone may pay for the extension
+    /// * **Expired or suspended access**: The record is still extended
+    pub fn extend_access_ttl(env: Env, course_id: String, user: Address) {
+        extend_access_ttl(env, course_id, user)
+    }
+
+    /// Revoke access for a specific user from a course.
+    ///
+    /// Removes a user's access to a specific course. Only authorized users
+    /// (admins, the course creator or its granting delegates) can revoke access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address revoking access (must be admin, course creator or a granting delegate)
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user to revoke access from
+    /// * `reason_hash` - Optional hash of the reason for the revocation (at most 128 characters),
+    ///   stored on the enrollment record
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if access was successfully revoked, `false` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// * If course doesn't exist
+    /// * If caller is not authorized (not course creator or admin)
+    /// * If `reason_hash` is empty or too long
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Revoke access from a user
+    /// let success = contract.revoke_access(
+    ///     env.clone(),
+    ///     admin_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     student_address,
+    ///     Some(refund_reason_hash)
+    /// );
+    /// 
+    /// if success {
+    ///     println!("Access revoked successfully");
+    /// } else {
+    ///     println!("User didn't have access");
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No access to revoke**: Returns `false` if user didn't have access
+    /// * **Non-existent course**: Will panic if course doesn't exist
+    /// * **Permission denied**: Only admins, the course creator and its granting
+    ///   delegates can revoke access
+    /// * **Idempotent**: Revoking access that is already gone returns `false`
+    ///   instead of failing
+    pub fn revoke_access(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        user: Address,
+        reason_hash: Option<String>,
+    ) -> bool {
+        course_access_revoke_access(env, caller, course_id, user, reason_hash)
+    }
+
+    /// Revoke a user's access to a course, recording why it was revoked.
+    ///
+    /// Behaves like `revoke_access`, which records `RevokeReason::Unspecified`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address revoking access (must be admin, course creator or a granting delegate)
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user to revoke access from
+    /// * `reason` - Why the access is revoked (refund, violation, expiry)
+    /// * `reason_hash` - Optional hash of the reason for the revocation (at most 128 characters)
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if access was successfully revoked, `false` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not authorized (not admin, course creator or granting delegate)
+    /// * If `reason_hash` is empty or too long
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Audit trail**: `reason` is stored on the enrollment record and
+    ///   included in the `crsAcRvk` event
+    /// * **Expired access**: Purging or replacing expired access records `RevokeReason::Expiry`
+    pub fn revoke_access_with_reason(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        user: Address,
+        reason: RevokeReason,
+        reason_hash: Option<String>,
+    ) -> bool {
+        revoke_access_with_reason(env, caller, course_id, user, reason, reason_hash)
+    }
+
+    /// Let a delegate grant and revoke access to one course.
+    ///
+    /// Delegates, such as teaching assistants, may call `grant_access`,
+    /// `grant_access_if_absent` and `revoke_access` for this course only.
+    /// They gain no rights in the course registry or on other courses.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator (must authorize)
+    /// * `course_id` - The unique identifier of the course
+    /// * `delegate` - The address to delegate granting rights to
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the course creator
+    /// * If the course already has 20 delegates (`TooManyGranters`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.add_granter(env.clone(), creator_address, "course_123".try_into().unwrap(), assistant_address);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Existing delegate**: Adding again is a no-op
+    pub fn add_granter(env: Env, creator: Address, course_id: String, delegate: Address) {
+        add_granter(env, creator, course_id, delegate)
+    }
+
+    /// Take away a delegate's granting rights for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator (must authorize)
+    /// * `course_id` - The unique identifier of the course
+    /// * `delegate` - The delegate to remove
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the delegate was removed, `false` if it was not a delegate.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the course creator
+    pub fn remove_granter(env: Env, creator: Address, course_id: String, delegate: Address) -> bool {
+        remove_granter(env, creator, course_id, delegate)
+    }
+
+    /// List the delegates allowed to grant and revoke access to a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the delegates in the order they were added.
+    pub fn list_granters(env: Env, course_id: String) -> Vec<Address> {
+        list_granters(&env, &course_id)
+    }
+
+    /// Drop a course as the enrolled student.
+    ///
+    /// Removes the student's access like `revoke_access`, recording
+    /// `RevokeReason::Unenrolled` with the student as the actor. The
+    /// enrollment history is preserved. If the access was purchased less than
+    /// the course's refund window ago, a `refund` event with the course, the
+    /// student, the payer, the amount paid and the enrollment time is
+    /// published, with the payer as topic.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The enrolled student (must authorize)
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a refund was triggered, `false` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// * If the student has no access entry for the course (`UserNoAccessCourse`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let refunded = contract.unenroll(env.clone(), student_address, "course_123".try_into().unwrap());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Outside the window**: The student is still removed, without a refund event
+    /// * **Trial access**: Never triggers a refund
+    /// * **Free or transferred access**: Never triggers a refund, as nothing was paid for it
+    /// * **Suspended or expired access**: Never triggers a refund
+    pub fn unenroll(env: Env, student: Address, course_id: String) -> bool {
+        unenroll(env, student, course_id)
+    }
+
+    /// Set the refund window of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address setting the window (must be admin or course creator)
+    /// * `course_id` - The unique identifier of the course
+    /// * `refund_window_seconds` - How long after enrolling `unenroll` triggers a refund; `0` disables refunds
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin or the course creator
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Refunds within 14 days
+    /// contract.set_refund_window(env.clone(), creator_address, "course_123".try_into().unwrap(), 14 * 24 * 60 * 60);
+    /// ```
+    pub fn set_refund_window(env: Env, caller: Address, course_id: String, refund_window_seconds: u64) {
+        set_refund_window(env, caller, course_id, refund_window_seconds)
+    }
+
+    /// Get the refund window of a course in seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the window in seconds, or `0` if refunds are disabled.
+    pub fn get_refund_window(env: Env, course_id: String) -> u64 {
+        get_refund_window(&env, &course_id)
+    }
+
+    /// Check whether a user has ever been enrolled in a course.
+    ///
+    /// Unlike the active-access queries, this keeps returning `true` after
+    /// the user's access has been revoked.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if access was granted at least once, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let enrolled_before = contract.was_ever_enrolled(
+    ///     env.clone(),
+    ///     "course_123".try_into().unwrap(),
+    ///     student_address
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Revoked access**: Still returns `true`
+    /// * **Never enrolled**: Returns `false`
+    pub fn was_ever_enrolled(env: Env, course_id: String, user: Address) -> bool {
+        was_ever_enrolled(env, course_id, user)
+    }
+
+    /// Get the enrollment history of a user in a course.
+    ///
+    /// Returns one record per grant, oldest first. Records of revoked access
+    /// carry the revocation timestamp and, when known, the revoking address
+    /// and the hash of the revocation reason.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user to query
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of `EnrollmentRecord` entries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let history = contract.get_enrollment_history(
+    ///     env.clone(),
+    ///     "course_123".try_into().unwrap(),
+    ///     student_address
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Never enrolled**: Returns an empty vector
+    /// * **Re-granted access**: Each grant appends a new record
+    /// * **Bounded size**: Only the most recent records are kept
+    pub fn get_enrollment_history(env: Env, course_id: String, user: Address) -> Vec<EnrollmentRecord> {
+        get_enrollment_history(env, course_id, user)
+    }
+
+    /// Look up an access record by its enrollment ID.
+    ///
+    /// Every grant gets a unique, increasing enrollment ID, carried by the
+    /// access record, its enrollment history record and the grant, revoke
+    /// and transfer events. Unlike the (course, user) pair, it is never
+    /// reused when access is revoked and granted again.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `enrollment_id` - The enrollment ID of the grant
+    ///
+    /// # Returns
+    ///
+    /// Returns the access record created by that grant, or `None` if the ID
+    /// is unknown or the enrollment has ended.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// if let Some(access) = contract.get_access_by_enrollment_id(env.clone(), 42) {
+    ///     // The enrollment is still active
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Revoked or transferred access**: Returns `None`; the enrollment
+    ///   history keeps the ID
+    pub fn get_access_by_enrollment_id(env: Env, enrollment_id: u64) -> Option<CourseAccess> {
+        get_access_by_enrollment_id(&env, enrollment_id)
+    }
+
+    /// Attest that a student completed a course.
+    ///
+    /// Stores an on-chain completion record that certificates can reference.
+    /// Only the course creator or an admin can attest a completion.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The creator or admin attesting the completion
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The student who completed the course
+    /// * `completion_hash` - Hash of the completion evidence (1-128 characters)
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored `Completion` record.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither an admin nor the course creator
+    /// * If the student never had access to the course
+    /// * If the completion hash is empty or too long
+    /// * If the student has already completed the course
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let completion = contract.mark_course_completed(
+    ///     env.clone(),
+    ///     creator_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     student_address,
+    ///     "9f86d081884c7d65".try_into().unwrap(),
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Revoked access**: Students whose access was revoked or expired can
+    ///   still be marked completed
+    /// * **Duplicates**: A completion is immutable once recorded
+    pub fn mark_course_completed(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        student: Address,
+        completion_hash: String,
+    ) -> Completion {
+        mark_course_completed(env, caller, course_id, student, completion_hash)
+    }
+
+    /// Get the completion record of a student for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The student's address
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Completion` record, or `None` if the course was not completed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// if let Some(completion) = contract.get_completion(env.clone(), course_id, student) {
+    ///     // Issue the certificate
+    /// }
+    /// ```
+    pub fn get_completion(env: Env, course_id: String, student: Address) -> Option<Completion> {
+        get_completion(env, course_id, student)
+    }
+
+    /// Check whether a student has completed a course.
+    ///
+    /// Cheap and non-panicking, so other contracts can gate prerequisites on
+    /// completion rather than mere access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The student's address
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a completion has been recorded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// if contract.has_completed(env.clone(), prerequisite_id, student) {
+    ///     // Allow enrollment in the follow-up course
+    /// }
+    /// ```
+    pub fn has_completed(env: Env, course_id: String, student: Address) -> bool {
+        has_completed(env, course_id, student)
+    }
+
+    /// List the prerequisites a user still has to complete before a course.
+    ///
+    /// Combines the registry's transitive prerequisite closure with the
+    /// completion records of this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The course the user wants to take
+    /// * `user` - The address of the learner
+    ///
+    /// # Returns
+    ///
+    /// Returns the unmet prerequisites nearest first, or an empty list if all are met.
+    ///
+    /// # Panics
+    ///
+    /// * If the course ID is empty or too long
+    /// * If the course registry cannot be reached
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let unmet = contract.check_prerequisites_met(env.clone(), course_id, student);
+    /// if !unmet.is_empty() {
+    ///     // Show "complete these first"
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Indirect prerequisites**: Prerequisites of prerequisites are checked too
+    /// * **No prerequisites**: Returns an empty list
+    pub fn check_prerequisites_met(env: Env, course_id: String, user: Address) -> Vec<CourseId> {
+        check_prerequisites_met(env, course_id, user)
+    }
+
+    /// List the completions of a course, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `limit` - Optional page size (default 10, capped at 100)
+    /// * `offset` - Optional number of completions to skip
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of `Completion` records.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let page = contract.list_completions_for_course(env.clone(), course_id, Some(20), Some(40));
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Out-of-range offset**: Returns an empty list instead of panicking
+    /// * **Oversized limit**: Capped at 100 instead of panicking
+    pub fn list_completions_for_course(
+        env: Env,
+        course_id: String,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Vec<Completion> {
+        list_completions_for_course(env, course_id, limit, offset)
+    }
+
+    /// List every course completion of a student, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The student's address
+    ///
+    /// # Returns
+    ///
+    /// Returns the student's `Completion` records, empty if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let completions = contract.list_user_completions(env.clone(), student);
+    /// ```
+    pub fn list_user_completions(env: Env, student: Address) -> Vec<Completion> {
+        list_user_completions(env, student)
+    }
+
+    /// Record that a student completed a module of a course.
+    ///
+    /// Gives learning apps resumable, on-chain progress at module granularity.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The student, who must authorize the call
+    /// * `course_id` - The unique identifier of the course
+    /// * `module_id` - The module ID as assigned by the course registry
+    ///
+    /// # Returns
+    ///
+    /// Returns the student's updated `ProgressSummary`.
+    ///
+    /// # Panics
+    ///
+    /// * If the student has no active access to the course
+    /// * If the module is not part of the course in the registry
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let progress = contract.mark_module_completed(env.clone(), student, course_id, module_id);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Already completed**: Nothing changes and no event is emitted
+    /// * **Removed modules**: Dropped from the stored progress on the next update
+    pub fn mark_module_completed(
+        env: Env,
+        student: Address,
+        course_id: String,
+        module_id: String,
+    ) -> ProgressSummary {
+        mark_module_completed(env, student, course_id, module_id)
+    }
+
+    /// Get a student's module progress in a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The student's address
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of completed modules and the course's module count,
+    /// fetched from the course registry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let progress = contract.get_progress(env.clone(), course_id, student);
+    /// let finished = progress.total > 0 && progress.completed == progress.total;
+    /// ```
+    pub fn get_progress(env: Env, course_id: String, student: Address) -> ProgressSummary {
+        get_progress(env, course_id, student)
+    }
+
+    /// Anchor the hash of an off-chain certificate to a completion.
+    ///
+    /// Lets anyone verify a certificate document against the chain. The
+    /// first anchoring may be done by the course creator or an admin;
+    /// re-anchoring is admin-only.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The creator or admin anchoring the certificate
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The student the certificate was issued to
+    /// * `certificate_hash` - Hash of the certificate document (1-128 characters)
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored `CertificateAnchor`.
+    ///
+    /// # Panics
+    ///
+    /// * If no completion is recorded for the student
+    /// * If the caller is not allowed to anchor (or re-anchor) the certificate
+    /// * If the certificate hash is empty or too long
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.anchor_certificate(
+    ///     env.clone(),
+    ///     creator_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     student_address,
+    ///     "5d41402abc4b2a76".try_into().unwrap(),
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Re-anchoring**: The replaced hash moves to `previous_hashes`,
+    ///   which keeps the 5 most recent
+    pub fn anchor_certificate(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        student: Address,
+        certificate_hash: String,
+    ) -> CertificateAnchor {
+        anchor_certificate(env, caller, course_id, student, certificate_hash)
+    }
+
+    /// Verify a certificate document against its anchored hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The student the certificate was issued to
+    /// * `certificate_hash` - Hash of the document being verified
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` only if the hash matches the currently anchored certificate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let valid = contract.verify_certificate(env.clone(), course_id, student, document_hash);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Replaced certificates**: Hashes in the history no longer verify
+    pub fn verify_certificate(
+        env: Env,
+        course_id: String,
+        student: Address,
+        certificate_hash: String,
+    ) -> bool {
+        verify_certificate(env, course_id, student, certificate_hash)
+    }
+
+    /// Get the certificate anchored to a completion.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The student's address
+    ///
+    /// # Returns
+    ///
+    /// Returns the `CertificateAnchor`, or `None` if nothing was anchored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// if let Some(anchor) = contract.get_certificate(env.clone(), course_id, student) {
+    ///     // anchor.previous_hashes lists replaced certificates
+    /// }
+    /// ```
+    pub fn get_certificate(env: Env, course_id: String, student: Address) -> Option<CertificateAnchor> {
+        get_certificate(env, course_id, student)
+    }
+
+    /// Save or update a user's profile on-chain.
+    ///
+    /// Stores user profile information in the contract storage.
+    /// This includes personal and professional information.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user whose profile is saved (must authorize)
+    /// * `name` - The user's full name
+    /// * `email` - The user's email address
+    /// * `profession` - Optional profession/job title
+    /// * `goals` - Optional learning goals or objectives
+    /// * `country` - The user's country of residence
+    ///
+    /// # Panics
+    ///
+    /// * If the user has not authorized the call
+    /// * If name, email, or country are empty
+    /// * If email format is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Save user profile
+    /// contract.save_user_profile(
+    ///     env.clone(),
+    ///     user_address.clone(),
+    ///     "John Doe".try_into().unwrap(),
+    ///     "john@example.com".try_into().unwrap(),
+    ///     Some("Software Developer".try_into().unwrap()),
+    ///     Some("Learn Rust programming".try_into().unwrap()),
+    ///     "US".try_into().unwrap()
+    /// );
+    /// 
+    /// // Save minimal profile
+    /// contract.save_user_profile(
+    ///     env.clone(),
+    ///     user_address,
+    ///     "Jane Smith".try_into().unwrap(),
+    ///     "jane@example.com".try_into().unwrap(),
+    ///     None,
+    ///     None,
+    ///     "CA".try_into().unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Empty required fields**: Name, email, and country cannot be empty
+    /// * **Invalid email**: Email must be in valid format
+    /// * **Profile updates**: Overwrites existing profile data and emits an
+    ///   `updUsPrl` event instead of `saveUsPrl`, carrying the replaced profile
+    /// * **Optional fields**: Profession and goals can be None
+    /// * **Saving for someone else**: Fails without that user's authorization
+    pub fn save_user_profile(
+        env: Env,
+        user: Address,
+        name: String,
+        email: String,
+        profession: Option<String>,
+        goals: Option<String>,
+        country: String,
+    ) {
+        save_user_profile(env, user, name, email, profession, goals, country);
+    }
+
+    /// Delete a user's profile.
+    ///
+    /// Removes the profile stored for `user`. Users may delete their own
+    /// profile and admins may delete any profile; the emitted event records
+    /// which address performed the deletion.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address performing the deletion (the user or an admin)
+    /// * `user` - The address whose profile is deleted
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither the user nor an admin
+    /// * If the user has no stored profile
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // User deleting their own profile
+    /// contract.delete_user_profile(env.clone(), user_address.clone(), user_address);
+    ///
+    /// // Admin deleting a profile on a user's behalf
+    /// contract.delete_user_profile(env.clone(), admin_address, user_address);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No profile**: Panics with `UserProfileNotFound`
+    /// * **Third party**: Panics with `Unauthorized`
+    pub fn delete_user_profile(env: Env, caller: Address, user: Address) {
+        delete_user_profile(env, caller, user)
+    }
+
+    /// Get a user's profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user to query
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored `UserProfile`, or `None` if the user has no profile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// if let Some(profile) = contract.get_user_profile(env.clone(), user_address) {
+    ///     println!("Name: {}", profile.name);
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Never saved**: Returns `None`
+    /// * **Deleted profile**: Returns `None`
+    pub fn get_user_profile(env: Env, user: Address) -> Option<UserProfile> {
+        get_user_profile(env, user)
+    }
+
+    /// Check whether a user has a stored profile.
+    ///
+    /// Cheaper than `get_user_profile` when only presence matters.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user to query
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if a profile is stored for the user, `false` otherwise.
+    pub fn has_user_profile(env: Env, user: Address) -> bool {
+        has_user_profile(&env, &user)
+    }
+
+    /// List all courses a user has access to.
+    ///
+    /// Retrieves all courses that the specified user is enrolled in
+    /// or has been granted access to.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user to query
+    /// * `include_inactive` - Also list expired and suspended access
+    ///
+    /// # Returns
+    ///
+    /// Returns a `UserCourses` struct containing the list of accessible courses.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Get user's accessible courses
+    /// let user_courses = contract.list_user_courses(env.clone(), user_address, false);
+    /// 
+    /// for course_id in user_courses.course_ids {
+    ///     println!("User has access to course: {}", course_id);
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No access**: Returns empty list if user has no course access
+    /// * **Non-existent user**: Returns empty list for non-existent users
+    /// * **Public access**: Anyone can query user courses
+    /// * **Revoked courses**: Only includes currently accessible courses
+    /// * **Inactive access**: Expired and suspended access is only listed
+    ///   when `include_inactive` is `true`, for full history views
+    pub fn list_user_courses(env: Env, user: Address, include_inactive: bool) -> UserCourses {
+        list_user_courses(env, user, include_inactive)
+    }
+
+    /// List the full course details of every course a user has access to.
+    ///
+    /// Resolves each course ID returned by `list_user_courses` through the
+    /// course registry's `get_course`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user to query
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Course` records of the user's accessible courses.
+    ///
+    /// # Panics
+    ///
+    /// * If the contract has not been initialized with a course registry
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Deleted courses**: Courses the registry can no longer return are skipped
+    /// * **Bounded**: At most 50 courses are resolved per call
+    /// * **No access**: Returns an empty list
+    pub fn list_user_courses_detailed(env: Env, user: Address) -> Vec<Course> {
+        list_user_courses_detailed(env, user)
+    }
+
+    /// List the IDs of a user's courses, most recent enrollment first.
+    ///
+    /// Meant for "continue learning" sections.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user to query
+    /// * `limit` - Maximum number of course IDs to return (at most 50)
+    ///
+    /// # Returns
+    ///
+    /// Returns up to `limit` course IDs sorted by grant time, newest first.
+    ///
+    /// # Panics
+    ///
+    /// * If `limit` is 0 or greater than 50
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let recent = contract.list_user_courses_by_recency(env.clone(), user_address, 5);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Inactive access**: Expired and suspended courses are left out
+    /// * **Same grant time**: Courses keep the order of `list_user_courses`
+    /// * **Re-grants and transfers**: Sorted by the time of the latest grant
+    pub fn list_user_courses_by_recency(env: Env, user: Address, limit: u32) -> Vec<String> {
+        list_user_courses_by_recency(env, user, limit)
+    }
+
+    /// List all users who have access to a course.
+    ///
+    /// Retrieves all users who have been granted access to the specified course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CourseUsers` struct containing the list of users with access.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Get all users with access to a course
+    /// let course_users = contract.list_course_access(env.clone(), "course_123".try_into().unwrap());
+    /// 
+    /// for user in course_users.users {
+    ///     println!("User with access: {}", user);
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No users**: Returns empty list if no users have access
+    /// * **Non-existent course**: Returns empty list for non-existent courses
+    /// * **Public access**: Anyone can query course access
+    /// * **Real-time data**: Always returns current access status
+    pub fn list_course_access(env: Env, course_id: String) -> CourseUsers {
+        course_access_list_course_access(env, course_id)
+    }
+
+    /// List the full access records of a course.
+    ///
+    /// Unlike `list_course_access`, which only returns addresses, this
+    /// returns each user's role, grant time, granter and expiry, optionally
+    /// filtered by role and paginated.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `role_filter` - Only return records with this role, if set
+    /// * `limit` - Optional maximum number of records to return (default 10, max 100)
+    /// * `offset` - Optional number of matching records to skip
+    ///
+    /// # Returns
+    ///
+    /// Returns the requested page of `CourseAccess` records in enrollment order.
+    ///
+    /// # Panics
+    ///
+    /// * If `limit` is 0 or greater than 100
+    /// * If course_id is empty or too long
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Only the teaching assistants of a course
+    /// let tas = contract.list_course_access_detailed(
+    ///     env.clone(),
+    ///     "course_123".try_into().unwrap(),
+    ///     Some(AccessRole::TeachingAssistant),
+    ///     None,
+    ///     None
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Expired access**: Left out of the results
+    /// * **Offset past the end**: Returns an empty vector
+    pub fn list_course_access_detailed(
+        env: Env,
+        course_id: String,
+        role_filter: Option<AccessRole>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Vec<CourseAccess> {
+        list_course_access_detailed(env, course_id, role_filter, limit, offset)
+    }
+
+    /// Get the latest grants of a course, newest first.
+    ///
+    /// Every grant path, including transfers, appends to a per-course feed
+    /// of the last 20 grants. It is a feed rather than current state:
+    /// revoked users stay in it until newer grants push them out.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `count` - Maximum number of grants to return (capped at 20)
+    ///
+    /// # Returns
+    ///
+    /// Returns up to `count` `RecentGrant` entries with the user, the grant
+    /// timestamp and the granter, newest first.
+    ///
+    /// # Panics
+    ///
+    /// * If `count` is 0 (`InvalidLimit`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let latest = contract.get_recent_grants(env.clone(), "course_123".try_into().unwrap(), 20);
+    /// ```
+    pub fn get_recent_grants(env: Env, course_id: String, count: u32) -> Vec<RecentGrant> {
+        get_recent_grants(&env, &course_id, count)
+    }
+
+    /// Count the enrollments of a course granted within a time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `start_ts` - Start of the range (ledger timestamp, inclusive)
+    /// * `end_ts` - End of the range (ledger timestamp, inclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of access records whose `granted_at` is in the range.
+    ///
+    /// # Panics
+    ///
+    /// * If `start_ts` is after `end_ts`
+    /// * If course_id is empty or too long
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Enrollments in the last 30 days
+    /// let now = env.ledger().timestamp();
+    /// let recent = contract.count_enrollments_between(env.clone(), course_id, now - 30 * 86_400, now);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Expired and suspended access**: Counted, they were still enrollments
+    /// * **Revoked access**: Not counted; use the enrollment history for those
+    /// * **Re-grants and transfers**: Counted at the time of the latest grant
+    pub fn count_enrollments_between(env: Env, course_id: String, start_ts: u64, end_ts: u64) -> u32 {
+        count_enrollments_between(env, course_id, start_ts, end_ts)
+    }
+
+    /// List the enrollments of a course granted within a time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `start_ts` - Start of the range (ledger timestamp, inclusive)
+    /// * `end_ts` - End of the range (ledger timestamp, inclusive)
+    /// * `limit` - Maximum number of records to return (at most 100)
+    /// * `offset` - Number of matching records to skip
+    ///
+    /// # Returns
+    ///
+    /// Returns the requested page of `CourseAccess` records in enrollment order.
+    ///
+    /// # Panics
+    ///
+    /// * If `start_ts` is after `end_ts`
+    /// * If `limit` is 0 or greater than 100
+    /// * If course_id is empty or too long
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Same records as `count_enrollments_between`**: Paging through
+    ///   returns exactly the counted records
+    /// * **Offset past the end**: Returns an empty vector
+    pub fn list_enrollments_between(
+        env: Env,
+        course_id: String,
+        start_ts: u64,
+        end_ts: u64,
+        limit: u32,
+        offset: u32,
+    ) -> Vec<CourseAccess> {
+        list_enrollments_between(env, course_id, start_ts, end_ts, limit, offset)
+    }
+
+    /// Change the role of a user in a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address changing the role (must be admin or course creator)
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user whose role changes
+    /// * `role` - The new role
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin or the course creator
+    /// * If the user has no unexpired access to the course
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_access_role(env.clone(), instructor, course_id, ta_address, AccessRole::TeachingAssistant);
+    /// ```
+    pub fn set_access_role(env: Env, caller: Address, course_id: String, user: Address, role: AccessRole) {
+        set_access_role(env, caller, course_id, user, role)
+    }
+
+    /// Revoke all user access for a course.
+    ///
+    /// Removes access for all users from the specified course.
+    /// Only admin or course creator is allowed to perform this operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user requesting the operation
+    /// * `course_id` - The unique identifier of the course
+    /// * `reason_hash` - Optional hash of the reason for the revocation (at most 128 characters),
+    ///   stored on the enrollment record of every affected user
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of access entries removed and emits an event.
+    ///
+    /// # Panics
+    ///
+    /// * If course doesn't exist
+    /// * If caller is not authorized (not course creator or admin)
+    /// * If `reason_hash` is empty or too long
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Revoke all access for a course
+    /// let affected_users = contract.revoke_all_access(
+    ///     env.clone(),
+    ///     admin_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     None
+    /// );
+    /// 
+    /// println!("Revoked access for {} users", affected_users);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No users**: Returns 0 if no users had access
+    /// * **Non-existent course**: Will panic if course doesn't exist
+    /// * **Permission denied**: Only course creators and admins can perform this
+    /// * **Bulk operation**: Efficiently removes all access in one transaction
+    pub fn revoke_all_access(env: Env, user: Address, course_id: String, reason_hash: Option<String>) -> u32 {
+        revoke_all_access(env, user, course_id, reason_hash)
+    }
+
+    /// Clean up access records after a course is deleted in the registry.
+    ///
+    /// Revokes all access to the course and removes its roster so no
+    /// orphaned records remain in this contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course registry contract address
+    /// * `course_id` - The unique identifier of the deleted course
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of users whose access was revoked and emits an event.
+    ///
+    /// # Panics
+    ///
+    /// * If the contract has not been initialized
+    /// * If the caller is not the configured course registry contract
+    /// * If the contract has been decommissioned
+    /// * If the course ID is empty or too long
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Called by the course registry from delete_course
+    /// let revoked = contract.handle_course_deleted(
+    ///     env.clone(),
+    ///     registry_address,
+    ///     "course_123".try_into().unwrap(),
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Empty roster**: Returns 0 and still emits the event
+    /// * **Enrollment history**: Kept, with the registry recorded as the revoker
+    pub fn handle_course_deleted(env: Env, caller: Address, course_id: String) -> u32 {
+        handle_course_deleted(env, caller, course_id)
+    }
+
+    /// Hook for the course registry to clean up after deleting a course.
+    ///
+    /// Same cleanup as `handle_course_deleted`, but without a caller argument:
+    /// the configured course registry must be the contract invoking this.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the deleted course
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of users whose access was revoked and emits an event.
+    ///
+    /// # Panics
+    ///
+    /// * If the contract has not been initialized
+    /// * If the invoker is not the configured course registry contract
+    /// * If the contract has been decommissioned
+    /// * If the course ID is empty or too long
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // From the registry's delete_course
+    /// let revoked = env.try_invoke_contract::<u32, soroban_sdk::Error>(
+    ///     &course_access_address,
+    ///     &Symbol::new(&env, "on_course_deleted"),
+    ///     (course_id,).into_val(&env),
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Empty roster**: Returns 0 and still emits the event
+    /// * **Enrollment history**: Kept, with the registry recorded as the revoker
+    pub fn on_course_deleted(env: Env, course_id: String) -> u32 {
+        on_course_deleted(env, course_id)
+    }
+
+    /// Temporarily freeze a user's access to a course.
+    ///
+    /// Unlike revocation, the access record and enrollment are kept; the
+    /// access simply counts as inactive until it is resumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The admin or course creator suspending the access
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The user whose access is suspended
+    /// * `reason_hash` - Optional hash of the reason (at most 128 characters)
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither an admin nor the course creator
+    /// * If the user has no unexpired access to the course
+    /// * If the access is already suspended
+    /// * If `reason_hash` is empty or too long
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Freeze access during a payment dispute
+    /// contract.suspend_access(env.clone(), admin_address, course_id, student, Some(reason_hash));
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Listings and checks**: `has_access`, `list_course_access` and
+    ///   `list_user_courses` treat suspended access as inactive
+    /// * **Re-granting**: Grants to a suspended user fail until access is resumed
+    pub fn suspend_access(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        user: Address,
+        reason_hash: Option<String>,
+    ) {
+        suspend_access(env, caller, course_id, user, reason_hash)
+    }
+
+    /// Lift the suspension of a user's access to a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The admin or course creator resuming the access
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The user whose access is resumed
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither an admin nor the course creator
+    /// * If the user has no unexpired access to the course
+    /// * If the access is not suspended
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.resume_access(env.clone(), admin_address, course_id, student);
+    /// ```
+    pub fn resume_access(env: Env, caller: Address, course_id: String, user: Address) {
+        resume_access(env, caller, course_id, user)
+    }
+
+    /// Block a user from being granted access to a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The admin or course creator denylisting the user
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The user to block
+    /// * `reason_hash` - Optional hash of the reason (at most 128 characters)
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin or the course creator
+    /// * If `reason_hash` is empty or too long
+    /// * If the course already has 200 denylisted users
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.add_to_denylist(env.clone(), admin_address, course_id, student, Some(reason_hash));
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Grants**: Every grant path, including vouchers, invites, trials
+    ///   and self-enrollment, fails with `UserDenylisted` (error code 44)
+    /// * **Transfers**: Transfers to the user fail the same way
+    /// * **Existing access**: Left alone; revoke it separately if needed
+    /// * **Already denylisted**: The reason is replaced
+    pub fn add_to_denylist(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        user: Address,
+        reason_hash: Option<String>,
+    ) {
+        add_to_denylist(env, caller, course_id, user, reason_hash)
+    }
+
+    /// Allow a denylisted user to be granted access to a course again.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The admin or course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The user to unblock
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the user was denylisted, `false` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin or the course creator
+    pub fn remove_from_denylist(env: Env, caller: Address, course_id: String, user: Address) -> bool {
+        remove_from_denylist(env, caller, course_id, user)
+    }
+
+    /// Check whether a user is denylisted for a course.
+    pub fn is_denylisted(env: Env, course_id: String, user: Address) -> bool {
+        is_denylisted(&env, &course_id, &user)
+    }
+
+    /// Get the reason, author and time a user was denylisted for a course, if they are.
+    pub fn get_denylist_entry(env: Env, course_id: String, user: Address) -> Option<DenylistEntry> {
+        get_denylist_entry(env, course_id, user)
+    }
+
+    /// List the denylisted users of a course (at most 200).
+    pub fn get_denylist(env: Env, course_id: String) -> Vec<Address> {
+        get_denylist(env, course_id)
+    }
+
+    /// Get a user's access record for a course.
+    ///
+    /// Unlike `has_access`, this also returns suspended and expired records,
+    /// so moderators can see the status.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The user's address
+    ///
+    /// # Returns
+    ///
+    /// Returns the `CourseAccess` record, or `None` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// if let Some(access) = contract.get_access_details(env.clone(), course_id, user) {
+    ///     let frozen = access.suspended;
+    /// }
+    /// ```
+    pub fn get_access_details(env: Env, course_id: String, user: Address) -> Option<CourseAccess> {
+        get_access_details(env, course_id, user)
+    }
+
+    /// Remove expired access entries of a course.
+    ///
+    /// Removes up to `max_entries` expired grants from the access records and
+    /// from both the user and course indexes, closing their enrollment
+    /// records. Unexpired access is never removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address performing the cleanup (admin or course creator)
+    /// * `course_id` - The unique identifier of the course
+    /// * `max_entries` - Maximum number of entries to purge in this call
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of entries purged.
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not authorized (not course creator or admin)
+    /// * If course_id is empty or too long
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Clean up a large course in chunks of 50
+    /// while contract.purge_expired_access(env.clone(), admin_address.clone(), course_id.clone(), 50) == 50 {}
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No expired entries**: Returns 0
+    /// * **Unknown course**: Returns 0
+    /// * **Zero `max_entries`**: Purges nothing and returns 0
+    pub fn purge_expired_access(env: Env, caller: Address, course_id: String, max_entries: u32) -> u32 {
+        purge_expired_access(env, caller, course_id, max_entries)
+    }
+
+    /// Reclaim storage of expired access entries of a course.
+    ///
+    /// Admin-only variant of `purge_expired_access` for operators sweeping
+    /// courses they did not create. Removes up to `limit` expired grants from
+    /// the access records and both indexes, closing their enrollment records.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The admin performing the sweep
+    /// * `course_id` - The unique identifier of the course
+    /// * `limit` - Maximum number of entries to remove in this call
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of entries removed.
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not an admin
+    /// * If course_id is empty or too long
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let reclaimed = contract.sweep_expired_access(env.clone(), admin_address, course_id, 50);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Course creators**: Rejected; they can use `purge_expired_access`
+    /// * **Unexpired access**: Never removed
+    /// * **Unknown course**: Returns 0
+    pub fn sweep_expired_access(env: Env, caller: Address, course_id: String, limit: u32) -> u32 {
+        sweep_expired_access(env, caller, course_id, limit)
+    }
+
+    /// Repair mismatches between a course's access entries and the indexes.
+    ///
+    /// Rescans the course's user list against the stored access entries.
+    /// Users without an access entry are dropped from both the course and
+    /// user indexes, and users with one get the course restored to their
+    /// course list. Users of the course's recent grants whose access entry is
+    /// missing from the course's user list are added back.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The admin performing the repair
+    /// * `course_id` - The unique identifier of the course
+    /// * `max_entries` - Maximum number of index entries to fix in this call
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of index entries fixed.
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not an admin
+    /// * If course_id is empty or too long
+    /// * If the contract has been decommissioned
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Repair a large course in chunks of 50
+    /// while contract.repair_access_consistency(env.clone(), admin_address.clone(), course_id.clone(), 50) == 50 {}
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Consistent course**: Returns 0 and changes nothing
+    /// * **Unknown course**: Returns 0
+    /// * **Zero `max_entries`**: Fixes nothing and returns 0
+    pub fn repair_access_consistency(env: Env, caller: Address, course_id: String, max_entries: u32) -> u32 {
+        repair_access_consistency(env, caller, course_id, max_entries)
+    }
+
+    /// Configure external contract addresses used for auth checks.
+    ///
+    /// Updates the addresses of external contracts that this contract
+    /// depends on for authentication and authorization checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the user making the configuration change
+    /// * `user_mgmt_addr` - Address of the user management contract
+    /// * `course_registry_addr` - Address of the course registry contract
+    ///
+    /// # Panics
+    ///
+    /// * If the contract has not been initialized
+    /// * If caller is not the contract owner
+    /// * If any of the provided addresses are invalid
+    /// * If both addresses are the same, or either is this contract's own address (`InvalidConfig`)
+    ///
+    /// # Storage
+    ///
+    /// Stores the addresses in instance storage keys: ("user_mgmt_addr",) and ("course_registry_addr",)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Update contract addresses
+    /// contract.set_config(
+    ///     env.clone(),
+    ///     contract_owner_address,
+    ///     new_user_mgmt_address,
+    ///     new_course_registry_address
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Owner only**: Only contract owner can update addresses
+    /// * **Invalid addresses**: Will panic if addresses are invalid
+    /// * **Runtime updates**: Can be called after contract deployment
+    /// * **Immediate effect**: Changes take effect immediately
+    /// * **Event**: Publishes `cfgChange` with the caller, the old addresses and the new ones
+    pub fn set_config(
+        env: Env,
+        caller: Address,
+        user_mgmt_addr: Address,
+        course_registry_addr: Address,
+    ) {
+        set_contract_addrs(env, caller, user_mgmt_addr, course_registry_addr)
+    }
+
+    /// Get the contract owner.
+    ///
+    /// Returns the address that initialized the contract, which manages its
+    /// configuration and local admin set.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the owner's address.
+    ///
+    /// # Panics
+    ///
+    /// * If the contract has not been initialized
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let owner = contract.get_owner(env.clone());
+    /// ```
+    pub fn get_owner(env: Env) -> Address {
+        get_owner(env)
+    }
+
+    /// Check whether the contract has been initialized.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` once `initialize` has run.
+    pub fn is_initialized(env: Env) -> bool {
+        is_initialized(&env)
+    }
+
+    /// Mark the contract as decommissioned before moving to a new deployment.
+    ///
+    /// Afterwards every write to access records and profiles, including
+    /// `grant_access`, `revoke_access`, `transfer_course` and
+    /// `save_user_profile`, fails with `Decommissioned` (error code 49).
+    /// Reads such as `has_access` and `list_user_courses` keep working.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not the contract owner
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.decommission(env.clone(), contract_owner_address);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Irreversible**: There is no way to re-enable writes
+    pub fn decommission(env: Env, caller: Address) {
+        decommission(env, caller)
+    }
+
+    /// Check whether the contract has been decommissioned.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` once `decommission` has run.
+    pub fn is_decommissioned(env: Env) -> bool {
+        is_decommissioned(&env)
+    }
+
+    /// Set or clear the contract notified of new enrollments.
+    ///
+    /// When configured, every grant makes a best-effort call to
+    /// `on_enrollment(course_id, user)` on this address.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    /// * `notify_addr` - The contract to notify, or `None` to disable notifications
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not the contract owner
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_notify_addr(env.clone(), contract_owner_address, Some(analytics_address));
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Failing receiver**: Errors raised by `on_enrollment` are ignored and
+    ///   the enrollment still succeeds
+    pub fn set_notify_addr(env: Env, caller: Address, notify_addr: Option<Address>) {
+        set_notify_addr(env, caller, notify_addr)
+    }
+
+    /// Get the contract notified of new enrollments, if configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the notification address, or `None` if notifications are disabled.
+    pub fn get_notify_addr(env: Env) -> Option<Address> {
+        get_notify_addr(env)
+    }
+
+    /// Set or clear the token contract course payments are made in.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    /// * `token` - The token contract, or `None` to disable paid enrollment
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not the contract owner
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_payment_token(env.clone(), contract_owner_address, Some(usdc_address));
+    /// ```
+    pub fn set_payment_token(env: Env, caller: Address, token: Option<Address>) {
+        set_payment_token(env, caller, token)
+    }
+
+    /// Get the token contract course payments are made in, if configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the payment token address, or `None` if not configured.
+    pub fn get_payment_token(env: Env) -> Option<Address> {
+        get_payment_token(&env)
+    }
+
+    /// Add an address to the local admin set.
+    ///
+    /// Local admins are recognized without a cross-contract call, so admin
+    /// operations keep working while the user management contract is
+    /// unavailable or being upgraded.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    /// * `admin` - The address to add
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not the contract owner
+    /// * If the local admin set already holds 20 addresses
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.add_admin(env.clone(), contract_owner_address, moderator_address);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Existing admin**: Nothing changes and no event is emitted
+    pub fn add_admin(env: Env, caller: Address, admin: Address) {
+        add_admin(env, caller, admin)
+    }
+
+    /// Remove an address from the local admin set.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    /// * `admin` - The address to remove
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not the contract owner
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.remove_admin(env.clone(), contract_owner_address, moderator_address);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unknown address**: Nothing changes and no event is emitted
+    /// * **External admins**: Addresses the user management contract reports
+    ///   as admins remain admins
+    pub fn remove_admin(env: Env, caller: Address, admin: Address) {
+        remove_admin(env, caller, admin)
+    }
+
+    /// Check if an address is an admin of this contract.
+    ///
+    /// The local admin set is checked first, then the user management
+    /// contract if one is configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `who` - The address to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the address is a local or external admin.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let allowed = contract.is_admin(env.clone(), caller);
+    /// ```
+    pub fn is_admin(env: Env, who: Address) -> bool {
+        is_admin(&env, &who)
+    }
+
+    /// Get the local admin set.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the locally managed admin addresses, oldest first.
+    pub fn get_admins(env: Env) -> Vec<Address> {
+        get_admins(env)
+    }
+
+    /// Get the current contract version
+    ///
+    /// Returns the semantic version of the current contract deployment.
+    /// This is useful for tracking contract upgrades and compatibility.
+    ///
+    /// # Arguments
+    /// * `_env` - The Soroban environment (unused)
+    ///
+    /// # Returns
+    /// * `String` - The current contract version
+    pub fn get_contract_version(_env: Env) -> String {
+        String::from_str(&_env, VERSION)
+    }
+
+    /// Get contract version history
+    ///
+    /// Returns a list of all versions that have been deployed for this contract.
+    /// The deployed version is recorded on initialization and every version
+    /// data is migrated to is appended.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    /// * `Vec<String>` - Vector of version strings in chronological order
+    pub fn get_version_history(env: Env) -> Vec<String> {
+        get_version_history(&env)
+    }
+
+    /// Check compatibility between contract versions
+    ///
+    /// Determines if data from one version can be safely used with another version.
+    /// Versions are compatible when they share the same major version; malformed
+    /// versions are never compatible. The course registry applies the same rules.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `from_version` - The source version to check compatibility from
+    /// * `to_version` - The target version to check compatibility to
+    ///
+    /// # Returns
+    /// * `bool` - True if the versions are compatible, false otherwise
     pub fn is_version_compatible(env: Env, from_version: String, to_version: String) -> bool {
         is_version_compatible(&env, from_version, to_version)
     }
@@ -436,13 +2722,17 @@ impl CourseAccessContract {
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
-    /// * `caller` - The address performing the migration (must be admin)
+    /// * `caller` - The address performing the migration (must be the owner or an admin)
     /// * `from_version` - The source version to migrate from
     /// * `to_version` - The target version to migrate to
     ///
     /// # Returns
     /// * `bool` - True if migration was successful, false otherwise
     ///
+    /// # Panics
+    /// * If the caller is not the owner or an admin (`Unauthorized`)
+    /// * If either version is not a valid `MAJOR.MINOR.PATCH` string
+    ///
     /// # Events
     /// Emits a migration event upon successful completion
     pub fn migrate_access_data(env: Env, caller: Address, from_version: String, to_version: String) -> bool {
@@ -451,8 +2741,8 @@ impl CourseAccessContract {
 
     /// Get migration status for the current contract
     ///
-    /// Returns information about the current migration status and any
-    /// pending migrations that need to be completed.
+    /// Reports the last version access data was successfully migrated to,
+    /// e.g. "Migrated to 1.1.0", or "No migrations completed".
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
@@ -463,7 +2753,338 @@ impl CourseAccessContract {
         get_migration_status(&env)
     }
 
+    /// Mark a migration as in progress, or as finished.
+    ///
+    /// While a migration is in progress `get_migration_status` reports it and
+    /// `upgrade` is refused.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    /// * `in_progress` - `true` when a migration starts, `false` when it ends
+    ///
+    /// # Panics
+    /// * If the caller is not the contract owner
+    pub fn set_migration_in_progress(env: Env, caller: Address, in_progress: bool) {
+        set_migration_in_progress(&env, caller, in_progress)
+    }
+
+    /// Upgrade the contract to new wasm.
+    ///
+    /// Replaces the contract's wasm with the uploaded wasm identified by
+    /// `new_wasm_hash`. The version being replaced is stored in the version
+    /// history, and the new wasm reports its own `VERSION` in
+    /// `get_version_history` once it runs.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner
+    /// * `new_wasm_hash` - Hash of the already uploaded wasm
+    ///
+    /// # Panics
+    /// * If the caller is not the contract owner
+    /// * If a migration is in progress (`MigrationInProgress`)
+    /// * If no wasm with `new_wasm_hash` has been uploaded
+    ///
+    /// # Events
+    /// Emits an upgrade event with the wasm hash and the version being replaced
+    pub fn upgrade(env: Env, caller: Address, new_wasm_hash: BytesN<32>) {
+        upgrade(&env, caller, new_wasm_hash)
+    }
+
+    /// Move `from`'s access to a course to `to`.
+    ///
+    /// Requires `from`'s authorization. Fails with `UserNoAccessCourse` unless
+    /// `from` has active access, with `UserAlreadyHasAccess` if `to` already
+    /// has access, and with `AccessSuspended` if `to`'s access is suspended.
+    /// The original grant time is kept. An expired entry `to` held is
+    /// replaced, and its enrollment record is closed. A transferred trial
+    /// uses up `to`'s trial of the course, and the purchase of bought access
+    /// moves to the new enrollment id, so the original payer stays refundable.
     pub fn transfer_course(env: Env, course_id: String, from: Address, to: Address){
         transfer_course_access(env, course_id, from, to)
     }
+
+    /// Move a user's course access to a new address.
+    ///
+    /// Transfers up to `max_courses` access records from `from` to `to` per
+    /// call, keeping the per-user and per-course indexes consistent. Call
+    /// repeatedly until `list_user_courses(from)` is empty or holds only
+    /// courses `to` is denylisted for. Each moved record
+    /// has `transferred_from` and `transferred_at` set, and gets an `xferAcc`
+    /// event with the course, `from`, `to`, the initiator and the timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `from` - The address giving up its access (must authorize)
+    /// * `to` - The address receiving the access (must authorize)
+    /// * `max_courses` - Maximum number of courses processed in this call
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of access records moved.
+    ///
+    /// # Panics
+    ///
+    /// * If either address does not authorize the call
+    /// * If `from` and `to` are the same address
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Rotate wallets 20 courses at a time
+    /// contract.transfer_all_access(env.clone(), old_wallet.clone(), new_wallet.clone(), 20);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Destination already enrolled**: The source's entry is dropped and
+    ///   counted as skipped; the destination keeps its own access
+    /// * **Destination suspended**: Handled like an enrolled destination
+    /// * **Expired source access**: Dropped and counted as skipped
+    /// * **Destination denylisted**: The access stays with `from`, moves to
+    ///   the back of its course list and is counted as skipped
+    /// * **No courses**: Returns 0
+    pub fn transfer_all_access(env: Env, from: Address, to: Address, max_courses: u32) -> u32 {
+        transfer_all_access(env, from, to, max_courses)
+    }
+
+    /// Remove a user's access to every course.
+    ///
+    /// The inverse of `revoke_all_access`, for account deletion and bans.
+    /// Removes up to `max_courses` access records per call, keeping the
+    /// per-user and per-course indexes consistent. Call repeatedly until
+    /// `list_user_courses(user)` is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - An admin or the user themselves (must authorize)
+    /// * `user` - The user losing their access
+    /// * `max_courses` - Maximum number of courses processed in this call
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of access records removed.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither an admin nor the user
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Ban a user, 20 courses at a time
+    /// contract.remove_user_from_all_courses(env.clone(), admin_address, user_address, 20);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Enrollment history**: Kept, with every record closed by `caller`
+    /// * **No courses**: Returns 0
+    pub fn remove_user_from_all_courses(env: Env, caller: Address, user: Address, max_courses: u32) -> u32 {
+        remove_user_from_all_courses(env, caller, user, max_courses)
+    }
+
+    /// Get the sequence number of the most recent access event.
+    ///
+    /// Every grant, revoke, transfer, trial conversion, expiry purge and
+    /// backup import event carries its sequence number as the last element of its data. The
+    /// sequence starts at 1 and increases by exactly one per event, so
+    /// indexers can resume from a known point and detect missed events.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the last emitted sequence number, or 0 if no access event has
+    /// been emitted yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let last_seq = contract.get_access_event_seq(env.clone());
+    /// ```
+    pub fn get_access_event_seq(env: Env) -> u64 {
+        get_access_event_seq(&env)
+    }
+
+    /// Get the number of courses a user has access to.
+    ///
+    /// Cheaper than `list_user_courses` when only the count is needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of courses in the user's course list, or 0 for an
+    /// unknown user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let enrolled_in = contract.get_user_course_count(env.clone(), user_address);
+    /// ```
+    pub fn get_user_course_count(env: Env, user: Address) -> u32 {
+        get_user_course_count(&env, &user)
+    }
+
+    /// Get the total number of access entries across all courses.
+    ///
+    /// Grants add to the total, while revocations and expiry purges subtract
+    /// from it. Transfers move an entry without changing the total.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of stored access entries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let total = contract.get_total_enrollments(env.clone());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Expired access**: Counted until purged or granted again
+    /// * **Transfer onto an existing enrollment**: The redundant entry is
+    ///   dropped, so the total goes down by one
+    pub fn get_total_enrollments(env: Env) -> u64 {
+        get_total_enrollments(&env)
+    }
+
+    /// Get platform-wide access statistics.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of stored access entries and the running totals
+    /// of entries granted and ended.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let stats = contract.get_platform_access_stats(env.clone());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Transfers**: Count as one entry ended and one granted
+    /// * **Expiry**: Purged or re-granted expired entries count as ended
+    /// * **Imports**: Written grants are counted like any other grant
+    pub fn get_platform_access_stats(env: Env) -> AccessStats {
+        get_platform_access_stats(&env)
+    }
+
+    /// Get the access statistics of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the size of the course roster and the running totals of the
+    /// course's access entries granted and ended.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let stats = contract.get_course_access_stats(env.clone(), course_id);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unknown course**: Returns all zeros
+    /// * **Deleted course**: Keeps its totals with no active users
+    pub fn get_course_access_stats(env: Env, course_id: String) -> CourseAccessStats {
+        get_course_access_stats(&env, &course_id)
+    }
+
+    /// Export all course access data for backup purposes.
+    ///
+    /// Produces a checksummed backup of every access entry with its full
+    /// grant metadata, the payments and enrollment history of those entries
+    /// and every saved user profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address requesting the backup (must be admin)
+    ///
+    /// # Returns
+    ///
+    /// Returns an `AccessBackupData` whose grants are split into
+    /// `CourseAccessChunk`s of at most 50 access entries each.
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not an admin
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let backup = contract.export_access_data(env.clone(), admin_address);
+    /// ```
+    pub fn export_access_data(env: Env, caller: Address) -> AccessBackupData {
+        export_access_data(env, caller)
+    }
+
+    /// Import course access data from a backup.
+    ///
+    /// The whole backup is validated before anything is written.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address performing the import (must be admin)
+    /// * `backup_data` - Backup produced by `export_access_data`
+    /// * `mode` - `Merge` keeps existing grants and profiles, `Overwrite`
+    ///   replaces the grants of backed-up courses and backed-up profiles
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of grants written.
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not an admin
+    /// * If the checksum does not match the backup contents
+    /// * If the backup is incompatible or contains invalid entries
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let imported = contract.import_access_data(env.clone(), admin_address, backup, AccessImportMode::Merge);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Merge**: Grants that already exist are skipped and keep their history
+    /// * **Overwrite**: Users of a backed-up course who are not in the backup lose access
+    /// * **Grant metadata**: Role, grant time, granter, expiry, trial and
+    ///   suspension state are restored unchanged; an enrollment ID already used
+    ///   by another course and user here is replaced by a fresh one
+    pub fn import_access_data(
+        env: Env,
+        caller: Address,
+        backup_data: AccessBackupData,
+        mode: AccessImportMode,
+    ) -> u32 {
+        import_access_data(env, caller, backup_data, mode)
+    }
 }
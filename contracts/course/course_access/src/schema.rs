@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracttype, Address, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Vec};
 
 /// Represents access permission for a user to a specific course.
 ///
@@ -14,6 +14,68 @@ pub struct CourseAccess {
     pub course_id: String,
     /// The address of the user who has access
     pub user: Address,
+    /// The user's role in the course
+    pub role: AccessRole,
+    /// Ledger timestamp when the access was granted
+    pub granted_at: u64,
+    /// The address that granted the access, if known
+    pub granted_by: Option<Address>,
+    /// Ledger timestamp from which the access is no longer valid, if it expires
+    pub expires_at: Option<u64>,
+    /// Whether this is a free trial that has not been converted to full access
+    pub is_trial: bool,
+    /// Unique ID of the grant that created this access, assigned when it is stored
+    pub enrollment_id: u64,
+    /// Whether the access is temporarily frozen by moderation
+    pub suspended: bool,
+    /// Hash of the reason for the current suspension, if given
+    pub suspension_reason: Option<String>,
+    /// The previous holder, if this access was transferred (only the immediate predecessor is kept)
+    pub transferred_from: Option<Address>,
+    /// Ledger timestamp of the transfer that produced this access, if any
+    pub transferred_at: Option<u64>,
+}
+
+/// Layout of `CourseAccess` as stored by contract version 1.0.0, before
+/// roles, timestamps, expiry, enrollment IDs, suspension and transfer
+/// provenance existed.
+///
+/// Only used to read access entries written before the upgrade.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct LegacyCourseAccessV1 {
+    /// The unique identifier of the course
+    pub course_id: String,
+    /// The address of the user who has access
+    pub user: Address,
+}
+
+/// Role of a user with access to a course.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AccessRole {
+    /// Enrolled learner (the default for every grant)
+    Student,
+    /// Assists the instructor with the course
+    TeachingAssistant,
+    /// Teaches the course
+    Instructor,
+}
+
+/// Why a user's access was revoked, recorded on the enrollment record.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum RevokeReason {
+    /// No reason given (the default for `revoke_access`)
+    Unspecified,
+    /// The purchase was refunded
+    Refund,
+    /// The user violated the course terms
+    Violation,
+    /// The access expired
+    Expiry,
+    /// The user dropped the course themselves
+    Unenrolled,
 }
 
 /// Contains all courses that a specific user has access to.
@@ -44,8 +106,330 @@ pub enum DataKey {
     UserCourses(Address),
     /// Key for storing users per course: course_id -> CourseUsers
     CourseUsers(String),
+    /// Key for storing enrollment history: (course_id, user) -> Vec<EnrollmentRecord>
+    EnrollmentHistory(String, Address),
+    /// Key for storing every course that has ever had a grant -> Vec<String>
+    AccessCourses,
+    /// Key for storing every user with a saved profile -> Vec<Address>
+    ProfileUsers,
+    /// Key for storing the sequence number of the last access event -> u64
+    AccessEventSeq,
+    /// Key marking that a user has used their trial of a course: (course_id, user) -> bool
+    TrialUsed(String, Address),
+    /// Key for storing the number of stored access entries -> u64
+    TotalEnrollments,
+    /// Key for storing a voucher: (course_id, code_hash) -> Voucher
+    Voucher(String, BytesN<32>),
+    /// Key marking that a user redeemed a voucher: (course_id, code_hash, user) -> bool
+    VoucherRedemption(String, BytesN<32>, Address),
+    /// Key for storing a pending invite: (course_id, invitee) -> Invite
+    Invite(String, Address),
+    /// Key for storing the last assigned enrollment ID -> u64
+    EnrollmentSeq,
+    /// Key for storing the (course_id, user) pair of an enrollment ID: id -> (String, Address)
+    EnrollmentIndex(u64),
+    /// Key for storing a course completion: (course_id, student) -> Completion
+    Completion(String, Address),
+    /// Key for storing the students who completed a course: course_id -> Vec<Address>
+    CourseCompletions(String),
+    /// Key for storing the courses a student completed: student -> Vec<String>
+    UserCompletions(Address),
+    /// Key for storing the certificate anchored to a completion: (course_id, student) -> CertificateAnchor
+    Certificate(String, Address),
+    /// Key for storing the completed module IDs: (course_id, student) -> Vec<String>
+    ModuleProgress(String, Address),
+    /// Key for storing the grant and revocation counters of a course: course_id -> AccessCounters
+    CourseAccessCounters(String),
+    /// Key for storing why a user is denylisted: (course_id, user) -> DenylistEntry
+    Denylisted(String, Address),
+    /// Key for storing the denylisted users of a course: course_id -> Vec<Address>
+    CourseDenylist(String),
+    /// Key for storing how long after enrolling a user can drop a course for a refund: course_id -> u64 seconds
+    RefundWindow(String),
+    /// Key for storing the delegates allowed to grant and revoke access to a course: course_id -> Vec<Address>
+    CourseGranters(String),
+    /// Key for storing the most recent grants of a course, oldest first: course_id -> Vec<RecentGrant>
+    RecentGrants(String),
+    /// Key for storing a granter's usage of the grant rate limit: address -> GrantRateLimitData
+    GrantRateLimit(Address),
+    /// Key marking a granter as exempt from the grant rate limit: address -> bool
+    GrantRateLimitExempt(Address),
+    /// Key for storing the enrollment window of a course: course_id -> EnrollmentWindow
+    EnrollmentWindow(String),
+    /// Key for storing the Merkle root of a course's cohort: course_id -> BytesN<32>
+    EnrollmentMerkleRoot(String),
+    /// Key marking a cohort leaf as claimed: (course_id, leaf) -> bool
+    EnrollmentClaimed(String, BytesN<32>),
+    /// Key for storing who paid for an enrollment: enrollment_id -> Purchase
+    Purchase(u64),
 }
 
+/// Payment made for an enrollment, kept while the access it bought exists.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Purchase {
+    /// The address that paid for the access
+    pub payer: Address,
+    /// The amount paid, in the payment token
+    pub amount: i128,
+}
+
+/// Why and by whom a user was denylisted for a course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DenylistEntry {
+    /// Hash of the reason the user was denylisted, if one was given
+    pub reason_hash: Option<String>,
+    /// The creator or admin who denylisted the user
+    pub added_by: Address,
+    /// Ledger timestamp when the user was denylisted
+    pub added_at: u64,
+}
+
+/// One entry of a course's recent-grants feed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RecentGrant {
+    /// The user who was granted access
+    pub user: Address,
+    /// Ledger timestamp of the grant
+    pub granted_at: u64,
+    /// The address that granted the access, if known
+    pub granted_by: Option<Address>,
+}
+
+/// Number of grants kept in each course's recent-grants feed.
+pub const RECENT_GRANTS_CAPACITY: u32 = 20;
+
+/// Maximum number of denylisted users per course.
+pub const MAX_DENYLIST_SIZE: u32 = 200;
+
+/// Maximum number of granting delegates per course.
+pub const MAX_COURSE_GRANTERS: u32 = 20;
+
+/// Period during which students can enroll themselves in a course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EnrollmentWindow {
+    /// Ledger timestamp from which enrollment is open
+    pub opens_at: u64,
+    /// Ledger timestamp from which enrollment is closed again
+    pub closes_at: u64,
+}
+
+/// Outcome of granting a bundle of courses.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BundleGrantResult {
+    /// Number of courses access was granted to
+    pub granted: u32,
+    /// IDs of the courses access could not be granted to
+    pub failed: Vec<String>,
+}
+
+/// Maximum number of courses in one bundle grant.
+pub const MAX_BUNDLE_SIZE: u32 = 20;
+
+/// Where to ask whether a user is banned platform-wide.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BanCheckConfig {
+    /// The user profile contract exposing `is_banned(user) -> bool`
+    pub profile_contract: Address,
+    /// Whether grants go ahead when the profile contract cannot answer
+    pub fail_open: bool,
+}
+
+/// Rate limit applied to the grants made by each granter.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GrantRateLimitConfig {
+    /// Length of the rate limiting window in seconds
+    pub window_seconds: u64,
+    /// Maximum grants a granter can make per window
+    pub max_grants_per_window: u32,
+}
+
+/// A granter's usage of the grant rate limit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GrantRateLimitData {
+    /// Grants made in the current window
+    pub count: u32,
+    /// Ledger timestamp when the current window started
+    pub window_start: u64,
+}
+
+/// Where a granter stands against the grant rate limit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GrantRateLimitStatus {
+    /// Grants made in the current window (0 once the window has elapsed)
+    pub count: u32,
+    /// Maximum grants per window
+    pub max_grants_per_window: u32,
+    /// Ledger timestamp at which the current window ends
+    pub window_resets_at: u64,
+    /// Whether the granter is exempt from the limit
+    pub exempt: bool,
+}
+
+/// Running totals of access entries granted and ended.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct AccessCounters {
+    /// Access entries ever written, including transfers in and re-grants
+    pub granted: u64,
+    /// Access entries that ended: revocations, transfers out, expiry
+    /// purges and expired entries replaced by a new grant
+    pub revoked: u64,
+}
+
+/// Platform-wide access statistics.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AccessStats {
+    /// Access entries currently stored, see `get_total_enrollments`
+    pub active: u64,
+    /// Access entries ever granted
+    pub total_granted: u64,
+    /// Access entries ever ended
+    pub total_revoked: u64,
+}
+
+/// Access statistics of a single course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CourseAccessStats {
+    /// The course the statistics belong to
+    pub course_id: String,
+    /// Users currently on the course roster
+    pub active: u32,
+    /// Access entries ever granted for the course
+    pub total_granted: u64,
+    /// Access entries of the course ever ended
+    pub total_revoked: u64,
+}
+
+/// On-chain attestation that a student completed a course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Completion {
+    /// The completed course
+    pub course_id: String,
+    /// The student who completed the course
+    pub student: Address,
+    /// Hash of the completion evidence, e.g. the certificate contents
+    pub completion_hash: String,
+    /// Ledger timestamp of the attestation
+    pub completed_at: u64,
+    /// The creator or admin who attested the completion
+    pub attested_by: Address,
+}
+
+/// Maximum length of a completion hash
+pub const MAX_COMPLETION_HASH_LENGTH: u32 = 128;
+
+/// Hash of an off-chain certificate anchored to a completion.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CertificateAnchor {
+    /// The completed course
+    pub course_id: String,
+    /// The student the certificate was issued to
+    pub student: Address,
+    /// Hash of the current certificate document
+    pub certificate_hash: String,
+    /// Ledger timestamp of the latest anchoring
+    pub issued_at: u64,
+    /// The creator or admin who anchored the current hash
+    pub issued_by: Address,
+    /// Previously anchored hashes, oldest first
+    pub previous_hashes: Vec<String>,
+}
+
+/// Module progress of a student in a course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProgressSummary {
+    /// Number of the course's modules the student completed
+    pub completed: u32,
+    /// Number of modules in the course
+    pub total: u32,
+}
+
+/// Maximum number of replaced certificate hashes kept per completion
+pub const MAX_CERTIFICATE_HISTORY: u32 = 5;
+
+/// A pending invitation for a user to join a course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Invite {
+    /// The course the invite is for
+    pub course_id: String,
+    /// The address invited to the course
+    pub invitee: Address,
+    /// The address that created or last renewed the invite
+    pub inviter: Address,
+    /// Ledger timestamp from which the invite can no longer be accepted
+    pub expires_at: u64,
+}
+
+/// A redemption code granting access to a course.
+///
+/// Only the SHA-256 hash of the code is stored; students redeem the voucher
+/// by presenting the code itself.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Voucher {
+    /// The course the voucher grants access to
+    pub course_id: String,
+    /// SHA-256 hash of the redemption code
+    pub code_hash: BytesN<32>,
+    /// The address that created the voucher
+    pub creator: Address,
+    /// Total number of redemptions allowed
+    pub max_uses: u32,
+    /// Number of redemptions left
+    pub remaining_uses: u32,
+    /// Ledger timestamp from which the voucher can no longer be redeemed
+    pub expires_at: u64,
+    /// Whether the creator has revoked the voucher
+    pub revoked: bool,
+}
+
+/// A single enrollment period of a user in a course.
+///
+/// A record is appended every time access is granted and closed when the
+/// access is revoked, so proof of enrollment survives revocation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EnrollmentRecord {
+    /// The unique identifier of the course
+    pub course_id: String,
+    /// The address of the enrolled user
+    pub user: Address,
+    /// Ledger timestamp when access was granted
+    pub granted_at: u64,
+    /// Ledger timestamp when access was revoked, if it has been
+    pub revoked_at: Option<u64>,
+    /// The address that revoked the access, if known
+    pub revoked_by: Option<Address>,
+    /// Hash of the reason the access was revoked, if one was given
+    pub revocation_reason: Option<String>,
+    /// Why the access was revoked; `Unspecified` while the record is open
+    pub revoke_reason: RevokeReason,
+    /// Enrollment ID of the grant this record belongs to
+    pub enrollment_id: u64,
+}
+
+/// Maximum number of enrollment records kept per (course, user) pair.
+/// Oldest records are dropped first once the limit is reached.
+pub const MAX_ENROLLMENT_HISTORY: u32 = 50;
+
+/// Maximum length of a revocation reason hash
+pub const MAX_REASON_HASH_LENGTH: u32 = 128;
+
 /// Represents a user's profile information.
 ///
 /// This struct contains all the personal and professional information
@@ -65,6 +449,132 @@ pub struct UserProfile {
     pub country: String,
 }
 
+/// Data of the `crsAccess` event emitted whenever access is granted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AccessGrantedEvent {
+    /// The course the access was granted to
+    pub course_id: String,
+    /// The user who received the access
+    pub user: Address,
+    /// The address that granted the access, if known
+    pub actor: Option<Address>,
+    /// Number of users with access to the course after the grant
+    pub user_count: u32,
+    /// Enrollment ID assigned to the grant
+    pub enrollment_id: u64,
+    /// Access event sequence number
+    pub seq: u64,
+    /// Ledger timestamp of the grant
+    pub timestamp: u64,
+}
+
+/// Data of the `crsAcRvk` event emitted when a single user's access is revoked.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AccessRevokedEvent {
+    /// The course the access was revoked from
+    pub course_id: String,
+    /// The user who lost the access
+    pub user: Address,
+    /// The address that revoked the access, if known
+    pub actor: Option<Address>,
+    /// Why the access was revoked
+    pub reason: RevokeReason,
+    /// Enrollment ID of the revoked grant
+    pub enrollment_id: u64,
+    /// Access event sequence number
+    pub seq: u64,
+    /// Ledger timestamp of the revocation
+    pub timestamp: u64,
+}
+
+/// Data of the `transfer` event emitted when access moves to another user.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AccessTransferredEvent {
+    /// The course whose access was transferred
+    pub course_id: String,
+    /// The user who received the access
+    pub user: Address,
+    /// The previous holder, who transferred the access
+    pub actor: Address,
+    /// Enrollment ID assigned to the recipient's grant
+    pub enrollment_id: u64,
+    /// Access event sequence number
+    pub seq: u64,
+    /// Ledger timestamp of the transfer
+    pub timestamp: u64,
+}
+
+/// Data of the `revokeAll` event emitted when a course's roster is cleared.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AllAccessRevokedEvent {
+    /// The course whose roster was cleared
+    pub course_id: String,
+    /// The admin or course creator who revoked the access
+    pub actor: Address,
+    /// Number of access entries removed from the roster
+    pub count: u32,
+    /// Access event sequence number
+    pub seq: u64,
+    /// Ledger timestamp of the revocation
+    pub timestamp: u64,
+}
+
+/// Data of the `saveUsPrl` and `updUsPrl` events emitted when a profile is saved.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProfileSavedEvent {
+    /// The user whose profile was saved
+    pub user: Address,
+    /// The address that saved the profile (always the user)
+    pub actor: Address,
+    /// The profile as stored
+    pub profile: UserProfile,
+    /// The profile that was replaced, `None` when the profile is new
+    pub previous: Option<UserProfile>,
+    /// Ledger timestamp of the save
+    pub timestamp: u64,
+}
+
+/// A course as returned by the course registry's `get_course`.
+///
+/// Mirrors the registry's `Course` field for field so it can be decoded
+/// from cross-contract calls; keep the two in sync.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Course {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub creator: Address,
+    pub price: u128,
+    pub category: Option<String>,
+    pub language: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub published: bool,
+    pub prerequisites: Vec<CourseId>,
+    pub is_archived: bool,
+    pub level: Option<String>,
+    pub duration_hours: Option<u32>,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub tags: Vec<String>,
+    pub co_creators: Vec<Address>,
+    pub sale_price: Option<u128>,
+    pub sale_ends_at: Option<u64>,
+}
+
+/// Prerequisite reference of a registry `Course`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CourseId {
+    pub id: String,
+    pub count: u128,
+}
+
 /// Contains all users who have access to a specific course.
 ///
 /// This struct is used to efficiently query and return all users
@@ -78,8 +588,90 @@ pub struct CourseUsers {
     pub users: Vec<Address>,
 }
 
+/// How `import_access_data` treats data that already exists on-chain.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AccessImportMode {
+    /// Keep existing grants and profiles; only add what is missing
+    Merge,
+    /// Replace the grants of every backed-up course and every backed-up profile
+    Overwrite,
+}
+
+/// Access entries of one course in an access backup.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CourseAccessChunk {
+    /// The unique identifier of the course
+    pub course: String,
+    /// Access entries of the course, stored exactly as on-chain
+    pub entries: Vec<CourseAccess>,
+}
+
+/// Backup of all course access data.
+///
+/// Grants are stored as `CourseAccessChunk`s of at most
+/// `ACCESS_BACKUP_CHUNK_SIZE` entries each, so a course with many students
+/// spans several chunks.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AccessBackupData {
+    /// Full access entries, split into bounded chunks per course
+    pub grants: Vec<CourseAccessChunk>,
+    /// Payments of the exported entries, by enrollment ID
+    pub purchases: Map<u64, Purchase>,
+    /// Saved user profiles
+    pub profiles: Map<Address, UserProfile>,
+    /// Enrollment history of every exported grant
+    pub history: Vec<EnrollmentRecord>,
+    /// Backup format version
+    pub backup_version: String,
+    /// Contract version that produced the backup
+    pub source_version: String,
+    /// Ledger timestamp at which the backup was exported
+    pub exported_at: u64,
+    /// Hex-encoded SHA-256 over all other fields, verified on import
+    pub checksum: String,
+}
+
+/// Maximum number of entries in a single `CourseAccessChunk` of an access backup.
+pub const ACCESS_BACKUP_CHUNK_SIZE: u32 = 50;
+
+/// TTL in ledgers below which access records are extended when they are read
+pub const ACCESS_TTL_THRESHOLD: u32 = 17_280 * 7;
+
+/// TTL in ledgers that access records are extended to (about 30 days)
+pub const ACCESS_TTL_EXTEND_TO: u32 = 17_280 * 30;
+
 /// Global configuration key for storing the user management contract address
 pub const KEY_USER_MGMT_ADDR: &str = "USER_MGMT_ADDR";
 
 /// Global configuration key for storing the course registry contract address
 pub const KEY_COURSE_REG_ADDR: &str = "COURSE_REGISTRY_ADDR";
+
+/// Global configuration key for storing the locally managed admin addresses
+pub const KEY_ADMINS: &str = "ADMINS";
+
+/// Maximum number of locally managed admins
+pub const MAX_LOCAL_ADMINS: u32 = 20;
+
+/// Global configuration key for storing the optional enrollment notification contract address
+pub const KEY_NOTIFY_ADDR: &str = "NOTIFY_ADDR";
+
+/// Global key for storing the platform-wide `AccessCounters`
+pub const KEY_ACCESS_COUNTERS: &str = "ACCESS_COUNTERS";
+
+/// Global configuration key for storing the maximum number of courses per user
+pub const KEY_USER_COURSE_LIMIT: &str = "USER_COURSE_LIMIT";
+
+/// Global configuration key for storing the `BanCheckConfig`
+pub const KEY_BAN_CHECK: &str = "BAN_CHECK";
+
+/// Global configuration key for storing whether grants require a user profile
+pub const KEY_REQUIRE_PROFILE: &str = "REQUIRE_PROFILE";
+
+/// Global configuration key for storing the token used to pay for courses
+pub const KEY_PAYMENT_TOKEN: &str = "PAYMENT_TOKEN";
+
+/// Global configuration key for storing the `GrantRateLimitConfig`
+pub const KEY_GRANT_RATE_LIMIT: &str = "GRANT_RATE_LIMIT";
@@ -3,6 +3,9 @@
 
 use soroban_sdk::{contracterror, panic_with_error, Env};
 
+// Codes are published in schemas/; never reassign a code that has shipped.
+// The contract spec allows at most 50 error cases per enum and `Error` is
+// full, so new codes go in the overflow enums below.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -60,6 +63,29 @@ pub enum Error {
     CourseRateLimitNotConfigured = 58,
 }
 
-pub fn handle_error(env: &Env, error: Error) -> ! {
+// Overflow of `Error` for rejected input. Codes continue after those of
+// `Error` and never overlap them.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ValidationError {
+    BackupChecksumMismatch = 59,
+    InvalidCourseLevel = 60,
+    InvalidCourseLanguage = 61,
+    TooManyCoCreators = 62,
+    CoCreatorNotFound = 63,
+}
+
+// `Error` is at the spec's limit of 50 cases, so later codes live here.
+// Codes continue after those of `Error` and never overlap them.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum StateError {
+    NotInitialized = 64,
+    InvalidImportChunk = 65,
+}
+
+pub fn handle_error(env: &Env, error: impl Into<soroban_sdk::Error>) -> ! {
     panic_with_error!(env, error);
 }
@@ -3,7 +3,7 @@
 
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol, IntoVal};
 
-use crate::error::{handle_error, Error};
+use crate::error::{handle_error, Error, StateError};
 use crate::schema::Course;
 use super::course_rate_limit_utils::initialize_course_rate_limit_config;
 
@@ -11,9 +11,13 @@ const COURSE_KEY: Symbol = symbol_short!("course");
 
 const INIT_ACCESS_CONTROL_EVENT: Symbol = symbol_short!("initAcCtr");
 const UPDATE_USER_MNGMT_EVENT: Symbol = symbol_short!("upUsrMgt");
+const STAGING_MODE_EVENT: Symbol = symbol_short!("staging");
+const UPDATE_COURSE_ACCESS_EVENT: Symbol = symbol_short!("upCrsAcc");
 
 const KEY_USER_MGMT_ADDR: &str = "user_mgmt_addr";
 const KEY_OWNER: &str = "owner";
+const KEY_STAGING: &str = "staging";
+const KEY_COURSE_ACCESS_ADDR: &str = "course_access_addr";
 
 /// Check if a user is an admin by querying the user management contract
 pub fn is_admin(env: &Env, who: &Address) -> bool {
@@ -33,19 +37,24 @@ pub fn is_admin(env: &Env, who: &Address) -> bool {
     }
 }
 
-/// Check if a user is the creator of a specific course
+/// Check if a user is the creator or a co-creator of a specific course
 pub fn is_course_creator(env: &Env, course_id: &String, who: &Address) -> bool {
     let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
 
     match env.storage().persistent().get::<_, Course>(&key) {
-        Some(course) => course.creator == *who,
+        Some(course) => is_creator_or_co_creator(&course, who),
         None => false,
     }
 }
 
+/// Returns `true` if `who` created the course or is one of its co-creators
+pub fn is_creator_or_co_creator(course: &Course, who: &Address) -> bool {
+    course.creator == *who || course.co_creators.contains(who)
+}
+
 /// Require that the caller has proper authorization for course management
 /// Authorization is granted if the caller is:
-/// 1. The course creator
+/// 1. The course creator or a co-creator
 /// 2. An admin
 pub fn require_course_management_auth(env: &Env, caller: &Address, course_id: &String) {
     // Always require basic authentication
@@ -76,17 +85,23 @@ pub fn initialize(env: &Env, owner: &Address, user_mgmt_addr: &Address) {
         .publish((INIT_ACCESS_CONTROL_EVENT,), (owner, user_mgmt_addr));
 }
 
+/// Get the contract owner set by `initialize`
+///
+/// Fails with `StateError::NotInitialized` before the contract is initialized.
+pub fn get_owner(env: &Env) -> Address {
+    match env.storage().instance().get(&(KEY_OWNER,)) {
+        Some(owner) => owner,
+        None => handle_error(env, StateError::NotInitialized),
+    }
+}
+
 /// Update the user management contract address
 /// Only the contract owner can perform this update
 pub fn update_user_mgmt_address(env: &Env, caller: &Address, new_addr: &Address) {
     caller.require_auth();
 
     // Check if caller is contract owner
-    let owner: Address = env
-        .storage()
-        .instance()
-        .get(&(KEY_OWNER,))
-        .expect("Contract not initialized");
+    let owner: Address = get_owner(env);
 
     if *caller != owner {
         handle_error(env, Error::Unauthorized)
@@ -99,6 +114,54 @@ pub fn update_user_mgmt_address(env: &Env, caller: &Address, new_addr: &Address)
         .publish((UPDATE_USER_MNGMT_EVENT,), (caller, new_addr));
 }
 
+/// Flag or unflag this deployment as staging
+/// Only the contract owner can change the flag. Destructive maintenance
+/// operations such as purging all courses only run while it is set.
+pub fn set_staging_mode(env: &Env, caller: &Address, enabled: bool) {
+    caller.require_auth();
+
+    let owner: Address = get_owner(env);
+
+    if *caller != owner {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    env.storage().instance().set(&(KEY_STAGING,), &enabled);
+    env.events().publish((STAGING_MODE_EVENT,), (caller, enabled));
+}
+
+/// Check whether this deployment is flagged as staging
+pub fn is_staging(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&(KEY_STAGING,))
+        .unwrap_or(false)
+}
+
+/// Set the course access contract notified when a course is deleted
+/// Only the contract owner can change it; `None` stops the notifications
+pub fn set_course_access_addr(env: &Env, caller: &Address, addr: &Option<Address>) {
+    caller.require_auth();
+
+    let owner: Address = get_owner(env);
+
+    if *caller != owner {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    match addr {
+        Some(addr) => env.storage().instance().set(&(KEY_COURSE_ACCESS_ADDR,), addr),
+        None => env.storage().instance().remove(&(KEY_COURSE_ACCESS_ADDR,)),
+    }
+    env.events()
+        .publish((UPDATE_COURSE_ACCESS_EVENT,), (caller, addr.clone()));
+}
+
+/// Get the course access contract notified when a course is deleted, if any
+pub fn get_course_access_addr(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&(KEY_COURSE_ACCESS_ADDR,))
+}
+
 #[cfg(test)]
 mod tests {
     // Note: These tests are commented out due to complex storage access issues
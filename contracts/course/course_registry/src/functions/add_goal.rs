@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
 use crate::error::{handle_error, Error};
 use crate::functions::utils::{self, trim};
@@ -19,19 +19,12 @@ pub fn add_goal(env: Env, creator: Address, course_id: String, content: String)
         handle_error(&env, Error::EmptyCourseId);
     }
     
-    // Validate goal content - prevent empty or whitespace-only content
-    if content.is_empty() || trim(&env, &content).is_empty() {
-        handle_error(&env, Error::EmptyGoalContent);
-    }
-    
+    validate_goal_content(&env, &content);
+
     // Check string lengths to prevent extremely long values
     if course_id.len() > 100 {
         handle_error(&env, Error::InvalidCourseId);
     }
-    
-    if content.len() > 1000 {
-        handle_error(&env, Error::InvalidGoalContent);
-    }
 
     // Load course
     let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
@@ -46,8 +39,25 @@ pub fn add_goal(env: Env, creator: Address, course_id: String, content: String)
         handle_error(&env, Error::OnlyCreatorCanAddGoals)
     }
 
+    write_goal(&env, creator, course_id, content)
+}
+
+/// Reject empty, whitespace-only or overly long goal content
+pub fn validate_goal_content(env: &Env, content: &String) {
+    // Validate goal content - prevent empty or whitespace-only content
+    if content.is_empty() || trim(env, content).is_empty() {
+        handle_error(env, Error::EmptyGoalContent);
+    }
+
+    if content.len() > 1000 {
+        handle_error(env, Error::InvalidGoalContent);
+    }
+}
+
+/// Store a validated goal, add it to the course's goal list and emit the goal event
+pub fn write_goal(env: &Env, creator: Address, course_id: String, content: String) -> CourseGoal {
     // Generate a unique goal ID
-    let goal_id = utils::generate_unique_id(&env);
+    let goal_id = utils::generate_unique_id(env);
 
     // Create new goal
     let goal: CourseGoal = CourseGoal {
@@ -64,10 +74,20 @@ pub fn add_goal(env: Env, creator: Address, course_id: String, content: String)
         &goal,
     );
 
-    // Emit event
+    // Keep the course's goal list in sync
+    let list_key: DataKey = DataKey::CourseGoalList(course_id.clone());
+    let mut goals: Vec<CourseGoal> = env
+        .storage()
+        .persistent()
+        .get(&list_key)
+        .unwrap_or(Vec::new(env));
+    goals.push_back(goal.clone());
+    env.storage().persistent().set(&list_key, &goals);
+
+    // Emit event with the new goal count
     env.events().publish(
         (GOAL_ADDED_EVENT, course_id.clone(), goal_id.clone()),
-        content.clone(),
+        (content.clone(), goals.len()),
     );
 
     goal
@@ -5,7 +5,7 @@ use soroban_sdk::{symbol_short, Vec, vec, Address, Env, String, Symbol};
 
 use crate::functions::utils::{concat_strings, u32_to_string};
 use crate::error::{handle_error, Error};
-use crate::schema::{CourseModule};
+use crate::schema::{CourseModule, DataKey};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 const MODULE_KEY: Symbol = symbol_short!("module");
@@ -24,23 +24,12 @@ pub fn course_registry_add_module(
         handle_error(&env, Error::EmptyCourseId);
     }
     
-    if title.is_empty() {
-        handle_error(&env, Error::InvalidModuleTitle);
-    }
-    
     // Check string lengths to prevent extremely long values
     if course_id.len() > 100 {
         handle_error(&env, Error::EmptyCourseId);
     }
-    
-    if title.len() > 500 {
-        handle_error(&env, Error::InvalidModuleTitle);
-    }
-    
-    // Validate position is reasonable (not extremely large)
-    if position > 10000 {
-        handle_error(&env, Error::InvalidModulePosition);
-    }
+
+    validate_module(&env, position, &title);
 
     let course_storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
 
@@ -57,19 +46,41 @@ pub fn course_registry_add_module(
         handle_error(&env, Error::DuplicateModulePosition)
     }
 
+    write_module(&env, caller, course_id, position, title)
+}
+
+/// Reject empty or overly long module titles and unreasonably large positions
+pub fn validate_module(env: &Env, position: u32, title: &String) {
+    if title.is_empty() {
+        handle_error(env, Error::InvalidModuleTitle);
+    }
+
+    if title.len() > 500 {
+        handle_error(env, Error::InvalidModuleTitle);
+    }
+
+    // Positions are 0-based: 0 is a valid position like any other.
+    // Validate position is reasonable (not extremely large)
+    if position > 10000 {
+        handle_error(env, Error::InvalidModulePosition);
+    }
+}
+
+/// Store a validated module at a free position, index it and emit the add event
+pub fn write_module(env: &Env, caller: Address, course_id: String, position: u32, title: String) -> CourseModule {
     let ledger_seq: u32 = env.ledger().sequence();
 
     let arr: Vec<String> = vec![
-        &env,
-        String::from_str(&env, "module_"),
+        env,
+        String::from_str(env, "module_"),
         course_id.clone(),
-        String::from_str(&env, "_"),
-        u32_to_string(&env, position),
-        String::from_str(&env, "_"),
-        u32_to_string(&env, ledger_seq),
+        String::from_str(env, "_"),
+        u32_to_string(env, position),
+        String::from_str(env, "_"),
+        u32_to_string(env, ledger_seq),
     ];
 
-    let module_id: String = concat_strings(&env, arr);
+    let module_id: String = concat_strings(env, arr);
 
     // Create new module
     let module: CourseModule = CourseModule {
@@ -84,7 +95,17 @@ pub fn course_registry_add_module(
     let position_key: (Symbol, String, u32) = (symbol_short!("pos"), course_id.clone(), position);
 
     env.storage().persistent().set(&storage_key, &module);
-    env.storage().persistent().set(&position_key, &true);
+    env.storage().persistent().set(&position_key, &module_id);
+
+    // Track the module in the course's module index
+    let modules_key: DataKey = DataKey::CourseModules(course_id.clone());
+    let mut module_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&modules_key)
+        .unwrap_or(Vec::new(env));
+    module_ids.push_back(module_id.clone());
+    env.storage().persistent().set(&modules_key, &module_ids);
 
     // emit an event
     env.events()
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::functions::add_module::course_registry_add_module;
+use crate::schema::{CourseModule, DataKey};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+/// Add a module after the last module of a course.
+///
+/// Module positions are 0-based, so the first module of a course is placed
+/// at position 0 and every later one at the highest used position plus one.
+pub fn append_module(env: Env, caller: Address, course_id: String, title: String) -> CourseModule {
+    let position: u32 = next_module_position(&env, &course_id);
+    course_registry_add_module(env, caller, course_id, position, title)
+}
+
+/// The position right after the highest position used by the course's modules, or 0 if it has none
+pub fn next_module_position(env: &Env, course_id: &String) -> u32 {
+    let module_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseModules(course_id.clone()))
+        .unwrap_or(Vec::new(env));
+
+    let mut next: u32 = 0;
+    for module_id in module_ids.iter() {
+        if let Some(module) = env
+            .storage()
+            .persistent()
+            .get::<(Symbol, String), CourseModule>(&(MODULE_KEY, module_id))
+        {
+            next = next.max(module.position.saturating_add(1));
+        }
+    }
+    next
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::list_modules::course_registry_list_modules;
+    use crate::{schema::Course, CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup_course<'a>() -> (Env, CourseRegistryClient<'a>, Address, Course) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, creator, course)
+    }
+
+    #[test]
+    fn test_module_at_position_zero_is_listed() {
+        let (env, client, creator, course) = setup_course();
+
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"));
+        assert_eq!(module.position, 0);
+
+        let module_ids = client.get_course_module_ids(&course.id);
+        assert_eq!(module_ids.len(), 1);
+        assert_eq!(module_ids.get(0).unwrap(), module.id);
+        let stored = env.as_contract(&client.address, || course_registry_list_modules(&env, module.id.clone()));
+        assert_eq!(stored, module);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #405)")]
+    fn test_module_at_position_zero_is_a_taken_position() {
+        let (env, client, creator, course) = setup_course();
+
+        client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"));
+        client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro again"));
+    }
+
+    #[test]
+    fn test_append_module_starts_at_zero() {
+        let (env, client, creator, course) = setup_course();
+
+        let first = client.append_module(&creator, &course.id, &String::from_str(&env, "Intro"));
+        let second = client.append_module(&creator, &course.id, &String::from_str(&env, "Basics"));
+
+        assert_eq!(first.position, 0);
+        assert_eq!(second.position, 1);
+        let ids = client.get_course_module_ids(&course.id);
+        assert_eq!(ids.get(0).unwrap(), first.id);
+        assert_eq!(ids.get(1).unwrap(), second.id);
+    }
+
+    #[test]
+    fn test_append_module_follows_highest_position() {
+        let (env, client, creator, course) = setup_course();
+
+        client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"));
+        client.add_module(&creator, &course.id, &4, &String::from_str(&env, "Advanced"));
+
+        let appended = client.append_module(&creator, &course.id, &String::from_str(&env, "Wrap-up"));
+        assert_eq!(appended.position, 5);
+    }
+}
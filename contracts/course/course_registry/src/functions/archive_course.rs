@@ -28,6 +28,7 @@ pub fn archive_course(env: &Env, creator: Address, course_id: String) -> Course
         handle_error(env, Error::CourseAlreadyArchived)
     }
     course.is_archived = true;
+    course.updated_at = env.ledger().timestamp();
 
     env.storage().persistent().set(&key, &course);
     
@@ -1,8 +1,19 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use crate::schema::{Course, CourseBackupData, CourseCategory, CourseGoal, CourseId, CourseModule, DataKey};
-use soroban_sdk::{Address, Env, Map, String, Vec};
+use crate::error::{handle_error, Error, StateError, ValidationError};
+use crate::functions::contract_versioning::is_version_compatible;
+use crate::functions::count_courses_by_category::{add_to_category_index, remove_from_category_index};
+use crate::functions::get_courses_by_level::{add_to_level_index, remove_from_level_index};
+use crate::functions::list_courses_by_language::{add_to_language_index, remove_from_language_index};
+use crate::schema::{
+    BackupChunk, Course, CourseBackupData, CourseCategory, CourseGoal, CourseId, CourseModule,
+    DataKey, ImportProgress,
+};
+use soroban_sdk::{xdr::ToXdr, Address, Env, Map, String, Vec};
+
+/// Maximum number of courses imported by a single `import_course_data_chunk` call
+pub const IMPORT_CHUNK_SIZE: u32 = 10;
 
 /// Export all course data for backup purposes
 ///
@@ -14,7 +25,8 @@ use soroban_sdk::{Address, Env, Map, String, Vec};
 /// * `caller` - Address requesting the backup (must be admin)
 ///
 /// # Returns
-/// * `CourseBackupData` - Complete backup structure
+/// * `CourseBackupData` - Complete backup structure, including a checksum
+///   over its contents
 ///
 /// # Panics
 /// * If caller is not an admin
@@ -122,7 +134,7 @@ pub fn export_course_data(env: Env, caller: Address) -> CourseBackupData {
         .unwrap_or(Vec::new(&env));
 
     // Create backup data structure
-    CourseBackupData {
+    let mut backup_data = CourseBackupData {
         courses,
         categories,
         modules,
@@ -132,7 +144,15 @@ pub fn export_course_data(env: Env, caller: Address) -> CourseBackupData {
         admins,
         backup_timestamp: env.ledger().timestamp(),
         backup_version: String::from_str(&env, "1.0.0"),
-    }
+        source_version: String::from_str(&env, crate::VERSION),
+        chunk: None,
+        checksum: String::from_str(&env, ""),
+    };
+
+    // Seal the backup so tampering can be detected on import
+    backup_data.checksum = compute_backup_checksum(&env, &backup_data);
+
+    backup_data
 }
 
 /// Import course data from backup
@@ -151,29 +171,22 @@ pub fn export_course_data(env: Env, caller: Address) -> CourseBackupData {
 /// # Panics
 /// * If caller is not an admin
 /// * If backup data is invalid
+/// * If the backup was exported by an incompatible contract version
+/// * If the checksum does not match the backup contents
+/// * If `backup_data` is a chunk rather than a whole backup
 pub fn import_course_data(env: Env, caller: Address, backup_data: CourseBackupData) -> u32 {
-    caller.require_auth();
-
-    // Verify caller is admin
-    if !is_admin(&env, caller) {
-        panic!("Unauthorized: Only admins can import course data");
-    }
+    validate_backup(&env, caller, &backup_data);
 
-    // Validate backup version compatibility
-    let expected_version = String::from_str(&env, "1.0.0");
-    if backup_data.backup_version != expected_version {
-        panic!("Incompatible backup version");
+    // A chunk only holds part of a backup
+    if backup_data.chunk.is_some() {
+        handle_error(&env, StateError::InvalidImportChunk);
     }
 
     let mut imported_count = 0u32;
-    let course_key = soroban_sdk::symbol_short!("course");
 
     // Import courses - store each course individually
     for (_course_id, course) in backup_data.courses.iter() {
-        let storage_key = (course_key.clone(), course.id.clone());
-        env.storage()
-            .persistent()
-            .set(&storage_key, &course);
+        store_imported_course(&env, &course);
         imported_count += 1;
     }
 
@@ -224,6 +237,303 @@ pub fn import_course_data(env: Env, caller: Address, backup_data: CourseBackupDa
     imported_count
 }
 
+/// Split a backup into chunks for `import_course_data_chunk`
+///
+/// Each chunk holds at most `IMPORT_CHUNK_SIZE` courses (with their goals
+/// and prerequisites), `IMPORT_CHUNK_SIZE` categories and
+/// `IMPORT_CHUNK_SIZE` modules, carries the backup's metadata and its
+/// position within the backup, and is sealed with its own checksum. Meant to
+/// be run off-chain by import tooling.
+pub fn split_backup(env: &Env, backup_data: &CourseBackupData) -> Vec<CourseBackupData> {
+    let size: u32 = IMPORT_CHUNK_SIZE;
+    let count: u32 = backup_data
+        .courses
+        .len()
+        .max(backup_data.categories.len())
+        .max(backup_data.modules.len())
+        .div_ceil(size)
+        .max(1);
+
+    let mut chunks: Vec<CourseBackupData> = Vec::new(env);
+    for index in 0..count {
+        let mut chunk: CourseBackupData = CourseBackupData {
+            courses: Map::new(env),
+            categories: Map::new(env),
+            modules: Map::new(env),
+            goals: Map::new(env),
+            prerequisites: Map::new(env),
+            chunk: Some(BackupChunk {
+                backup_checksum: backup_data.checksum.clone(),
+                index,
+                count,
+            }),
+            checksum: String::from_str(env, ""),
+            ..backup_data.clone()
+        };
+
+        for (course_id, course) in backup_data
+            .courses
+            .iter()
+            .skip((index * size) as usize)
+            .take(size as usize)
+        {
+            if let Some(course_goals) = backup_data.goals.get(course_id.clone()) {
+                chunk.goals.set(course_id.clone(), course_goals);
+            }
+            if let Some(prereqs) = backup_data.prerequisites.get(course_id.clone()) {
+                chunk.prerequisites.set(course_id.clone(), prereqs);
+            }
+            chunk.courses.set(course_id, course);
+        }
+        for (category_id, category) in backup_data
+            .categories
+            .iter()
+            .skip((index * size) as usize)
+            .take(size as usize)
+        {
+            chunk.categories.set(category_id, category);
+        }
+        for (module_id, module) in backup_data
+            .modules
+            .iter()
+            .skip((index * size) as usize)
+            .take(size as usize)
+        {
+            chunk.modules.set(module_id, module);
+        }
+
+        chunk.checksum = compute_backup_checksum(env, &chunk);
+        chunks.push_back(chunk);
+    }
+    chunks
+}
+
+/// Import one chunk of course data from a backup
+///
+/// Large backups may not fit in a single transaction's budget. A chunk is a
+/// `CourseBackupData` holding part of a backup, as produced by
+/// `split_backup`: every map in it holds at most `IMPORT_CHUNK_SIZE`
+/// entries and its checksum covers only its own contents, so each call does
+/// a bounded amount of work. The category sequence and the admin list are
+/// imported with the first chunk.
+///
+/// The import progress (the backup's checksum and the next chunk index) is
+/// stored between calls, so chunks must be imported in order, each exactly
+/// once, and all from the same backup. Passing `None` with the first chunk
+/// starts (or restarts) an import; the progress is cleared once the last
+/// chunk is imported.
+///
+/// # Arguments
+/// * `env` - Soroban environment
+/// * `caller` - Address performing the import (must be admin)
+/// * `chunk` - Part of a backup to restore
+/// * `continuation_token` - Index of this chunk, as returned by the previous call (`None` for the first chunk)
+///
+/// # Returns
+/// * `u32` - Token for the next call, the index of the next chunk
+///
+/// # Panics
+/// * Same conditions as `import_course_data`, checked against the chunk
+/// * If any map in the chunk holds more than `IMPORT_CHUNK_SIZE` entries
+/// * If the chunk is not the next one of the import in progress, or the
+///   token does not match the stored progress
+pub fn import_course_data_chunk(
+    env: Env,
+    caller: Address,
+    chunk: CourseBackupData,
+    continuation_token: Option<u32>,
+) -> u32 {
+    if chunk.courses.len() > IMPORT_CHUNK_SIZE
+        || chunk.categories.len() > IMPORT_CHUNK_SIZE
+        || chunk.modules.len() > IMPORT_CHUNK_SIZE
+        || chunk.goals.len() > IMPORT_CHUNK_SIZE
+        || chunk.prerequisites.len() > IMPORT_CHUNK_SIZE
+    {
+        handle_error(&env, Error::InvalidLimitValue);
+    }
+
+    validate_backup(&env, caller, &chunk);
+
+    let position: BackupChunk = match chunk.chunk.clone() {
+        Some(position) => position,
+        None => handle_error(&env, StateError::InvalidImportChunk),
+    };
+
+    match continuation_token {
+        // Starting an import: only the first chunk may come without a token
+        None => {
+            if position.index != 0 {
+                handle_error(&env, StateError::InvalidImportChunk);
+            }
+        }
+        // Continuing an import: the chunk must be the next one of the same backup
+        Some(token) => {
+            let progress: ImportProgress = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ImportProgress)
+                .unwrap_or_else(|| handle_error(&env, StateError::InvalidImportChunk));
+            if token != progress.next_index
+                || position.index != progress.next_index
+                || position.backup_checksum != progress.backup_checksum
+                || position.count != progress.count
+            {
+                handle_error(&env, StateError::InvalidImportChunk);
+            }
+        }
+    }
+
+    let index: u32 = position.index;
+    if index == 0 {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CategorySeq, &chunk.category_seq);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Admins, &chunk.admins);
+    }
+
+    for (category_id, category) in chunk.categories.iter() {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CourseCategory(category_id), &category);
+    }
+
+    for (module_id, module) in chunk.modules.iter() {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Module(module_id), &module);
+    }
+
+    for (_course_id, course) in chunk.courses.iter() {
+        store_imported_course(&env, &course);
+    }
+
+    for (course_id, course_goals) in chunk.goals.iter() {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CourseGoalList(course_id), &course_goals);
+    }
+
+    for (course_id, prereqs) in chunk.prerequisites.iter() {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CoursePrerequisites(course_id), &prereqs);
+    }
+
+    env.events().publish(
+        (String::from_str(&env, "course_data_chunk_imported"),),
+        (index, chunk.courses.len(), chunk.backup_timestamp),
+    );
+
+    let next_index: u32 = index + 1;
+    if next_index >= position.count {
+        env.storage().persistent().remove(&DataKey::ImportProgress);
+    } else {
+        env.storage().persistent().set(
+            &DataKey::ImportProgress,
+            &ImportProgress {
+                backup_checksum: position.backup_checksum,
+                next_index,
+                count: position.count,
+            },
+        );
+    }
+
+    next_index
+}
+
+/// Store an imported course and update the listing indexes
+///
+/// A course that already exists is moved out of the index buckets of its
+/// stored version first, so overwritten courses do not leave stale entries.
+fn store_imported_course(env: &Env, course: &Course) {
+    let storage_key = (soroban_sdk::symbol_short!("course"), course.id.clone());
+    if let Some(previous) = env.storage().persistent().get::<_, Course>(&storage_key) {
+        if let Some(ref level) = previous.level {
+            remove_from_level_index(env, level, &course.id);
+        }
+        if let Some(ref language) = previous.language {
+            remove_from_language_index(env, language, &course.id);
+        }
+        if let Some(ref category) = previous.category {
+            remove_from_category_index(env, category, &course.id);
+        }
+    }
+
+    env.storage().persistent().set(&storage_key, course);
+
+    if let Some(ref level) = course.level {
+        add_to_level_index(env, level, &course.id);
+    }
+    if let Some(ref language) = course.language {
+        add_to_language_index(env, language, &course.id);
+    }
+    if let Some(ref category) = course.category {
+        add_to_category_index(env, category, &course.id);
+    }
+}
+
+/// Check that a backup may be imported by `caller`
+fn validate_backup(env: &Env, caller: Address, backup_data: &CourseBackupData) {
+    caller.require_auth();
+
+    // Verify caller is admin
+    if !is_admin(env, caller) {
+        panic!("Unauthorized: Only admins can import course data");
+    }
+
+    // Validate backup version compatibility
+    let expected_version = String::from_str(env, "1.0.0");
+    if backup_data.backup_version != expected_version {
+        panic!("Incompatible backup version");
+    }
+
+    // Refuse backups exported by an incompatible contract version
+    if !is_version_compatible(
+        env,
+        backup_data.source_version.clone(),
+        String::from_str(env, crate::VERSION),
+    ) {
+        panic!("Incompatible backup source version");
+    }
+
+    // Reject corrupted or tampered backups
+    if compute_backup_checksum(env, backup_data) != backup_data.checksum {
+        handle_error(env, ValidationError::BackupChecksumMismatch);
+    }
+}
+
+/// Compute the checksum of a backup
+///
+/// The checksum is the hex-encoded SHA-256 of the XDR serialization of every
+/// backup field except the checksum itself.
+fn compute_backup_checksum(env: &Env, backup_data: &CourseBackupData) -> String {
+    let contents = (
+        backup_data.courses.clone(),
+        backup_data.categories.clone(),
+        backup_data.modules.clone(),
+        backup_data.goals.clone(),
+        backup_data.prerequisites.clone(),
+        backup_data.category_seq,
+        backup_data.admins.clone(),
+        backup_data.backup_timestamp,
+        backup_data.backup_version.clone(),
+        backup_data.source_version.clone(),
+        backup_data.chunk.clone(),
+    );
+    let hash: [u8; 32] = env.crypto().sha256(&contents.to_xdr(env)).to_array();
+
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut hex: [u8; 64] = [0u8; 64];
+    for (i, byte) in hash.iter().enumerate() {
+        hex[i * 2] = HEX_DIGITS[(byte >> 4) as usize];
+        hex[i * 2 + 1] = HEX_DIGITS[(byte & 0x0f) as usize];
+    }
+    String::from_bytes(env, &hex)
+}
+
 /// Check if an address is an admin
 /// 
 /// This is a simplified version for the backup system.
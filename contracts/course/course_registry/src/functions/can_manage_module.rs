@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::schema::CourseModule;
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+/// Returns `true` if `caller` may manage the module
+///
+/// Resolves the module's course and applies the course management rules:
+/// the course creator, a co-creator or an admin. Unknown modules return `false`.
+pub fn can_manage_module(env: &Env, caller: Address, module_id: String) -> bool {
+    let module: CourseModule = match env.storage().persistent().get(&(MODULE_KEY, module_id)) {
+        Some(module) => module,
+        None => return false,
+    };
+
+    is_course_creator(env, &module.course_id, &caller) || is_admin(env, &caller)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, CourseModule};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup_module(env: &Env) -> (CourseRegistryClient<'_>, Address, CourseModule) {
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator: Address = Address::generate(env);
+
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(env, "title"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module: CourseModule =
+            client.add_module(&creator, &course.id, &0, &String::from_str(env, "Module 1"));
+
+        (client, creator, module)
+    }
+
+    #[test]
+    fn test_course_creator_can_manage_module() {
+        let env = Env::default();
+        let (client, creator, module) = setup_module(&env);
+
+        assert!(client.can_manage_module(&creator, &module.id));
+    }
+
+    #[test]
+    fn test_co_creator_can_manage_module() {
+        let env = Env::default();
+        let (client, creator, module) = setup_module(&env);
+        let co_creator: Address = Address::generate(&env);
+
+        client.add_co_creator(&creator, &module.course_id, &co_creator);
+
+        assert!(client.can_manage_module(&co_creator, &module.id));
+    }
+
+    #[test]
+    fn test_unrelated_user_cannot_manage_module() {
+        let env = Env::default();
+        let (client, _creator, module) = setup_module(&env);
+
+        assert!(!client.can_manage_module(&Address::generate(&env), &module.id));
+    }
+
+    #[test]
+    fn test_nonexistent_module_cannot_be_managed() {
+        let env = Env::default();
+        let (client, creator, _module) = setup_module(&env);
+
+        assert!(!client.can_manage_module(&creator, &String::from_str(&env, "missing_module")));
+    }
+}
@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error, ValidationError};
+use crate::schema::Course;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const ADD_CO_CREATOR_EVENT: Symbol = symbol_short!("coCrAdd");
+const REMOVE_CO_CREATOR_EVENT: Symbol = symbol_short!("coCrRm");
+
+/// Maximum number of co-creators per course
+pub const MAX_CO_CREATORS: u32 = 10;
+
+/// Add a co-creator to a course.
+///
+/// Co-creators can manage the course like its creator, except for managing
+/// co-creators. Adding the creator or an existing co-creator changes nothing.
+/// Only the course creator can add co-creators.
+pub fn add_co_creator(env: &Env, creator: Address, course_id: String, co_creator: Address) -> Course {
+    creator.require_auth();
+
+    let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = match env.storage().persistent().get(&key) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+
+    if course.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if co_creator == course.creator || course.co_creators.contains(&co_creator) {
+        return course;
+    }
+
+    if course.co_creators.len() >= MAX_CO_CREATORS {
+        handle_error(env, ValidationError::TooManyCoCreators)
+    }
+
+    course.co_creators.push_back(co_creator.clone());
+    course.updated_at = env.ledger().timestamp();
+    env.storage().persistent().set(&key, &course);
+
+    env.events()
+        .publish((ADD_CO_CREATOR_EVENT, course_id), co_creator);
+
+    course
+}
+
+/// Remove a co-creator from a course.
+///
+/// Only the course creator can remove co-creators.
+pub fn remove_co_creator(env: &Env, creator: Address, course_id: String, co_creator: Address) -> Course {
+    creator.require_auth();
+
+    let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = match env.storage().persistent().get(&key) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+
+    if course.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let index: u32 = match course.co_creators.first_index_of(&co_creator) {
+        Some(index) => index,
+        None => handle_error(env, ValidationError::CoCreatorNotFound),
+    };
+
+    course.co_creators.remove(index);
+    course.updated_at = env.ledger().timestamp();
+    env.storage().persistent().set(&key, &course);
+
+    env.events()
+        .publish((REMOVE_CO_CREATOR_EVENT, course_id), co_creator);
+
+    course
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup_test_env() -> (Env, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        (env, client)
+    }
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, creator: &Address) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(env, "Co-authored course"),
+            &String::from_str(env, "Description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn rename_params(env: &Env, title: &str) -> EditCourseParams {
+        EditCourseParams {
+            new_title: Some(String::from_str(env, title)),
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_co_creator_can_edit_course() {
+        let (env, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let co_creator = Address::generate(&env);
+        let course = create_course(&env, &client, &creator);
+
+        let updated = client.add_co_creator(&creator, &course.id, &co_creator);
+        assert_eq!(updated.co_creators.len(), 1);
+        assert!(client.is_course_creator(&course.id, &co_creator));
+
+        let edited = client.edit_course(&co_creator, &course.id, &rename_params(&env, "Renamed"));
+        assert_eq!(edited.title, String::from_str(&env, "Renamed"));
+
+        // Adding the same co-creator again changes nothing
+        assert_eq!(client.add_co_creator(&creator, &course.id, &co_creator).co_creators.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_removed_co_creator_loses_access() {
+        let (env, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let co_creator = Address::generate(&env);
+        let course = create_course(&env, &client, &creator);
+
+        client.add_co_creator(&creator, &course.id, &co_creator);
+        client.remove_co_creator(&creator, &course.id, &co_creator);
+        assert!(!client.is_course_creator(&course.id, &co_creator));
+
+        client.edit_course(&co_creator, &course.id, &rename_params(&env, "Renamed"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_co_creator_cannot_add_co_creators() {
+        let (env, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let co_creator = Address::generate(&env);
+        let course = create_course(&env, &client, &creator);
+
+        client.add_co_creator(&creator, &course.id, &co_creator);
+        client.add_co_creator(&co_creator, &course.id, &Address::generate(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #62)")]
+    fn test_co_creator_list_is_capped() {
+        let (env, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let course = create_course(&env, &client, &creator);
+
+        for _ in 0..=super::MAX_CO_CREATORS {
+            client.add_co_creator(&creator, &course.id, &Address::generate(&env));
+        }
+    }
+}
@@ -1,7 +1,14 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracterror, Address, Env, String, Vec, vec};
+use soroban_sdk::{
+    contracterror, symbol_short, vec, Address, Env, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::functions::utils::u32_to_string;
+use crate::schema::{Course, LegacyCourseV1, LegacyCourseV1_1, LegacyCourseV1_2};
 
 /// Errors that can occur during contract versioning operations
 #[contracterror]
@@ -25,6 +32,20 @@ pub enum VersioningError {
 /// Storage keys for versioning data
 const VERSION_HISTORY_KEY: &str = "version_history";
 const MIGRATION_STATUS_KEY: &str = "migration_status";
+const DATA_VERSION_KEY: &str = "data_version";
+
+/// Layout of course data stored before the data version was tracked
+const INITIAL_DATA_VERSION: &str = "1.0.0";
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const MIGRATION_EVENT: Symbol = symbol_short!("crsMigr");
+const ROLLBACK_EVENT: Symbol = symbol_short!("crsRollbk");
+
+/// Course data layouts in the order they were introduced, with the number
+/// of fields a course stored in that layout has. `migrate_course_data` can
+/// only move between versions listed here, one layout step at a time.
+const COURSE_LAYOUTS: [(&str, u32); 4] = [("1.0.0", 13), ("1.1.0", 16), ("1.2.0", 17), ("1.3.0", 19)];
 
 /// Get the version history of the contract
 pub fn get_version_history(env: &Env) -> Vec<String> {
@@ -71,25 +92,133 @@ fn set_migration_status(env: &Env, status: String) {
 }
 
 /// Check compatibility between two versions
-pub fn is_version_compatible(_env: &Env, _from_version: String, _to_version: String) -> bool {
-    // Simple compatibility check - for now, assume all versions are compatible
-    // In a real implementation, you would parse semantic versions properly
-    true
+///
+/// Versions are compatible when both are valid `MAJOR.MINOR.PATCH` strings
+/// sharing the same major version.
+pub fn is_version_compatible(_env: &Env, from_version: String, to_version: String) -> bool {
+    match (parse_major_version(&from_version), parse_major_version(&to_version)) {
+        (Some(from_major), Some(to_major)) => from_major == to_major,
+        _ => false,
+    }
+}
+
+/// Maximum number of version pairs that can be checked in one call
+pub const MAX_COMPATIBILITY_BATCH_SIZE: u32 = 50;
+
+/// Check compatibility of several `(from_version, to_version)` pairs at once
+///
+/// The result is aligned with `pairs`: entry `i` is `is_version_compatible`
+/// for `pairs[i]`, so malformed versions map to `false`. Panics with
+/// `InvalidLimitValue` if more than `MAX_COMPATIBILITY_BATCH_SIZE` pairs are passed.
+pub fn check_compatibility_batch(env: &Env, pairs: Vec<(String, String)>) -> Vec<bool> {
+    if pairs.len() > MAX_COMPATIBILITY_BATCH_SIZE {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+
+    let mut results: Vec<bool> = Vec::new(env);
+    for (from_version, to_version) in pairs.iter() {
+        results.push_back(is_version_compatible(env, from_version, to_version));
+    }
+    results
+}
+
+/// Extract the major component of a `MAJOR.MINOR.PATCH` version string
+fn parse_major_version(version: &String) -> Option<u32> {
+    parse_version(version).map(|(major, _, _)| major)
+}
+
+/// Parse a `MAJOR.MINOR.PATCH` version string into its components
+fn parse_version(version: &String) -> Option<(u32, u32, u32)> {
+    let len: usize = version.len() as usize;
+    let mut buffer: [u8; 32] = [0u8; 32];
+    if len == 0 || len > buffer.len() {
+        return None;
+    }
+    version.copy_into_slice(&mut buffer[..len]);
+
+    let mut parts: [u32; 3] = [0; 3];
+    let mut dots: usize = 0;
+    let mut digits_in_part: u32 = 0;
+    for byte in buffer[..len].iter() {
+        match *byte {
+            b'0'..=b'9' => {
+                parts[dots] = parts[dots].checked_mul(10)?.checked_add((*byte - b'0') as u32)?;
+                digits_in_part += 1;
+            }
+            b'.' => {
+                if digits_in_part == 0 || dots == 2 {
+                    return None;
+                }
+                dots += 1;
+                digits_in_part = 0;
+            }
+            _ => return None,
+        }
+    }
+
+    if dots != 2 || digits_in_part == 0 {
+        return None;
+    }
+    Some((parts[0], parts[1], parts[2]))
+}
+
+/// Get the version of the layout stored course data is in
+///
+/// Data written before the version was tracked is in the 1.0.0 layout.
+pub fn get_data_version(env: &Env) -> String {
+    let key: String = String::from_str(env, DATA_VERSION_KEY);
+    env.storage()
+        .instance()
+        .get::<String, String>(&key)
+        .unwrap_or_else(|| String::from_str(env, INITIAL_DATA_VERSION))
+}
+
+/// Record the version of the layout stored course data is in
+fn set_data_version(env: &Env, version: &String) {
+    let key: String = String::from_str(env, DATA_VERSION_KEY);
+    env.storage().instance().set(&key, version);
+}
+
+/// Returns `true` if stored course data is already at `to_version` or later
+fn is_data_at_or_beyond(env: &Env, to_version: &String) -> bool {
+    match (parse_version(&get_data_version(env)), parse_version(to_version)) {
+        (Some(current), Some(target)) => current >= target,
+        _ => false,
+    }
 }
 
 /// Migrate course data between contract versions
+///
+/// Runs every layout step between `from_version` and `to_version` in order
+/// (see `COURSE_LAYOUTS`), rewriting stored courses in each step's source
+/// layout to the next one. Admins migrate all courses and move the stored
+/// data version to `to_version`; any other caller only migrates the courses
+/// they created and leaves the data version unchanged. Courses already in a
+/// later layout are left untouched.
+///
+/// Returns `false` without touching storage if the data version is already
+/// `to_version` or later, so a completed migration is never applied twice,
+/// or if there is no migration from `from_version` to `to_version`.
+///
+/// Emits a `crsMigr` event with the versions, the number of courses migrated
+/// and whether the migration succeeded.
 pub fn migrate_course_data(
     env: &Env,
-    _caller: Address,
+    caller: Address,
     from_version: String,
     to_version: String,
 ) -> bool {
-    // For course registry, we need to check if the caller is authorized
-    // This could be a course creator or admin (depending on your authorization logic)
-    // For now, we'll allow any authenticated user to perform migration
-    
-    // Validate versions exist in history
-    if !version_exists_in_history(env, &from_version) {
+    caller.require_auth();
+
+    // Refuse to apply a migration the data has already been through
+    if is_data_at_or_beyond(env, &to_version) {
+        return false;
+    }
+
+    // Validate versions exist in history. Before any version has been
+    // recorded there is nothing to check against.
+    let first_migration: bool = get_version_history(env).is_empty();
+    if !first_migration && !version_exists_in_history(env, &from_version) {
         set_migration_status(env, String::from_str(env, "Migration failed: Source version not found"));
         return false;
     }
@@ -100,69 +229,279 @@ pub fn migrate_course_data(
         return false;
     }
     
-    // Perform migration based on version differences
-    let migration_result = perform_course_data_migration(env, &from_version, &to_version);
-    
-    if migration_result {
-        // Update version history with new version
-        store_version_in_history(env, to_version.clone());
-        
-        // Set successful migration status
-        let status = String::from_str(env, "Migration completed successfully");
-        set_migration_status(env, status);
-        
-        // Emit migration event
-        emit_migration_event(env, &from_version, &to_version, true);
-        
-        true
-    } else {
-        set_migration_status(env, String::from_str(env, "Migration failed: Data transformation error"));
-        emit_migration_event(env, &from_version, &to_version, false);
-        false
+    // Only known layouts can be migrated, and only forward
+    let layouts: (Option<usize>, Option<usize>) =
+        (course_layout_index(env, &from_version), course_layout_index(env, &to_version));
+    let (from_layout, to_layout): (usize, usize) = match layouts {
+        (Some(from), Some(to)) if from < to => (from, to),
+        _ => {
+            set_migration_status(env, String::from_str(env, "Migration failed: No migration between these versions"));
+            return false;
+        }
+    };
+
+    // Perform migration one layout step at a time
+    let migrate_all: bool = is_admin(env, &caller);
+    let migrated: Option<u32> = migrate_courses(env, &caller, migrate_all, from_layout, to_layout);
+
+    match migrated {
+        Some(count) => {
+            // Update version history with new version
+            if first_migration {
+                store_version_in_history(env, from_version.clone());
+            }
+            if !version_exists_in_history(env, &to_version) {
+                store_version_in_history(env, to_version.clone());
+            }
+
+            // Only a migration covering every course moves the data version
+            if migrate_all {
+                set_data_version(env, &to_version);
+            }
+
+            // Set successful migration status
+            let status = String::from_str(env, "Migration completed successfully");
+            set_migration_status(env, status);
+
+            // Emit migration event
+            emit_migration_event(env, &caller, &from_version, &to_version, count, true);
+
+            true
+        }
+        None => {
+            set_migration_status(env, String::from_str(env, "Migration failed: Data transformation error"));
+            emit_migration_event(env, &caller, &from_version, &to_version, 0, false);
+            false
+        }
     }
 }
 
-/// Perform the actual course data migration between versions
-fn perform_course_data_migration(env: &Env, _from_version: &String, _to_version: &String) -> bool {
-    // This is a placeholder for actual course data migration logic
-    // In a real implementation, this would:
-    // 1. Read existing course data structures
-    // 2. Transform them according to the new version schema
-    // 3. Write the transformed data back to storage
-    
-    // For now, we'll simulate a successful migration
-    migrate_courses_v1_0_0_to_v1_1_0(env)
-}
+/// Reset the stored data version to an earlier version so a corrected
+/// migration can run again
+///
+/// Only admins can roll back. `to_version` must be strictly older than the
+/// current data version and be either the initial 1.0.0 layout or a version
+/// from the history. Course data is not rewritten: migrations skip courses
+/// already in the current layout, so re-running one after a rollback is safe.
+///
+/// Returns `false`, recording the reason in the migration status, if the
+/// rollback is refused. Emits a `crsRollbk` event with the previous and the
+/// new data version on success.
+pub fn rollback_migration(env: &Env, admin: Address, to_version: String) -> bool {
+    admin.require_auth();
+
+    if !is_admin(env, &admin) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let current_version: String = get_data_version(env);
+    let steps_backward: bool = match (parse_version(&current_version), parse_version(&to_version)) {
+        (Some(current), Some(target)) => target < current,
+        _ => false,
+    };
+    if !steps_backward {
+        set_migration_status(env, String::from_str(env, "Rollback failed: Version is not older than the data version"));
+        return false;
+    }
+
+    let known_version: bool = to_version == String::from_str(env, INITIAL_DATA_VERSION)
+        || version_exists_in_history(env, &to_version);
+    if !known_version {
+        set_migration_status(env, String::from_str(env, "Rollback failed: Version not found"));
+        return false;
+    }
+
+    set_data_version(env, &to_version);
+    set_migration_status(env, String::from_str(env, "Rollback completed successfully"));
+
+    env.events().publish(
+        (ROLLBACK_EVENT, admin),
+        (current_version, to_version),
+    );
 
-/// Migrate courses from version 1.0.0 to 1.1.0
-fn migrate_courses_v1_0_0_to_v1_1_0(_env: &Env) -> bool {
-    // Placeholder for course migration logic
-    // This would typically involve:
-    // 1. Reading existing courses
-    // 2. Adding new fields with default values (e.g., tags, difficulty)
-    // 3. Saving updated courses
-    
-    // For now, return true to indicate successful migration
     true
 }
 
+/// Position of `version` in `COURSE_LAYOUTS`
+fn course_layout_index(env: &Env, version: &String) -> Option<usize> {
+    COURSE_LAYOUTS
+        .iter()
+        .position(|(layout, _)| String::from_str(env, layout) == *version)
+}
+
+/// Rewrite stored courses from layout `COURSE_LAYOUTS[from_layout]` up to
+/// `COURSE_LAYOUTS[to_layout]`, applying each layout step in turn
+///
+/// Only courses created by `caller` are migrated unless `migrate_all` is
+/// set. Courses already partway through, or at `to_layout` or later, only
+/// go through the remaining steps.
+///
+/// Returns the number of courses migrated, or `None` if a stored course is
+/// in a layout older than `from_layout` or in no known layout.
+fn migrate_courses(
+    env: &Env,
+    caller: &Address,
+    migrate_all: bool,
+    from_layout: usize,
+    to_layout: usize,
+) -> Option<u32> {
+    let max_course_id: u128 = env
+        .storage()
+        .persistent()
+        .get(&COURSE_KEY)
+        .unwrap_or(0u128);
+
+    let mut migrated: u32 = 0;
+    for id in 1..=max_course_id {
+        let course_id: String = u32_to_string(env, id as u32);
+        let storage_key: (Symbol, String) = (COURSE_KEY, course_id);
+
+        // Read the raw field map, as decoding into the wrong layout traps
+        let stored: Map<Symbol, Val> = match env.storage().persistent().get(&storage_key) {
+            Some(stored) => stored,
+            None => continue,
+        };
+
+        if !migrate_all {
+            let creator: Address = Address::try_from_val(env, &stored.get(Symbol::new(env, "creator"))?).ok()?;
+            if creator != *caller {
+                continue;
+            }
+        }
+
+        let layout: usize = COURSE_LAYOUTS
+            .iter()
+            .position(|(_, field_count)| *field_count == stored.len())?;
+        if layout < from_layout {
+            return None;
+        }
+        // Already in the target layout or a later one
+        if layout >= to_layout {
+            continue;
+        }
+
+        for step in layout..to_layout {
+            match step {
+                0 => migrate_course_v1_0_0_to_v1_1_0(env, &storage_key)?,
+                1 => migrate_course_v1_1_0_to_v1_2_0(env, &storage_key)?,
+                _ => migrate_course_v1_2_0_to_v1_3_0(env, &storage_key)?,
+            }
+        }
+        migrated += 1;
+    }
+
+    Some(migrated)
+}
+
+/// Migrate a course from version 1.0.0 to 1.1.0
+///
+/// Version 1.1.0 added `created_at`, `updated_at` and `tags`. Both
+/// timestamps are set to the time of the migration and the course gets no
+/// tags.
+fn migrate_course_v1_0_0_to_v1_1_0(env: &Env, storage_key: &(Symbol, String)) -> Option<()> {
+    let legacy: LegacyCourseV1 = env.storage().persistent().get(storage_key)?;
+    let now: u64 = env.ledger().timestamp();
+    let course: LegacyCourseV1_1 = LegacyCourseV1_1 {
+        id: legacy.id,
+        title: legacy.title,
+        description: legacy.description,
+        creator: legacy.creator,
+        price: legacy.price,
+        category: legacy.category,
+        language: legacy.language,
+        thumbnail_url: legacy.thumbnail_url,
+        published: legacy.published,
+        prerequisites: legacy.prerequisites,
+        is_archived: legacy.is_archived,
+        level: legacy.level,
+        duration_hours: legacy.duration_hours,
+        created_at: now,
+        updated_at: now,
+        tags: Vec::new(env),
+    };
+    env.storage().persistent().set(storage_key, &course);
+    Some(())
+}
+
+/// Migrate a course from version 1.1.0 to 1.2.0
+///
+/// Version 1.2.0 added `co_creators`. The course gets no co-creators.
+fn migrate_course_v1_1_0_to_v1_2_0(env: &Env, storage_key: &(Symbol, String)) -> Option<()> {
+    let legacy: LegacyCourseV1_1 = env.storage().persistent().get(storage_key)?;
+    let course: LegacyCourseV1_2 = LegacyCourseV1_2 {
+        id: legacy.id,
+        title: legacy.title,
+        description: legacy.description,
+        creator: legacy.creator,
+        price: legacy.price,
+        category: legacy.category,
+        language: legacy.language,
+        thumbnail_url: legacy.thumbnail_url,
+        published: legacy.published,
+        prerequisites: legacy.prerequisites,
+        is_archived: legacy.is_archived,
+        level: legacy.level,
+        duration_hours: legacy.duration_hours,
+        created_at: legacy.created_at,
+        updated_at: legacy.updated_at,
+        tags: legacy.tags,
+        co_creators: Vec::new(env),
+    };
+    env.storage().persistent().set(storage_key, &course);
+    Some(())
+}
+
+/// Migrate a course from version 1.2.0 to 1.3.0
+///
+/// Version 1.3.0 added `sale_price` and `sale_ends_at`. The course is not
+/// put on sale.
+fn migrate_course_v1_2_0_to_v1_3_0(env: &Env, storage_key: &(Symbol, String)) -> Option<()> {
+    let legacy: LegacyCourseV1_2 = env.storage().persistent().get(storage_key)?;
+    let course: Course = Course {
+        id: legacy.id,
+        title: legacy.title,
+        description: legacy.description,
+        creator: legacy.creator,
+        price: legacy.price,
+        category: legacy.category,
+        language: legacy.language,
+        thumbnail_url: legacy.thumbnail_url,
+        published: legacy.published,
+        prerequisites: legacy.prerequisites,
+        is_archived: legacy.is_archived,
+        level: legacy.level,
+        duration_hours: legacy.duration_hours,
+        created_at: legacy.created_at,
+        updated_at: legacy.updated_at,
+        tags: legacy.tags,
+        co_creators: legacy.co_creators,
+        sale_price: None,
+        sale_ends_at: None,
+    };
+    env.storage().persistent().set(storage_key, &course);
+    Some(())
+}
 
 /// Emit a migration event
-fn emit_migration_event(_env: &Env, _from_version: &String, _to_version: &String, _success: bool) {
-    // In a real implementation, you would emit events here
-    // For now, we'll just set a status message
-    
-    let _event_type: &'static str = if _success { "success" } else { "failure" };
-    // In a real implementation, you would emit actual events here
-    // For now, we'll just store a simple status message
-    
-    // You could emit actual events here using env.events()
-    // env.events().publish(("course_migration", event_type), (from_version, to_version, success));
+fn emit_migration_event(
+    env: &Env,
+    caller: &Address,
+    from_version: &String,
+    to_version: &String,
+    migrated: u32,
+    success: bool,
+) {
+    env.events().publish(
+        (MIGRATION_EVENT, caller.clone()),
+        (from_version.clone(), to_version.clone(), migrated, success),
+    );
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use soroban_sdk::testutils::{Address as _, Events, Ledger};
+    use soroban_sdk::TryFromVal;
 
     #[test]
     fn test_version_history() {
@@ -180,14 +519,407 @@ mod test {
     fn test_version_compatibility() {
         let env: Env = Env::default();
         
-        // All versions are compatible in our simplified implementation
+        // Same major version is compatible
         assert!(is_version_compatible(&env, 
             String::from_str(&env, "1.0.0"), 
             String::from_str(&env, "1.1.0")));
         
-        // All versions are compatible in our simplified implementation
-        assert!(is_version_compatible(&env, 
+        // A major version bump is not compatible
+        assert!(!is_version_compatible(&env, 
             String::from_str(&env, "1.0.0"), 
             String::from_str(&env, "2.0.0")));
+
+        // Malformed versions are never compatible
+        assert!(!is_version_compatible(&env, 
+            String::from_str(&env, "1.0"), 
+            String::from_str(&env, "1.0.0")));
+        assert!(!is_version_compatible(&env, 
+            String::from_str(&env, "v1.0.0"), 
+            String::from_str(&env, "1.0.0")));
+    }
+
+    #[test]
+    fn test_check_compatibility_batch() {
+        let env: Env = Env::default();
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+        let pair = |from: &str, to: &str| (String::from_str(&env, from), String::from_str(&env, to));
+
+        let pairs: Vec<(String, String)> = soroban_sdk::vec![
+            &env,
+            pair("1.0.0", "1.4.2"),
+            pair("1.0.0", "2.0.0"),
+            pair("1.0", "1.0.0"),
+            pair("3.2.1", "3.0.0"),
+            pair("1.0.0", "one.0.0"),
+        ];
+
+        assert_eq!(
+            client.check_compatibility_batch(&pairs),
+            soroban_sdk::vec![&env, true, false, false, true, false]
+        );
+        assert_eq!(client.check_compatibility_batch(&Vec::new(&env)).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #46)")]
+    fn test_check_compatibility_batch_too_large() {
+        let env: Env = Env::default();
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+
+        let mut pairs: Vec<(String, String)> = Vec::new(&env);
+        for _ in 0..=MAX_COMPATIBILITY_BATCH_SIZE {
+            pairs.push_back((String::from_str(&env, "1.0.0"), String::from_str(&env, "1.0.0")));
+        }
+        client.check_compatibility_batch(&pairs);
+    }
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    /// Store a course in the version 1.0.0 layout and bump the ID counter
+    fn store_legacy_course(env: &Env, contract_id: &Address, id: u128, creator: &Address) {
+        env.as_contract(contract_id, || {
+            let course_id: String = u32_to_string(env, id as u32);
+            let legacy = LegacyCourseV1 {
+                id: course_id.clone(),
+                title: String::from_str(env, "Legacy course"),
+                description: String::from_str(env, "Stored before 1.1.0"),
+                creator: creator.clone(),
+                price: 1000,
+                category: Some(String::from_str(env, "Programming")),
+                language: None,
+                thumbnail_url: None,
+                published: true,
+                prerequisites: Vec::new(env),
+                is_archived: false,
+                level: None,
+                duration_hours: Some(10),
+            };
+            env.storage().persistent().set(&(COURSE_KEY, course_id), &legacy);
+            env.storage().persistent().set(&COURSE_KEY, &id);
+        });
+    }
+
+    #[test]
+    fn test_migrate_course_data_rewrites_legacy_courses() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 5_000);
+
+        let user_mgmt_id: Address = env.register(mock_user_management::UserManagement, ());
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+
+        let admin: Address = Address::generate(&env);
+        let creator: Address = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+
+        store_legacy_course(&env, &contract_id, 1, &creator);
+        store_legacy_course(&env, &contract_id, 2, &creator);
+
+        assert!(client.migrate_course_data(
+            &admin,
+            &String::from_str(&env, "1.0.0"),
+            &String::from_str(&env, "1.3.0")
+        ));
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(
+            Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(),
+            MIGRATION_EVENT
+        );
+        let (_, _, migrated, success): (String, String, u32, bool) =
+            <(String, String, u32, bool)>::try_from_val(&env, &data).unwrap();
+        assert_eq!(migrated, 2);
+        assert!(success);
+
+        let course: Course = client.get_course(&String::from_str(&env, "1"));
+        assert_eq!(course.title, String::from_str(&env, "Legacy course"));
+        assert_eq!(course.creator, creator);
+        assert_eq!(course.price, 1000);
+        assert_eq!(course.duration_hours, Some(10));
+        assert!(course.published);
+        assert_eq!(course.created_at, 5_000);
+        assert_eq!(course.updated_at, 5_000);
+        assert_eq!(course.tags.len(), 0);
+        assert_eq!(course.co_creators.len(), 0);
+        assert_eq!(course.sale_price, None);
+        assert_eq!(course.sale_ends_at, None);
+
+        assert_eq!(
+            client.get_version_history(),
+            vec![&env, String::from_str(&env, "1.0.0"), String::from_str(&env, "1.3.0")]
+        );
+
+        assert_eq!(client.get_data_version(), String::from_str(&env, "1.3.0"));
+    }
+
+    #[test]
+    fn test_migrate_course_data_refuses_to_run_twice() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id: Address = env.register(mock_user_management::UserManagement, ());
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+
+        let admin: Address = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+        store_legacy_course(&env, &contract_id, 1, &Address::generate(&env));
+
+        let from_version = String::from_str(&env, "1.0.0");
+        let to_version = String::from_str(&env, "1.1.0");
+        assert!(client.migrate_course_data(&admin, &from_version, &to_version));
+        let status = client.get_migration_status();
+        let history = client.get_version_history();
+
+        // A course written after the migration must not be touched again
+        store_legacy_course(&env, &contract_id, 2, &Address::generate(&env));
+
+        assert!(!client.migrate_course_data(&admin, &from_version, &to_version));
+        assert!(env.events().all().is_empty());
+        assert_eq!(client.get_migration_status(), status);
+        assert_eq!(client.get_version_history(), history);
+        env.as_contract(&contract_id, || {
+            let stored: Option<LegacyCourseV1> = env
+                .storage()
+                .persistent()
+                .get(&(COURSE_KEY, String::from_str(&env, "2")));
+            assert!(stored.is_some());
+        });
+
+        // Migrating to an older version is refused as well
+        assert!(!client.migrate_course_data(&admin, &from_version, &from_version));
+    }
+
+    #[test]
+    fn test_rollback_migration_restores_data_version() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id: Address = env.register(mock_user_management::UserManagement, ());
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+
+        let admin: Address = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+        store_legacy_course(&env, &contract_id, 1, &Address::generate(&env));
+
+        let from_version = String::from_str(&env, "1.0.0");
+        let to_version = String::from_str(&env, "1.1.0");
+        assert!(client.migrate_course_data(&admin, &from_version, &to_version));
+        assert_eq!(client.get_data_version(), to_version);
+
+        assert!(client.rollback_migration(&admin, &from_version));
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(
+            Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(),
+            ROLLBACK_EVENT
+        );
+        assert_eq!(
+            <(String, String)>::try_from_val(&env, &data).unwrap(),
+            (to_version.clone(), from_version.clone())
+        );
+        assert_eq!(client.get_data_version(), from_version);
+        assert_eq!(
+            client.get_migration_status(),
+            String::from_str(&env, "Rollback completed successfully")
+        );
+
+        // The corrected migration can run again
+        assert!(client.migrate_course_data(&admin, &from_version, &to_version));
+        assert_eq!(client.get_data_version(), to_version);
+    }
+
+    #[test]
+    fn test_rollback_migration_only_steps_backward() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id: Address = env.register(mock_user_management::UserManagement, ());
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+
+        let admin: Address = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+
+        // Same version, a newer version and a malformed version are refused
+        assert!(!client.rollback_migration(&admin, &String::from_str(&env, "1.0.0")));
+        assert!(!client.rollback_migration(&admin, &String::from_str(&env, "1.1.0")));
+        assert!(!client.rollback_migration(&admin, &String::from_str(&env, "v0.9")));
+        assert_eq!(client.get_data_version(), String::from_str(&env, "1.0.0"));
+
+        assert!(client.migrate_course_data(
+            &admin,
+            &String::from_str(&env, "1.0.0"),
+            &String::from_str(&env, "1.1.0")
+        ));
+
+        // Older versions that were never deployed are refused
+        assert!(!client.rollback_migration(&admin, &String::from_str(&env, "0.9.0")));
+        assert_eq!(
+            client.get_migration_status(),
+            String::from_str(&env, "Rollback failed: Version not found")
+        );
+        assert_eq!(client.get_data_version(), String::from_str(&env, "1.1.0"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_rollback_migration_requires_admin() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+
+        client.rollback_migration(&Address::generate(&env), &String::from_str(&env, "1.0.0"));
+    }
+
+    #[test]
+    fn test_migrate_course_data_creator_migrates_own_courses() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+
+        let creator: Address = Address::generate(&env);
+        let other: Address = Address::generate(&env);
+        store_legacy_course(&env, &contract_id, 1, &creator);
+        store_legacy_course(&env, &contract_id, 2, &other);
+
+        assert!(client.migrate_course_data(
+            &creator,
+            &String::from_str(&env, "1.0.0"),
+            &String::from_str(&env, "1.3.0")
+        ));
+
+        assert_eq!(client.get_course(&String::from_str(&env, "1")).creator, creator);
+        // A partial migration leaves the data version unchanged
+        assert_eq!(client.get_data_version(), String::from_str(&env, "1.0.0"));
+        // The other creator's course is still in the old layout
+        env.as_contract(&contract_id, || {
+            let stored: LegacyCourseV1 = env
+                .storage()
+                .persistent()
+                .get(&(COURSE_KEY, String::from_str(&env, "2")))
+                .unwrap();
+            assert_eq!(stored.creator, other);
+        });
+    }
+
+    #[test]
+    fn test_migrate_course_data_rejects_incompatible_versions() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+
+        assert!(!client.migrate_course_data(
+            &Address::generate(&env),
+            &String::from_str(&env, "1.0.0"),
+            &String::from_str(&env, "2.0.0")
+        ));
+    }
+
+    #[test]
+    fn test_migrate_course_data_runs_one_layout_step() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 7_000);
+
+        let user_mgmt_id: Address = env.register(mock_user_management::UserManagement, ());
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+
+        let admin: Address = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+        store_legacy_course(&env, &contract_id, 1, &Address::generate(&env));
+
+        assert!(client.migrate_course_data(
+            &admin,
+            &String::from_str(&env, "1.0.0"),
+            &String::from_str(&env, "1.1.0")
+        ));
+        assert_eq!(client.get_data_version(), String::from_str(&env, "1.1.0"));
+        env.as_contract(&contract_id, || {
+            let stored: LegacyCourseV1_1 = env
+                .storage()
+                .persistent()
+                .get(&(COURSE_KEY, String::from_str(&env, "1")))
+                .unwrap();
+            assert_eq!(stored.created_at, 7_000);
+        });
+
+        // The remaining steps bring the course to the current layout
+        assert!(client.migrate_course_data(
+            &admin,
+            &String::from_str(&env, "1.1.0"),
+            &String::from_str(&env, crate::VERSION)
+        ));
+        let course: Course = client.get_course(&String::from_str(&env, "1"));
+        assert_eq!(course.created_at, 7_000);
+        assert_eq!(course.co_creators.len(), 0);
+        assert_eq!(course.sale_price, None);
+        assert_eq!(client.get_data_version(), String::from_str(&env, crate::VERSION));
+    }
+
+    #[test]
+    fn test_migrate_course_data_rejects_unknown_version_pairs() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id: Address = env.register(mock_user_management::UserManagement, ());
+        let contract_id: Address = env.register(crate::CourseRegistry, ());
+        let client = crate::CourseRegistryClient::new(&env, &contract_id);
+
+        let admin: Address = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+        store_legacy_course(&env, &contract_id, 1, &Address::generate(&env));
+
+        assert!(!client.migrate_course_data(
+            &admin,
+            &String::from_str(&env, "1.0.0"),
+            &String::from_str(&env, "1.4.0")
+        ));
+        assert_eq!(
+            client.get_migration_status(),
+            String::from_str(&env, "Migration failed: No migration between these versions")
+        );
+        assert_eq!(client.get_data_version(), String::from_str(&env, "1.0.0"));
+        env.as_contract(&contract_id, || {
+            let stored: Option<LegacyCourseV1> = env
+                .storage()
+                .persistent()
+                .get(&(COURSE_KEY, String::from_str(&env, "1")));
+            assert!(stored.is_some());
+        });
     }
 }
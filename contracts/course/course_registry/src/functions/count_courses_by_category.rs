@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+use crate::schema::{Course, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Counts the published, non-archived courses in the given category.
+///
+/// Reads only the course IDs stored in the category index instead of
+/// scanning every course.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - category: the category name, matched exactly.
+///
+/// Returns:
+/// - u32: the number of visible courses in the category (0 if none).
+pub fn count_courses_by_category(env: &Env, category: String) -> u32 {
+    let course_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CategoryCourses(category))
+        .unwrap_or(Vec::new(env));
+
+    let mut count: u32 = 0;
+    for course_id in course_ids.iter() {
+        let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+            Some(course) => course,
+            None => continue,
+        };
+
+        // Same visibility rules as the public listing
+        if !course.is_archived && course.published {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// Adds a course to the index bucket of its category
+pub fn add_to_category_index(env: &Env, category: &String, course_id: &String) {
+    let key: DataKey = DataKey::CategoryCourses(category.clone());
+    let mut course_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+
+    if !course_ids.contains(course_id) {
+        course_ids.push_back(course_id.clone());
+        env.storage().persistent().set(&key, &course_ids);
+    }
+}
+
+/// Removes a course from the index bucket of its category
+pub fn remove_from_category_index(env: &Env, category: &String, course_id: &String) {
+    let key: DataKey = DataKey::CategoryCourses(category.clone());
+    let mut course_ids: Vec<String> = match env.storage().persistent().get(&key) {
+        Some(course_ids) => course_ids,
+        None => return,
+    };
+
+    if let Some(index) = course_ids.first_index_of(course_id) {
+        course_ids.remove(index);
+        if course_ids.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &course_ids);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::EditCourseParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course(
+        client: &CourseRegistryClient<'_>,
+        creator: &Address,
+        title: &str,
+        category: &str,
+    ) -> String {
+        let env = &client.env;
+        let course_id = client
+            .create_course(
+                creator,
+                &String::from_str(env, title),
+                &String::from_str(env, "description"),
+                &1000_u128,
+                &Some(String::from_str(env, category)),
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .id;
+        let mut params = edit_params();
+        params.new_published = Some(true);
+        client.edit_course(creator, &course_id, &params);
+        course_id
+    }
+
+    fn edit_params() -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_count_courses_by_category() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let other_creator = Address::generate(&env);
+
+        let programming = String::from_str(&env, "Programming");
+        let design = String::from_str(&env, "Design");
+
+        create_course(&client, &creator, "Rust", "Programming");
+        create_course(&client, &creator, "Go", "Programming");
+        let figma_id = create_course(&client, &other_creator, "Figma", "Design");
+
+        assert_eq!(client.count_courses_by_category(&programming), 2);
+        assert_eq!(client.count_courses_by_category(&design), 1);
+        assert_eq!(client.count_courses_by_category(&String::from_str(&env, "Music")), 0);
+
+        // Moving a course updates both buckets
+        let mut params = edit_params();
+        params.new_category = Some(Some(programming.clone()));
+        client.edit_course(&other_creator, &figma_id, &params);
+
+        assert_eq!(client.count_courses_by_category(&programming), 3);
+        assert_eq!(client.count_courses_by_category(&design), 0);
+    }
+
+    #[test]
+    fn test_count_courses_by_category_skips_hidden_and_deleted() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let programming = String::from_str(&env, "Programming");
+
+        let hidden_id = create_course(&client, &creator, "Hidden", "Programming");
+        let deleted_id = create_course(&client, &creator, "Deleted", "Programming");
+        create_course(&client, &creator, "Visible", "Programming");
+        assert_eq!(client.count_courses_by_category(&programming), 3);
+
+        let mut params = edit_params();
+        params.new_published = Some(false);
+        client.edit_course(&creator, &hidden_id, &params);
+        client.delete_course(&creator, &deleted_id);
+
+        assert_eq!(client.count_courses_by_category(&programming), 1);
+    }
+}
@@ -0,0 +1,265 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error, ValidationError};
+use crate::functions::access_control::is_admin;
+use crate::functions::get_courses_by_level::{add_to_level_index, remove_from_level_index};
+use crate::functions::utils::{to_lowercase, trim};
+use crate::schema::{Course, CourseLevel};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const MIGRATE_LEVELS_EVENT: Symbol = symbol_short!("lvlMigr");
+
+pub const LEVEL_BEGINNER: &str = "Beginner";
+pub const LEVEL_INTERMEDIATE: &str = "Intermediate";
+pub const LEVEL_ADVANCED: &str = "Advanced";
+
+/// Returns the canonical spelling of a course level.
+///
+/// Matching ignores case and surrounding whitespace, so "beginner" and
+/// " BEGINNER " both become "Beginner". Unknown levels yield `None`.
+pub fn normalize_course_level(env: &Env, level: &CourseLevel) -> Option<CourseLevel> {
+    let lowercase: String = to_lowercase(env, &trim(env, level));
+
+    [LEVEL_BEGINNER, LEVEL_INTERMEDIATE, LEVEL_ADVANCED]
+        .into_iter()
+        .map(|canonical| String::from_str(env, canonical))
+        .find(|canonical| to_lowercase(env, canonical) == lowercase)
+}
+
+/// Returns the canonical spelling of a course level, rejecting unknown levels
+/// with `ValidationError::InvalidCourseLevel`.
+pub fn validate_course_level(env: &Env, level: &CourseLevel) -> CourseLevel {
+    match normalize_course_level(env, level) {
+        Some(canonical) => canonical,
+        None => handle_error(env, ValidationError::InvalidCourseLevel),
+    }
+}
+
+/// Maps a free-form level stored before validation existed to a canonical one.
+///
+/// Besides the canonical levels (in any case) a few common synonyms are
+/// recognised. Anything else yields `None`.
+fn map_legacy_level(env: &Env, level: &CourseLevel) -> Option<CourseLevel> {
+    if let Some(canonical) = normalize_course_level(env, level) {
+        return Some(canonical);
+    }
+
+    let lowercase: String = to_lowercase(env, &trim(env, level));
+    let aliases: [(&str, &str); 8] = [
+        ("entry", LEVEL_BEGINNER),
+        ("basic", LEVEL_BEGINNER),
+        ("novice", LEVEL_BEGINNER),
+        ("introductory", LEVEL_BEGINNER),
+        ("medium", LEVEL_INTERMEDIATE),
+        ("middle", LEVEL_INTERMEDIATE),
+        ("expert", LEVEL_ADVANCED),
+        ("professional", LEVEL_ADVANCED),
+    ];
+
+    aliases
+        .into_iter()
+        .find(|(alias, _)| String::from_str(env, alias) == lowercase)
+        .map(|(_, canonical)| String::from_str(env, canonical))
+}
+
+/// Rewrites the level of every stored course to its canonical spelling.
+///
+/// Legacy free-form levels are mapped with `map_legacy_level`; levels that
+/// cannot be mapped are cleared. The level index is updated accordingly.
+/// Only admins may run the migration.
+///
+/// Returns the number of courses whose level changed.
+pub fn migrate_course_levels(env: &Env, caller: &Address) -> u32 {
+    caller.require_auth();
+
+    if !is_admin(env, caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let max_course_id: u128 = env
+        .storage()
+        .persistent()
+        .get(&COURSE_KEY)
+        .unwrap_or(0u128);
+
+    let mut migrated: u32 = 0;
+    for id in 1..=max_course_id {
+        let course_id: String = super::utils::u32_to_string(env, id as u32);
+        let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+
+        let mut course: Course = match env.storage().persistent().get(&storage_key) {
+            Some(course) => course,
+            None => continue,
+        };
+
+        let old_level: CourseLevel = match course.level.clone() {
+            Some(level) => level,
+            None => continue,
+        };
+
+        let new_level: Option<CourseLevel> = map_legacy_level(env, &old_level);
+        if new_level.as_ref() == Some(&old_level) {
+            continue;
+        }
+
+        remove_from_level_index(env, &old_level, &course_id);
+        if let Some(ref level) = new_level {
+            add_to_level_index(env, level, &course_id);
+        }
+
+        course.level = new_level;
+        env.storage().persistent().set(&storage_key, &course);
+        migrated += 1;
+    }
+
+    env.events()
+        .publish((MIGRATE_LEVELS_EVENT, caller.clone()), migrated);
+
+    migrated
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schema::EditCourseParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+
+    mod mock_user_management {
+        use super::*;
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup_test_env() -> (Env, Address, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+
+        (env, contract_id, client)
+    }
+
+    fn create_course_with_level(
+        env: &Env,
+        client: &CourseRegistryClient,
+        creator: &Address,
+        title: &str,
+        level: &str,
+    ) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(env, title),
+            &String::from_str(env, "Description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &Some(String::from_str(env, level)),
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_create_course_normalizes_level() {
+        let (env, _contract_id, client) = setup_test_env();
+        let creator = Address::generate(&env);
+
+        let beginner = create_course_with_level(&env, &client, &creator, "One", "beginner");
+        let intermediate = create_course_with_level(&env, &client, &creator, "Two", "Intermediate");
+        let advanced = create_course_with_level(&env, &client, &creator, "Three", " ADVANCED ");
+
+        assert_eq!(beginner.level, Some(String::from_str(&env, LEVEL_BEGINNER)));
+        assert_eq!(intermediate.level, Some(String::from_str(&env, LEVEL_INTERMEDIATE)));
+        assert_eq!(advanced.level, Some(String::from_str(&env, LEVEL_ADVANCED)));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #60)")]
+    fn test_create_course_unknown_level_rejected() {
+        let (env, _contract_id, client) = setup_test_env();
+        let creator = Address::generate(&env);
+
+        create_course_with_level(&env, &client, &creator, "One", "Noob");
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #60)")]
+    fn test_edit_course_unknown_level_rejected() {
+        let (env, _contract_id, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let course = create_course_with_level(&env, &client, &creator, "One", "Beginner");
+
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: Some(Some(String::from_str(&env, "Noob"))),
+            new_duration_hours: None,
+        };
+        client.edit_course(&creator, &course.id, &params);
+    }
+
+    #[test]
+    fn test_migrate_course_levels_maps_legacy_values() {
+        let (env, contract_id, client) = setup_test_env();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+
+        let first = create_course_with_level(&env, &client, &creator, "One", "Beginner");
+        let second = create_course_with_level(&env, &client, &creator, "Two", "Beginner");
+        let third = create_course_with_level(&env, &client, &creator, "Three", "Advanced");
+
+        // Simulate free-form levels stored before validation existed
+        env.as_contract(&contract_id, || {
+            for (course, legacy) in [(&first, "entry"), (&second, "Noob"), (&third, "advanced")] {
+                let key = (COURSE_KEY, course.id.clone());
+                let mut stored: Course = env.storage().persistent().get(&key).unwrap();
+                let old_level = stored.level.clone().unwrap();
+                let legacy_level = String::from_str(&env, legacy);
+                remove_from_level_index(&env, &old_level, &course.id);
+                add_to_level_index(&env, &legacy_level, &course.id);
+                stored.level = Some(legacy_level);
+                env.storage().persistent().set(&key, &stored);
+            }
+        });
+
+        assert_eq!(client.migrate_course_levels(&admin), 3);
+
+        assert_eq!(
+            client.get_course(&first.id).level,
+            Some(String::from_str(&env, LEVEL_BEGINNER))
+        );
+        assert_eq!(client.get_course(&second.id).level, None);
+        assert_eq!(
+            client.get_course(&third.id).level,
+            Some(String::from_str(&env, LEVEL_ADVANCED))
+        );
+
+        // Running it again finds nothing left to migrate
+        assert_eq!(client.migrate_course_levels(&admin), 0);
+    }
+}
@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_creator_or_co_creator;
+use crate::schema::Course;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const COURSE_SALE_EVENT: Symbol = symbol_short!("crsSale");
+
+/// Put a course on sale, or end its sale when `sale_price` is `None`.
+///
+/// The sale price must be above zero and below the regular price. `ends_at`,
+/// if given, must be in the future; without it the sale runs until ended.
+/// Either violation fails with `Error::InvalidPrice`.
+/// Only the course creator and co-creators can set a sale.
+pub fn set_course_sale(
+    env: &Env,
+    creator: Address,
+    course_id: String,
+    sale_price: Option<u128>,
+    ends_at: Option<u64>,
+) -> Course {
+    creator.require_auth();
+
+    let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = match env.storage().persistent().get(&key) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+
+    if !is_creator_or_co_creator(&course, &creator) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    match sale_price {
+        Some(price) => {
+            if price == 0 || price >= course.price {
+                handle_error(env, Error::InvalidPrice)
+            }
+            if ends_at.is_some_and(|ends_at| ends_at <= env.ledger().timestamp()) {
+                handle_error(env, Error::InvalidPrice)
+            }
+            course.sale_price = Some(price);
+            course.sale_ends_at = ends_at;
+        }
+        None => {
+            course.sale_price = None;
+            course.sale_ends_at = None;
+        }
+    }
+    course.updated_at = env.ledger().timestamp();
+    env.storage().persistent().set(&key, &course);
+
+    env.events()
+        .publish((COURSE_SALE_EVENT, course_id), (course.sale_price, course.sale_ends_at));
+
+    course
+}
+
+/// The price a course is currently sold at.
+///
+/// This is the sale price while a sale is running and the regular price
+/// otherwise, never more than the regular price.
+pub fn effective_price(env: &Env, course: &Course) -> u128 {
+    match course.sale_price {
+        Some(sale_price) if course.sale_ends_at.is_none_or(|ends_at| env.ledger().timestamp() < ends_at) => {
+            sale_price.min(course.price)
+        }
+        _ => course.price,
+    }
+}
+
+/// The price a course is currently sold at, see `effective_price`.
+pub fn get_effective_price(env: &Env, course_id: String) -> u128 {
+    match env.storage().persistent().get::<_, Course>(&(COURSE_KEY, course_id)) {
+        Some(course) => effective_price(env, &course),
+        None => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::EditCourseParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env, String};
+
+    fn setup(env: &Env) -> (CourseRegistryClient<'_>, Address, String) {
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator: Address = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "title"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_effective_price_without_sale() {
+        let env = Env::default();
+        let (client, _creator, course_id) = setup(&env);
+
+        let course = client.get_course(&course_id);
+        assert_eq!(course.sale_price, None);
+        assert_eq!(client.get_effective_price(&course_id), course.price);
+    }
+
+    #[test]
+    fn test_effective_price_never_above_regular_price() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        client.set_course_sale(&creator, &course_id, &Some(600), &None);
+
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: Some(500),
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(&creator, &course_id, &params);
+
+        assert_eq!(client.get_effective_price(&course_id), 500);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #17)")]
+    fn test_effective_price_unknown_course() {
+        let env = Env::default();
+        let (client, _creator, _course_id) = setup(&env);
+
+        client.get_effective_price(&String::from_str(&env, "missing"));
+    }
+
+    #[test]
+    fn test_sale_price_until_sale_ends() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        assert_eq!(client.get_effective_price(&course_id), 1000);
+
+        let course = client.set_course_sale(&creator, &course_id, &Some(600), &Some(2_000));
+        assert_eq!(course.sale_price, Some(600));
+        assert_eq!(course.sale_ends_at, Some(2_000));
+        assert_eq!(client.get_effective_price(&course_id), 600);
+
+        env.ledger().with_mut(|li| li.timestamp = 2_000);
+        assert_eq!(client.get_effective_price(&course_id), 1000);
+        assert_eq!(client.get_course(&course_id).price, 1000);
+    }
+
+    #[test]
+    fn test_end_sale() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+
+        client.set_course_sale(&creator, &course_id, &Some(600), &None);
+        assert_eq!(client.get_effective_price(&course_id), 600);
+
+        let course = client.set_course_sale(&creator, &course_id, &None, &None);
+        assert_eq!(course.sale_price, None);
+        assert_eq!(client.get_effective_price(&course_id), 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #9)")]
+    fn test_sale_price_not_below_price() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+
+        client.set_course_sale(&creator, &course_id, &Some(1000), &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #9)")]
+    fn test_sale_ending_in_the_past() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+        client.set_course_sale(&creator, &course_id, &Some(600), &Some(1_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #6)")]
+    fn test_sale_requires_creator() {
+        let env = Env::default();
+        let (client, _creator, course_id) = setup(&env);
+
+        client.set_course_sale(&Address::generate(&env), &course_id, &Some(600), &None);
+    }
+}
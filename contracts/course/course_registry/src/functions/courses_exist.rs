@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Maximum number of course IDs that can be checked in one call
+pub const MAX_COURSES_EXIST_BATCH_SIZE: u32 = 100;
+
+/// Check which of the given courses exist.
+///
+/// The result is aligned with `course_ids`: entry `i` tells whether
+/// `course_ids[i]` exists. Panics with `InvalidLimitValue` if more than
+/// `MAX_COURSES_EXIST_BATCH_SIZE` IDs are passed.
+pub fn courses_exist(env: &Env, course_ids: Vec<String>) -> Vec<bool> {
+    if course_ids.len() > MAX_COURSES_EXIST_BATCH_SIZE {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+
+    let mut results: Vec<bool> = Vec::new(env);
+    for course_id in course_ids.iter() {
+        results.push_back(env.storage().persistent().has(&(COURSE_KEY, course_id)));
+    }
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address};
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, creator: &Address, title: &str) -> String {
+        client
+            .create_course(
+                creator,
+                &String::from_str(env, title),
+                &String::from_str(env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .id
+    }
+
+    #[test]
+    fn test_courses_exist_aligned_with_input() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let first = create_course(&env, &client, &creator, "First");
+        let second = create_course(&env, &client, &creator, "Second");
+
+        let result = client.courses_exist(&vec![
+            &env,
+            String::from_str(&env, "missing"),
+            first.clone(),
+            String::from_str(&env, "999"),
+            second,
+            first,
+        ]);
+
+        assert_eq!(result, vec![&env, false, true, false, true, true]);
+        assert_eq!(client.courses_exist(&Vec::new(&env)).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #46)")]
+    fn test_courses_exist_rejects_oversized_batch() {
+        let env = Env::default();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let mut course_ids: Vec<String> = Vec::new(&env);
+        for _ in 0..=MAX_COURSES_EXIST_BATCH_SIZE {
+            course_ids.push_back(String::from_str(&env, "1"));
+        }
+        client.courses_exist(&course_ids);
+    }
+}
@@ -3,6 +3,12 @@
 
 use super::utils::{to_lowercase, trim, u32_to_string};
 use super::course_rate_limit_utils::check_course_creation_rate_limit;
+use super::course_level::validate_course_level;
+use super::count_courses_by_category::add_to_category_index;
+use super::get_courses_by_level::add_to_level_index;
+use super::language_allowlist::validate_course_language;
+use super::list_courses_by_language::add_to_language_index;
+use super::strict_categories::require_known_category;
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 use crate::error::{handle_error, Error};
 use crate::schema::{Course, CourseLevel};
@@ -57,6 +63,7 @@ pub fn create_course(
         if cat.is_empty() || cat.len() > 100 {
             handle_error(&env, Error::EmptyCategory);
         }
+        require_known_category(&env, cat);
     }
 
     if let Some(ref lang) = language {
@@ -64,6 +71,7 @@ pub fn create_course(
             handle_error(&env, Error::InvalidLanguageLength);
         }
     }
+    let language: Option<String> = language.map(|lang| validate_course_language(&env, &lang));
 
     if let Some(ref url) = thumbnail_url {
         if url.is_empty() || url.len() > 500 {
@@ -78,6 +86,9 @@ pub fn create_course(
         }
     }
 
+    // Store levels in their canonical spelling so level filters are not fragmented
+    let level: Option<CourseLevel> = level.map(|lvl| validate_course_level(&env, &lvl));
+
     let lowercase_title: String = to_lowercase(&env, &title);
 
     // to avoid duplicate title,
@@ -112,11 +123,26 @@ pub fn create_course(
         is_archived: false,
         level: level.clone(),
         duration_hours,
+        created_at: env.ledger().timestamp(),
+        updated_at: env.ledger().timestamp(),
+        tags: Vec::new(&env),
+        co_creators: Vec::new(&env),
+        sale_price: None,
+        sale_ends_at: None,
     };
 
     // save to the storage
     env.storage().persistent().set(&storage_key, &new_course);
     env.storage().persistent().set(&title_key, &true);
+    if let Some(ref lvl) = level {
+        add_to_level_index(&env, lvl, &converted_id);
+    }
+    if let Some(ref lang) = language {
+        add_to_language_index(&env, lang, &converted_id);
+    }
+    if let Some(ref cat) = category {
+        add_to_category_index(&env, cat, &converted_id);
+    }
 
     // emit an event
     env.events()
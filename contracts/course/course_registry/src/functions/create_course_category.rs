@@ -4,9 +4,10 @@
 use soroban_sdk::{Address, Env, String, Vec, Symbol, symbol_short};
 
 use crate::error::{handle_error, Error};
+use crate::functions::utils::{to_lowercase, trim};
 use crate::schema::{CourseCategory, DataKey};
 
-const CREATE_COURSE_CATEGORY_EVENT: Symbol = symbol_short!("crtCrsCat");
+const CREATE_COURSE_CATEGORY_EVENT: Symbol = symbol_short!("catCreate");
 const NEXT_CATEGORY_ID_EVENT: Symbol = symbol_short!("nxtCatId");
 
 /// Creates a new course category (admin-only).
@@ -23,6 +24,9 @@ const NEXT_CATEGORY_ID_EVENT: Symbol = symbol_short!("nxtCatId");
 /// Storage used (replace keys if your schema differs):
 /// - ("category_seq",) -> u128                // sequence counter
 /// - (("category", id),) -> CourseCategory    // category record by id
+///
+/// Panics with `InvalidCategoryName` if a category with the same
+/// normalized name already exists.
 pub fn create_course_category(
     env: Env,
     caller: Address,
@@ -52,6 +56,11 @@ pub fn create_course_category(
         }
     }
 
+    // Reject duplicates, comparing trimmed, lowercased names
+    if find_category_by_name(&env, &name).is_some() {
+        handle_error(&env, Error::InvalidCategoryName);
+    }
+
     // Generate a new category ID
     let id: u128 = next_category_id(&env);
 
@@ -65,14 +74,60 @@ pub fn create_course_category(
         .persistent()
         .set(&DataKey::CourseCategory(id), &category);
 
-    // emit an event
+    // emit an event indexers can use to track new categories
     env.events()
-        .publish((CREATE_COURSE_CATEGORY_EVENT,), (caller, name, description, id));
+        .publish((CREATE_COURSE_CATEGORY_EVENT, id), (name, description, caller));
 
     // Return the new ID
     id
 }
 
+/// Returns the ID of the category named `name`, creating it if needed (admin-only).
+///
+/// Names are compared trimmed and lowercased, like in `create_course_category`.
+/// An existing category is returned unchanged; `description` is only used
+/// when a new category is created.
+pub fn ensure_course_category(
+    env: Env,
+    caller: Address,
+    name: String,
+    description: Option<String>,
+) -> u128 {
+    match find_category_by_name(&env, &name) {
+        Some(id) => {
+            caller.require_auth();
+            if !is_admin(&env, caller) {
+                handle_error(&env, Error::Unauthorized)
+            }
+            id
+        }
+        None => create_course_category(env, caller, name, description),
+    }
+}
+
+/// Finds the ID of the category whose normalized name matches `name`.
+pub fn find_category_by_name(env: &Env, name: &String) -> Option<u128> {
+    let wanted: String = to_lowercase(env, &trim(env, name));
+    let last_id: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CategorySeq)
+        .unwrap_or(0u128);
+
+    for id in 1..=last_id {
+        if let Some(category) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CourseCategory>(&DataKey::CourseCategory(id))
+        {
+            if to_lowercase(env, &trim(env, &category.name)) == wanted {
+                return Some(id);
+            }
+        }
+    }
+    None
+}
+
 /// Checks whether who is an admin using the same pattern as user_management contract.
 /// This assumes the course_registry contract has its own admin system or uses a similar pattern.
 fn is_admin(env: &Env, who: Address) -> bool {
@@ -102,3 +157,89 @@ fn next_category_id(env: &Env) -> u128 {
 
     seq
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, testutils::Events, vec, TryFromVal};
+
+    #[test]
+    fn test_create_course_category_emits_event() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let admin: Address = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Admins, &vec![&env, admin.clone()]);
+        });
+
+        let name: String = String::from_str(&env, "Programming");
+        let id: u128 = client.create_course_category(&admin, &name, &None);
+
+        let (emitter, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(emitter, contract_id);
+        assert_eq!(
+            Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(),
+            CREATE_COURSE_CATEGORY_EVENT
+        );
+        assert_eq!(u128::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), id);
+
+        let (event_name, event_description, event_caller): (String, Option<String>, Address) =
+            <(String, Option<String>, Address)>::try_from_val(&env, &data).unwrap();
+        assert_eq!(event_name, name);
+        assert_eq!(event_description, None);
+        assert_eq!(event_caller, admin);
+    }
+
+    fn setup_admin(env: &Env, contract_id: &Address) -> Address {
+        let admin: Address = Address::generate(env);
+        env.as_contract(contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Admins, &vec![env, admin.clone()]);
+        });
+        admin
+    }
+
+    #[test]
+    fn test_ensure_course_category_returns_existing_id() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let admin: Address = setup_admin(&env, &contract_id);
+
+        let first: u128 = client.ensure_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+        let second: u128 = client.ensure_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+        assert_eq!(first, second);
+
+        // Names are normalized before comparing
+        let third: u128 = client.ensure_course_category(&admin, &String::from_str(&env, " programming "), &None);
+        assert_eq!(first, third);
+
+        let other: u128 = client.ensure_course_category(&admin, &String::from_str(&env, "Design"), &None);
+        assert_ne!(first, other);
+        assert_eq!(client.get_course_category(&first).unwrap().name, String::from_str(&env, "Programming"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #27)")]
+    fn test_create_course_category_rejects_duplicate() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let admin: Address = setup_admin(&env, &contract_id);
+
+        client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+        client.create_course_category(&admin, &String::from_str(&env, "PROGRAMMING"), &None);
+    }
+}
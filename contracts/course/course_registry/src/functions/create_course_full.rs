@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::add_goal::{validate_goal_content, write_goal};
+use crate::functions::add_module::{validate_module, write_module};
+use crate::functions::create_course::create_course;
+use crate::schema::{Course, CreateCourseParams};
+
+/// Create a course together with its first modules and goals in one call
+///
+/// Every module and goal is validated before the course is created. Soroban
+/// reverts every write of a failed invocation, so a course whose modules or
+/// goals are rejected is never stored.
+pub fn create_course_full(
+    env: Env,
+    creator: Address,
+    params: CreateCourseParams,
+    modules: Vec<(u32, String)>,
+    goals: Vec<String>,
+) -> Course {
+    let mut positions: Vec<u32> = Vec::new(&env);
+    for (position, title) in modules.iter() {
+        validate_module(&env, position, &title);
+        if positions.contains(position) {
+            handle_error(&env, Error::DuplicateModulePosition)
+        }
+        positions.push_back(position);
+    }
+
+    for content in goals.iter() {
+        validate_goal_content(&env, &content);
+    }
+
+    let course: Course = create_course(
+        env.clone(),
+        creator.clone(),
+        params.title,
+        params.description,
+        params.price,
+        params.category,
+        params.language,
+        params.thumbnail_url,
+        params.level,
+        params.duration_hours,
+    );
+
+    for (position, title) in modules.iter() {
+        write_module(&env, creator.clone(), course.id.clone(), position, title);
+    }
+
+    for content in goals.iter() {
+        write_goal(&env, creator.clone(), course.id.clone(), content);
+    }
+
+    course
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::get_module_at_position::get_module_at_position;
+    use crate::schema::CreateCourseParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn params(env: &Env) -> CreateCourseParams {
+        CreateCourseParams {
+            title: String::from_str(env, "title"),
+            description: String::from_str(env, "description"),
+            price: 1000,
+            category: None,
+            language: None,
+            thumbnail_url: None,
+            level: None,
+            duration_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_create_course_full() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let course = client.create_course_full(
+            &creator,
+            &params(&env),
+            &vec![
+                &env,
+                (0u32, String::from_str(&env, "Intro")),
+                (1u32, String::from_str(&env, "Basics")),
+            ],
+            &vec![&env, String::from_str(&env, "Learn the basics")],
+        );
+
+        assert_eq!(client.get_course(&course.id), course);
+        assert_eq!(client.get_course_module_ids(&course.id).len(), 2);
+        let second = env
+            .as_contract(&contract_id, || get_module_at_position(&env, course.id.clone(), 1))
+            .unwrap();
+        assert_eq!(second.title, String::from_str(&env, "Basics"));
+
+        let goals = client.get_goals_by_instructor(&creator);
+        assert_eq!(goals.len(), 1);
+        assert_eq!(goals.get(0).unwrap().course_id, course.id);
+        assert_eq!(goals.get(0).unwrap().content, String::from_str(&env, "Learn the basics"));
+    }
+
+    #[test]
+    fn test_create_course_full_rejects_duplicate_position() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let result = client.try_create_course_full(
+            &creator,
+            &params(&env),
+            &vec![
+                &env,
+                (2u32, String::from_str(&env, "Intro")),
+                (2u32, String::from_str(&env, "Basics")),
+            ],
+            &vec![&env, String::from_str(&env, "Learn the basics")],
+        );
+        assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(405));
+
+        // Nothing was written, so the title is still free
+        assert_eq!(client.get_courses_by_instructor(&creator).len(), 0);
+        let course = client.create_course_full(&creator, &params(&env), &vec![&env], &vec![&env]);
+        assert_eq!(course.title, String::from_str(&env, "title"));
+    }
+}
@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::schema::DataKey;
+
+const DEBUG_MODE_EVENT: Symbol = symbol_short!("debugMode");
+const PERF_METRIC_EVENT: &str = "perfMetric";
+
+/// Enable or disable debug mode. Only admins can toggle it.
+///
+/// While enabled, heavy read paths emit a `perfMetric` event describing
+/// how much storage they touched.
+pub fn set_debug_mode(env: &Env, admin: Address, enabled: bool) {
+    admin.require_auth();
+
+    if !is_admin(env, &admin) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    env.storage().persistent().set(&DataKey::DebugMode, &enabled);
+
+    env.events().publish((DEBUG_MODE_EVENT,), (admin, enabled));
+}
+
+/// Check whether debug mode is enabled (`false` if never set)
+pub fn is_debug_mode(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DebugMode)
+        .unwrap_or(false)
+}
+
+/// Emit a `perfMetric` event for `function` if debug mode is enabled.
+///
+/// The event data is `(storage_reads, ids_scanned)`.
+pub fn emit_perf_metric(env: &Env, function: Symbol, storage_reads: u32, ids_scanned: u32) {
+    if !is_debug_mode(env) {
+        return;
+    }
+
+    env.events().publish(
+        (Symbol::new(env, PERF_METRIC_EVENT), function),
+        (storage_reads, ids_scanned),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{CourseFilters, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Events},
+        Address, Env, String, Symbol, TryFromVal,
+    };
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup_test_env() -> (Env, Address, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+
+        (env, admin, client)
+    }
+
+    fn no_filters() -> CourseFilters {
+        CourseFilters {
+            min_price: None,
+            max_price: None,
+            category: None,
+            level: None,
+            min_duration: None,
+            max_duration: None,
+            require_duration: None,
+            search_text: None,
+        }
+    }
+
+    /// Returns `(reads, scanned)` from the last `perfMetric` event, if any
+    fn last_perf_metric(env: &Env) -> Option<(u32, u32)> {
+        let perf_topic: Symbol = Symbol::new(env, "perfMetric");
+        let mut last: Option<(u32, u32)> = None;
+        for (_, topics, data) in env.events().all().iter() {
+            let first: Option<Symbol> = topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(env, &t).ok());
+            if first == Some(perf_topic.clone()) {
+                last = Some(<(u32, u32)>::try_from_val(env, &data).unwrap());
+            }
+        }
+        last
+    }
+
+    #[test]
+    fn test_debug_mode_defaults_to_off() {
+        let (_env, _admin, client) = setup_test_env();
+        assert!(!client.is_debug_mode());
+    }
+
+    #[test]
+    fn test_no_perf_metric_without_debug_mode() {
+        let (env, _admin, client) = setup_test_env();
+
+        client.list_courses_with_filters(&no_filters(), &None, &None);
+
+        assert_eq!(last_perf_metric(&env), None);
+    }
+
+    #[test]
+    fn test_filtered_list_emits_perf_metric_in_debug_mode() {
+        let (env, admin, client) = setup_test_env();
+        let creator: Address = Address::generate(&env);
+
+        let publish = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(true),
+            new_level: None,
+            new_duration_hours: None,
+        };
+        for title in ["Course A", "Course B", "Course C"] {
+            let course = client.create_course(
+                &creator,
+                &String::from_str(&env, title),
+                &String::from_str(&env, "Description"),
+                &100,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            );
+            client.edit_course(&creator, &course.id, &publish);
+        }
+
+        client.set_debug_mode(&admin, &true);
+        assert!(client.is_debug_mode());
+
+        let results = client.list_courses_with_filters(&no_filters(), &None, &None);
+        assert_eq!(results.len(), 3);
+
+        let (reads, scanned) = last_perf_metric(&env).expect("perfMetric event");
+        // Every existing course costs a `has` and a `get`; the scan then
+        // runs past them until the empty-check budget is exhausted.
+        let max_empty: u32 = crate::schema::MAX_EMPTY_CHECKS;
+        assert_eq!(scanned, 3 + max_empty + 1);
+        assert_eq!(reads, scanned + 3);
+
+        // The function name is the second topic
+        let (_, topics, _) = env.events().all().last().unwrap();
+        let function: Symbol = Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap();
+        assert_eq!(function, symbol_short!("lstFilter"));
+
+        client.set_debug_mode(&admin, &false);
+        assert!(!client.is_debug_mode());
+    }
+}
@@ -1,10 +1,14 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, vec, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, String, Symbol, Vec};
 
 use crate::error::{handle_error, Error};
 use crate::schema::{Course, CourseModule};
+use crate::functions::access_control::get_course_access_addr;
+use crate::functions::count_courses_by_category::remove_from_category_index;
+use crate::functions::get_courses_by_level::remove_from_level_index;
+use crate::functions::list_courses_by_language::remove_from_language_index;
 use crate::functions::utils::{concat_strings, to_lowercase, u32_to_string};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
@@ -13,6 +17,9 @@ const TITLE_KEY: Symbol = symbol_short!("title");
 
 const DELETE_COURSE_EVENT: Symbol = symbol_short!("delCourse");
 
+/// Maximum number of course IDs accepted by `delete_courses_batch`
+const MAX_DELETE_BATCH_SIZE: u32 = 50;
+
 pub fn delete_course(env: &Env, creator: Address, course_id: String) -> Result<(), &'static str> {
     creator.require_auth();
 
@@ -36,19 +43,86 @@ pub fn delete_course(env: &Env, creator: Address, course_id: String) -> Result<(
         handle_error(env, Error::Unauthorized)
     }
 
+    remove_course(env, creator, course_id, &course);
+
+    Ok(())
+}
+
+/// Delete several courses in one call.
+///
+/// Courses that do not exist or that were not created by `creator` are
+/// skipped instead of failing the whole batch.
+///
+/// Returns the number of courses deleted.
+pub fn delete_courses_batch(env: &Env, creator: Address, course_ids: Vec<String>) -> u32 {
+    creator.require_auth();
+
+    if course_ids.len() > MAX_DELETE_BATCH_SIZE {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+
+    let mut deleted: u32 = 0;
+    for course_id in course_ids.iter() {
+        let course: Course = match env
+            .storage()
+            .persistent()
+            .get(&(COURSE_KEY, course_id.clone()))
+        {
+            Some(course) => course,
+            None => continue,
+        };
+
+        if course.creator != creator {
+            continue;
+        }
+
+        remove_course(env, creator.clone(), course_id, &course);
+        deleted += 1;
+    }
+
+    deleted
+}
+
+/// Remove a course, its modules and its index entries, notify the course
+/// access contract, then emit the delete event
+fn remove_course(env: &Env, creator: Address, course_id: String, course: &Course) {
     delete_course_modules(env, &course_id);
 
     let lowercase_title: String = to_lowercase(env, &course.title);
 
     let title_key: (Symbol, String) = (TITLE_KEY, lowercase_title);
     env.storage().persistent().remove(&title_key);
-    env.storage().persistent().remove(&course_storage_key);
+    env.storage().persistent().remove(&(COURSE_KEY, course_id.clone()));
+
+    if let Some(ref level) = course.level {
+        remove_from_level_index(env, level, &course_id);
+    }
+    if let Some(ref language) = course.language {
+        remove_from_language_index(env, language, &course_id);
+    }
+    if let Some(ref category) = course.category {
+        remove_from_category_index(env, category, &course_id);
+    }
+
+    notify_course_access(env, &course_id);
 
     // emit an event
     env.events()
         .publish((DELETE_COURSE_EVENT,), (creator, course_id));
+}
 
-    Ok(())
+/// Let the configured course access contract revoke a deleted course's enrollments
+///
+/// Best effort: a failing or decommissioned course access contract must not
+/// block the deletion.
+pub fn notify_course_access(env: &Env, course_id: &String) {
+    if let Some(course_access_addr) = get_course_access_addr(env) {
+        let _ = env.try_invoke_contract::<u32, soroban_sdk::Error>(
+            &course_access_addr,
+            &Symbol::new(env, "on_course_deleted"),
+            (course_id.clone(),).into_val(env),
+        );
+    }
 }
 
 fn delete_course_modules(env: &Env, course_id: &String) {
@@ -110,6 +184,46 @@ mod tests {
         }
     }
 
+    // Mock CourseAccess contract recording the deleted courses it is notified of
+    mod mock_course_access {
+        use soroban_sdk::{contract, contractimpl, symbol_short, Env, String, Vec};
+
+        #[contract]
+        pub struct CourseAccess;
+
+        #[contractimpl]
+        impl CourseAccess {
+            pub fn on_course_deleted(env: Env, course_id: String) -> u32 {
+                let mut deleted: Vec<String> = Self::deleted_courses(env.clone());
+                deleted.push_back(course_id);
+                env.storage().instance().set(&symbol_short!("deleted"), &deleted);
+                deleted.len()
+            }
+
+            pub fn deleted_courses(env: Env) -> Vec<String> {
+                env.storage()
+                    .instance()
+                    .get(&symbol_short!("deleted"))
+                    .unwrap_or(Vec::new(&env))
+            }
+        }
+    }
+
+    // Mock CourseAccess contract whose hook always fails
+    mod failing_course_access {
+        use soroban_sdk::{contract, contractimpl, Env, String};
+
+        #[contract]
+        pub struct CourseAccess;
+
+        #[contractimpl]
+        impl CourseAccess {
+            pub fn on_course_deleted(_env: Env, _course_id: String) -> u32 {
+                panic!("course access unavailable")
+            }
+        }
+    }
+
     fn setup_test_env() -> (Env, Address, CourseRegistryClient<'static>) {
         let env = Env::default();
         env.mock_all_auths();
@@ -340,4 +454,147 @@ mod tests {
         });
         assert!(course2_exists);
     }
+
+    #[test]
+    fn test_delete_courses_batch_skips_unowned_and_missing() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let creator: Address = Address::generate(&env);
+        let other: Address = Address::generate(&env);
+
+        let mut ids: Vec<String> = Vec::new(&env);
+        for (owner, title) in [(&creator, "owned1"), (&other, "foreign"), (&creator, "owned2")] {
+            let course: Course = client.create_course(
+                owner,
+                &String::from_str(&env, title),
+                &String::from_str(&env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            );
+            ids.push_back(course.id);
+        }
+        ids.push_back(String::from_str(&env, "not_found"));
+
+        let deleted: u32 = client.delete_courses_batch(&creator, &ids);
+        assert_eq!(deleted, 2);
+
+        let exists = |id: String| -> bool {
+            env.as_contract(&contract_id, || env.storage().persistent().has(&(COURSE_KEY, id)))
+        };
+        assert!(!exists(ids.get(0).unwrap()));
+        assert!(exists(ids.get(1).unwrap()));
+        assert!(!exists(ids.get(2).unwrap()));
+
+        // The freed titles can be reused
+        client.create_course(
+            &creator,
+            &String::from_str(&env, "owned1"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #46)")]
+    fn test_delete_courses_batch_too_large() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let mut ids: Vec<String> = Vec::new(&env);
+        for i in 0..=MAX_DELETE_BATCH_SIZE {
+            ids.push_back(u32_to_string(&env, i + 1));
+        }
+
+        client.delete_courses_batch(&Address::generate(&env), &ids);
+    }
+
+    fn create_titled_course(client: &CourseRegistryClient<'_>, creator: &Address, title: &str) -> String {
+        client
+            .create_course(
+                creator,
+                &String::from_str(&client.env, title),
+                &String::from_str(&client.env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .id
+    }
+
+    #[test]
+    fn test_delete_course_notifies_course_access() {
+        let (env, _contract_id, client) = setup_test_env();
+        let access_id: Address = env.register(mock_course_access::CourseAccess, ());
+        let access = mock_course_access::CourseAccessClient::new(&env, &access_id);
+        client.set_course_access_contract(&client.get_admin(), &Some(access_id.clone()));
+        assert_eq!(client.get_course_access_contract(), Some(access_id));
+
+        let creator: Address = Address::generate(&env);
+        let first_id: String = create_titled_course(&client, &creator, "first");
+        let second_id: String = create_titled_course(&client, &creator, "second");
+
+        client.delete_course(&creator, &first_id);
+        client.delete_courses_batch(&creator, &vec![&env, second_id.clone()]);
+
+        assert_eq!(access.deleted_courses(), vec![&env, first_id, second_id]);
+    }
+
+    #[test]
+    fn test_delete_course_without_course_access_contract() {
+        let (env, _contract_id, client) = setup_test_env();
+        let access_id: Address = env.register(mock_course_access::CourseAccess, ());
+        let access = mock_course_access::CourseAccessClient::new(&env, &access_id);
+        client.set_course_access_contract(&client.get_admin(), &Some(access_id));
+        client.set_course_access_contract(&client.get_admin(), &None);
+        assert_eq!(client.get_course_access_contract(), None);
+
+        let creator: Address = Address::generate(&env);
+        let course_id: String = create_titled_course(&client, &creator, "first");
+        client.delete_course(&creator, &course_id);
+
+        assert_eq!(access.deleted_courses().len(), 0);
+    }
+
+    #[test]
+    fn test_delete_course_when_course_access_fails() {
+        let (env, contract_id, client) = setup_test_env();
+        let access_id: Address = env.register(failing_course_access::CourseAccess, ());
+        client.set_course_access_contract(&client.get_admin(), &Some(access_id));
+
+        let creator: Address = Address::generate(&env);
+        let course_id: String = create_titled_course(&client, &creator, "first");
+        client.delete_course(&creator, &course_id);
+
+        let exists: bool = env.as_contract(&contract_id, || {
+            env.storage().persistent().has(&(COURSE_KEY, course_id.clone()))
+        });
+        assert!(!exists);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_course_access_contract_requires_owner() {
+        let (env, _contract_id, client) = setup_test_env();
+
+        client.set_course_access_contract(&Address::generate(&env), &Some(Address::generate(&env)));
+    }
 }
@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::schema::{Course, DataKey, DurationValidationMode};
+
+const DURATION_MODE_EVENT: Symbol = symbol_short!("durMode");
+const DURATION_WARNING_EVENT: Symbol = symbol_short!("durWarn");
+
+/// Set how publishing treats courses that claim a duration but have no modules.
+/// Only admins can change the mode.
+pub fn set_duration_validation_mode(env: &Env, admin: Address, mode: DurationValidationMode) {
+    admin.require_auth();
+
+    if !is_admin(env, &admin) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::DurationValidationMode, &mode);
+
+    env.events().publish((DURATION_MODE_EVENT,), (admin, mode));
+}
+
+/// Get the configured duration validation mode (`Off` if never set)
+pub fn get_duration_validation_mode(env: &Env) -> DurationValidationMode {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DurationValidationMode)
+        .unwrap_or(DurationValidationMode::Off)
+}
+
+/// Validate a course that is being published.
+///
+/// When a nonzero `duration_hours` is set but the course has no modules,
+/// either emits a `durWarn` event or rejects with `InvalidDurationValue`,
+/// depending on the configured mode.
+pub fn check_duration_on_publish(env: &Env, course: &Course) {
+    let mode: DurationValidationMode = get_duration_validation_mode(env);
+    if mode == DurationValidationMode::Off {
+        return;
+    }
+
+    let duration: u32 = match course.duration_hours {
+        Some(duration) if duration > 0 => duration,
+        _ => return,
+    };
+
+    let module_count: u32 = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<String>>(&DataKey::CourseModules(course.id.clone()))
+        .map(|module_ids| module_ids.len())
+        .unwrap_or(0);
+    if module_count > 0 {
+        return;
+    }
+
+    match mode {
+        DurationValidationMode::Reject => handle_error(env, Error::InvalidDurationValue),
+        _ => env
+            .events()
+            .publish((DURATION_WARNING_EVENT, course.id.clone()), duration),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{DurationValidationMode, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Events},
+        Address, Env, String, Symbol, TryFromVal,
+    };
+
+    // Mock UserManagement contract for testing
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup_test_env() -> (Env, Address, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+
+        (env, admin, client)
+    }
+
+    fn create_course_with_duration(client: &CourseRegistryClient<'_>, creator: &Address) -> String {
+        client
+            .create_course(
+                creator,
+                &String::from_str(&client.env, "Title"),
+                &String::from_str(&client.env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &Some(100),
+            )
+            .id
+    }
+
+    fn publish_params() -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(true),
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    fn last_event_topic(env: &Env) -> Symbol {
+        let (_, topics, _) = env.events().all().last().unwrap();
+        Symbol::try_from_val(env, &topics.get(0).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_publish_without_modules_allowed_by_default() {
+        let (env, _admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let course_id = create_course_with_duration(&client, &creator);
+
+        assert_eq!(client.get_duration_validation_mode(), DurationValidationMode::Off);
+        let course = client.edit_course(&creator, &course_id, &publish_params());
+        assert!(course.published);
+        assert_eq!(last_event_topic(&env), Symbol::new(&env, "editCours"));
+    }
+
+    #[test]
+    fn test_publish_without_modules_warns() {
+        let (env, admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let course_id = create_course_with_duration(&client, &creator);
+
+        client.set_duration_validation_mode(&admin, &DurationValidationMode::Warn);
+        let course = client.edit_course(&creator, &course_id, &publish_params());
+        assert!(course.published);
+
+        let warned = env.events().all().iter().any(|(_, topics, _)| {
+            Symbol::try_from_val(&env, &topics.get(0).unwrap())
+                == Ok(Symbol::new(&env, "durWarn"))
+        });
+        assert!(warned);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #45)")]
+    fn test_publish_without_modules_rejected() {
+        let (env, admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let course_id = create_course_with_duration(&client, &creator);
+
+        client.set_duration_validation_mode(&admin, &DurationValidationMode::Reject);
+        client.edit_course(&creator, &course_id, &publish_params());
+    }
+
+    #[test]
+    fn test_publish_with_modules_passes_reject_mode() {
+        let (env, admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let course_id = create_course_with_duration(&client, &creator);
+
+        client.set_duration_validation_mode(&admin, &DurationValidationMode::Reject);
+        client.add_module(&creator, &course_id, &1, &String::from_str(&env, "Intro"));
+
+        let course = client.edit_course(&creator, &course_id, &publish_params());
+        assert!(course.published);
+    }
+}
@@ -4,13 +4,22 @@
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
 use crate::error::{handle_error, Error};
-use crate::schema::{Course, EditCourseParams};
+use crate::functions::access_control::is_creator_or_co_creator;
+use crate::schema::{Course, CourseLevel, EditCourseParams};
+use crate::functions::course_level::validate_course_level;
+use crate::functions::duration_validation::check_duration_on_publish;
+use crate::functions::strict_categories::require_known_category;
+use crate::functions::count_courses_by_category::{add_to_category_index, remove_from_category_index};
+use crate::functions::get_courses_by_level::{add_to_level_index, remove_from_level_index};
+use crate::functions::language_allowlist::validate_course_language;
+use crate::functions::list_courses_by_language::{add_to_language_index, remove_from_language_index};
 use crate::functions::utils::{to_lowercase, trim};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 const TITLE_KEY: Symbol = symbol_short!("title");
 
 const EDIT_COURSE_EVENT: Symbol = symbol_short!("editCours");
+const VISIBILITY_CHANGE_EVENT: Symbol = symbol_short!("visChange");
 
 pub fn edit_course(
     env: Env,
@@ -20,6 +29,11 @@ pub fn edit_course(
 ) -> Course {
     creator.require_auth();
 
+    // --- Reject an edit that changes nothing ---
+    if !has_changes(&params) {
+        panic!("Course error: No changes provided");
+    }
+
     // --- Load existing course ---
     let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
     let mut course: Course = env
@@ -28,8 +42,8 @@ pub fn edit_course(
         .get(&storage_key)
         .expect("Course error: Course not found");
 
-    // --- Permission: only creator can edit ---
-    if creator != course.creator {
+    // --- Permission: only creator and co-creators can edit ---
+    if !is_creator_or_co_creator(&course, &creator) {
         handle_error(&env, Error::Unauthorized)
     }
 
@@ -79,9 +93,32 @@ pub fn edit_course(
 
     // --- Optional fields: category / language / thumbnail ---
     if let Some(cat) = params.new_category {
+        // Move the course between category index buckets
+        if cat != course.category {
+            if let Some(ref new_cat) = cat {
+                require_known_category(&env, new_cat);
+            }
+            if let Some(ref old_cat) = course.category {
+                remove_from_category_index(&env, old_cat, &course_id);
+            }
+            if let Some(ref new_cat) = cat {
+                add_to_category_index(&env, new_cat, &course_id);
+            }
+        }
         course.category = cat; // Some(value) sets; None clears
     }
     if let Some(lang) = params.new_language {
+        let lang: Option<String> = lang.map(|lang| validate_course_language(&env, &lang));
+
+        // Move the course between language index buckets
+        if lang != course.language {
+            if let Some(ref old_lang) = course.language {
+                remove_from_language_index(&env, old_lang, &course_id);
+            }
+            if let Some(ref new_lang) = lang {
+                add_to_language_index(&env, new_lang, &course_id);
+            }
+        }
         course.language = lang;
     }
     if let Some(url) = params.new_thumbnail_url {
@@ -89,12 +126,24 @@ pub fn edit_course(
     }
 
     // --- Published flag ---
+    let was_published: bool = course.published;
     if let Some(p) = params.new_published {
         course.published = p;
     }
 
     // --- Level field ---
     if let Some(level) = params.new_level {
+        let level: Option<CourseLevel> = level.map(|lvl| validate_course_level(&env, &lvl));
+
+        // Move the course between level index buckets
+        if level != course.level {
+            if let Some(ref old_level) = course.level {
+                remove_from_level_index(&env, old_level, &course_id);
+            }
+            if let Some(ref new_level) = level {
+                add_to_level_index(&env, new_level, &course_id);
+            }
+        }
         course.level = level; // Some(value) sets; None clears
     }
 
@@ -103,21 +152,48 @@ pub fn edit_course(
         course.duration_hours = duration; // Some(value) sets; None clears
     }
 
+    // --- Duration consistency check when publishing (opt-in) ---
+    if params.new_published == Some(true) {
+        check_duration_on_publish(&env, &course);
+    }
+
     // --- Persist updated course ---
+    course.updated_at = env.ledger().timestamp();
     env.storage().persistent().set(&storage_key, &course);
 
-    // --- Emit event ---
+    // --- Emit events ---
+    // Publish and unpublish transitions get their own event
+    if course.published != was_published {
+        env.events()
+            .publish((VISIBILITY_CHANGE_EVENT, course_id.clone()), (was_published, course.published));
+    }
+
     env.events()
         .publish((EDIT_COURSE_EVENT,), (creator, course_id));
 
     course
 }
 
+/// Returns `true` if at least one field of the edit is set
+fn has_changes(params: &EditCourseParams) -> bool {
+    params.new_title.is_some()
+        || params.new_description.is_some()
+        || params.new_price.is_some()
+        || params.new_category.is_some()
+        || params.new_language.is_some()
+        || params.new_thumbnail_url.is_some()
+        || params.new_published.is_some()
+        || params.new_level.is_some()
+        || params.new_duration_hours.is_some()
+}
+
 #[cfg(test)]
 mod test {
     use crate::schema::{Course, EditCourseParams};
     use crate::{CourseRegistry, CourseRegistryClient};
-    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+    use soroban_sdk::{
+        symbol_short, testutils::{Address as _, Events}, Address, Env, String, Symbol, TryFromVal, Val,
+    };
 
     #[test]
     fn test_edit_course_success() {
@@ -485,4 +561,142 @@ mod test {
             String::from_str(&env, "New Description")
         );
     }
+
+    #[test]
+    #[should_panic(expected = "Course error: No changes provided")]
+    fn test_edit_course_no_changes_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let creator: Address = Address::generate(&env);
+
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Original Title"),
+            &String::from_str(&env, "Original Description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(&creator, &course.id, &params);
+    }
+
+    #[test]
+    fn test_edit_course_single_field() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let creator: Address = Address::generate(&env);
+
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Original Title"),
+            &String::from_str(&env, "Original Description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: Some(1500_u128),
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        };
+        let edited_course = client.edit_course(&creator, &course.id, &params);
+
+        assert_eq!(edited_course.price, 1500_u128);
+        assert_eq!(edited_course.title, String::from_str(&env, "Original Title"));
+    }
+
+    fn event_data(env: &Env, name: Symbol) -> Option<Val> {
+        env.events()
+            .all()
+            .iter()
+            .find(|(_, topics, _)| Symbol::try_from_val(env, &topics.get(0).unwrap()).ok() == Some(name.clone()))
+            .map(|(_, _, data)| data)
+    }
+
+    fn publish_params(published: bool) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(published),
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_edit_course_visibility_change_event() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let creator: Address = Address::generate(&env);
+
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Original Title"),
+            &String::from_str(&env, "Original Description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        client.edit_course(&creator, &course.id, &publish_params(true));
+
+        assert!(event_data(&env, symbol_short!("editCours")).is_some());
+        let data = event_data(&env, symbol_short!("visChange")).unwrap();
+        assert_eq!(<(bool, bool)>::try_from_val(&env, &data).unwrap(), (false, true));
+
+        // Publishing an already published course is not a transition
+        client.edit_course(&creator, &course.id, &publish_params(true));
+
+        assert!(event_data(&env, symbol_short!("editCours")).is_some());
+        assert!(event_data(&env, symbol_short!("visChange")).is_none());
+
+        client.edit_course(&creator, &course.id, &publish_params(false));
+
+        let data = event_data(&env, symbol_short!("visChange")).unwrap();
+        assert_eq!(<(bool, bool)>::try_from_val(&env, &data).unwrap(), (true, false));
+    }
 }
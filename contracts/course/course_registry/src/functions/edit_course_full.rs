@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::edit_course::edit_course;
+use crate::functions::edit_prerequisite::replace_prerequisites;
+use crate::schema::{Course, EditCourseParams};
+
+/// Edit a course and optionally replace its prerequisites in one call
+///
+/// Soroban reverts every write of a failed invocation, so a rejected
+/// prerequisite list also discards the course edit. Replacing prerequisites
+/// is restricted to the course creator, as in `edit_prerequisite`.
+pub fn edit_course_full(
+    env: Env,
+    creator: Address,
+    course_id: String,
+    params: EditCourseParams,
+    new_prerequisites: Option<Vec<String>>,
+) -> Course {
+    let course: Course = edit_course(env.clone(), creator.clone(), course_id.clone(), params);
+
+    if let Some(prerequisites) = new_prerequisites {
+        if course.creator != creator {
+            handle_error(&env, Error::Unauthorized)
+        }
+        replace_prerequisites(&env, &course_id, prerequisites);
+    }
+
+    course
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, DataKey, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn title_params(env: &Env, title: &str) -> EditCourseParams {
+        EditCourseParams {
+            new_title: Some(String::from_str(env, title)),
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    fn stored_prerequisites(env: &Env, contract_id: &Address, course_id: &String) -> Option<Vec<String>> {
+        env.as_contract(contract_id, || {
+            env.storage()
+                .persistent()
+                .get(&DataKey::CoursePrerequisites(course_id.clone()))
+        })
+    }
+
+    #[test]
+    fn test_edit_course_full_success() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let basics = create_course(&env, &client, &creator, "Basics");
+        let course = create_course(&env, &client, &creator, "Advanced");
+
+        let prerequisites = vec![&env, basics.id.clone()];
+        let edited = client.edit_course_full(
+            &creator,
+            &course.id,
+            &title_params(&env, "Advanced Topics"),
+            &Some(prerequisites.clone()),
+        );
+
+        assert_eq!(edited.title, String::from_str(&env, "Advanced Topics"));
+        assert_eq!(client.get_course(&course.id).title, String::from_str(&env, "Advanced Topics"));
+        assert_eq!(stored_prerequisites(&env, &contract_id, &course.id), Some(prerequisites));
+    }
+
+    #[test]
+    fn test_edit_course_full_without_prerequisites() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let course = create_course(&env, &client, &creator, "Advanced");
+
+        let edited = client.edit_course_full(&creator, &course.id, &title_params(&env, "Renamed"), &None);
+
+        assert_eq!(edited.title, String::from_str(&env, "Renamed"));
+        assert_eq!(stored_prerequisites(&env, &contract_id, &course.id), None);
+    }
+
+    #[test]
+    fn test_edit_course_full_bad_prerequisites_reject_edit() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let course = create_course(&env, &client, &creator, "Advanced");
+
+        let result = client.try_edit_course_full(
+            &creator,
+            &course.id,
+            &title_params(&env, "Advanced Topics"),
+            &Some(vec![&env, String::from_str(&env, "missing")]),
+        );
+        assert!(result.is_err());
+
+        // Neither the edit nor the prerequisites were applied
+        assert_eq!(client.get_course(&course.id).title, String::from_str(&env, "Advanced"));
+        assert_eq!(stored_prerequisites(&env, &contract_id, &course.id), None);
+    }
+
+    #[test]
+    fn test_edit_course_full_circular_prerequisites_reject_edit() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let course = create_course(&env, &client, &creator, "Advanced");
+
+        let result = client.try_edit_course_full(
+            &creator,
+            &course.id,
+            &title_params(&env, "Advanced Topics"),
+            &Some(vec![&env, course.id.clone()]),
+        );
+        assert!(result.is_err());
+        assert_eq!(client.get_course(&course.id).title, String::from_str(&env, "Advanced"));
+    }
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
 use crate::functions::is_course_creator::is_course_creator;
 use crate::error::{handle_error, Error};
@@ -58,6 +58,22 @@ pub fn edit_goal(
     // Save updated goal
     env.storage().persistent().set(&goal_key, &goal);
 
+    // Keep the course's goal list in sync
+    let list_key: DataKey = DataKey::CourseGoalList(course_id.clone());
+    if let Some(mut goals) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<CourseGoal>>(&list_key)
+    {
+        for (i, listed) in goals.iter().enumerate() {
+            if listed.goal_id == goal_id {
+                goals.set(i as u32, goal.clone());
+                env.storage().persistent().set(&list_key, &goals);
+                break;
+            }
+        }
+    }
+
     // Emit event
     env.events().publish(
         (GOAL_EDITED_EVENT, course_id.clone(), goal_id.clone()),
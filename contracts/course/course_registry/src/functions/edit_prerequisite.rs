@@ -31,19 +31,27 @@ pub fn edit_prerequisite(
         handle_error(&env, Error::Unauthorized)
     }
 
+    replace_prerequisites(&env, &course_id, new_prerequisites);
+}
+
+/// Validate and store the full prerequisite list of a course
+///
+/// Every prerequisite must exist, appear once and not lead back to the
+/// course. Callers are responsible for authorization.
+pub fn replace_prerequisites(env: &Env, course_id: &String, new_prerequisites: Vec<String>) {
     // Validate that all prerequisite courses exist
     for prerequisite_id in new_prerequisites.iter() {
         let prereq_course_key: (Symbol, String) = (COURSE_KEY, prerequisite_id.clone());
         if !env.storage().persistent().has(&prereq_course_key) {
-            handle_error(&env, Error::PrereqCourseNotFound)
+            handle_error(env, Error::PrereqCourseNotFound)
         }
     }
 
     // Validate no duplicate prerequisites
-    validate_no_duplicate_prerequisites(&env, &new_prerequisites);
+    validate_no_duplicate_prerequisites(env, &new_prerequisites);
 
     // Prevent circular dependencies
-    validate_no_circular_dependency(&env, &course_id, &new_prerequisites);
+    validate_no_circular_dependency(env, course_id, &new_prerequisites);
 
     // Save updated prerequisites
     env.storage().persistent().set(
@@ -53,7 +61,7 @@ pub fn edit_prerequisite(
 
     // Emit event
     env.events().publish(
-        (PREREQ_UPDATED_EVENT, course_id),
+        (PREREQ_UPDATED_EVENT, course_id.clone()),
         new_prerequisites,
     );
 }
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, xdr::ToXdr, Env, IntoVal, String, Symbol, Val, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{CourseGoal, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+/// Estimate the persistent storage a course uses, in bytes
+///
+/// Sums the XDR-serialized size of the course, its module index and
+/// modules, its goal list and goals, and its prerequisite list. Storage
+/// keys and ledger entry overhead are not counted.
+pub fn estimate_course_storage(env: &Env, course_id: String) -> u32 {
+    let course_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    if !env.storage().persistent().has(&course_key) {
+        handle_error(env, Error::CourseNotFound)
+    }
+
+    let mut total: u32 = stored_size(env, &course_key);
+
+    // Modules and the course's module index
+    let modules_key: DataKey = DataKey::CourseModules(course_id.clone());
+    total += stored_size(env, &modules_key);
+    let module_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&modules_key)
+        .unwrap_or(Vec::new(env));
+    for module_id in module_ids.iter() {
+        total += stored_size(env, &(MODULE_KEY, module_id));
+    }
+
+    // Goals, stored both individually and in the course's goal list
+    let goals_key: DataKey = DataKey::CourseGoalList(course_id.clone());
+    total += stored_size(env, &goals_key);
+    let goals: Vec<CourseGoal> = env
+        .storage()
+        .persistent()
+        .get(&goals_key)
+        .unwrap_or(Vec::new(env));
+    for goal in goals.iter() {
+        total += stored_size(env, &DataKey::CourseGoal(course_id.clone(), goal.goal_id));
+    }
+
+    total + stored_size(env, &DataKey::CoursePrerequisites(course_id))
+}
+
+/// Serialized size of a persistent entry, or 0 if it does not exist
+fn stored_size<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> u32 {
+    env.storage()
+        .persistent()
+        .get::<K, Val>(key)
+        .map(|value| value.to_xdr(env).len())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    #[test]
+    fn test_estimate_course_storage_grows_with_content() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let basics = client.create_course(
+            &creator,
+            &String::from_str(&env, "Basics"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        let empty = client.estimate_course_storage(&course.id);
+        assert!(empty > 0);
+
+        client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"));
+        let one_module = client.estimate_course_storage(&course.id);
+        assert!(one_module > empty);
+
+        client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Basics"));
+        let two_modules = client.estimate_course_storage(&course.id);
+        assert!(two_modules > one_module);
+
+        client.add_goal(&creator, &course.id, &String::from_str(&env, "Learn the basics"));
+        client.add_goal(&creator, &course.id, &String::from_str(&env, "Build a project"));
+        let with_goals = client.estimate_course_storage(&course.id);
+        assert!(with_goals > two_modules);
+
+        client.edit_prerequisite(&creator, &course.id, &vec![&env, basics.id.clone()]);
+        let with_prerequisites = client.estimate_course_storage(&course.id);
+        assert!(with_prerequisites > with_goals);
+
+        // Other courses are not affected
+        assert!(client.estimate_course_storage(&basics.id) < with_prerequisites);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #17)")]
+    fn test_estimate_course_storage_unknown_course() {
+        let env = Env::default();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.estimate_course_storage(&String::from_str(&env, "missing"));
+    }
+}
@@ -16,7 +16,82 @@ use crate::schema::{CourseCategory, DataKey};
 /// Storage used:
 /// - DataKey::CourseCategory(id) -> CourseCategory
 pub fn get_course_category(env: &Env, category_id: u128) -> Option<CourseCategory> {
+    if !is_assigned_category_id(env, category_id) {
+        return None;
+    }
+
     env.storage()
         .persistent()
         .get(&DataKey::CourseCategory(category_id))
 }
+
+/// Checks whether a category with the given ID exists.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - category_id: the ID of the category to look for.
+///
+/// Returns:
+/// - bool: true if the category exists, without loading it.
+pub fn category_exists(env: &Env, category_id: u128) -> bool {
+    is_assigned_category_id(env, category_id)
+        && env
+            .storage()
+            .persistent()
+            .has(&DataKey::CourseCategory(category_id))
+}
+
+/// Category IDs start at 1 and never exceed the category sequence counter.
+fn is_assigned_category_id(env: &Env, category_id: u128) -> bool {
+    let last_id: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CategorySeq)
+        .unwrap_or(0u128);
+    category_id >= 1 && category_id <= last_id
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::DataKey;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup<'a>() -> (Env, CourseRegistryClient<'a>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let admin: Address = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Admins, &vec![&env, admin.clone()]);
+        });
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_category_exists() {
+        let (env, client, admin) = setup();
+
+        let id = client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+
+        assert!(client.category_exists(&id));
+        assert_eq!(client.get_course_category(&id).unwrap().name, String::from_str(&env, "Programming"));
+    }
+
+    #[test]
+    fn test_category_does_not_exist() {
+        let (env, client, admin) = setup();
+
+        assert!(!client.category_exists(&1));
+        client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+
+        assert!(!client.category_exists(&0));
+        assert!(!client.category_exists(&2));
+        assert!(!client.category_exists(&u128::MAX));
+        assert_eq!(client.get_course_category(&u128::MAX), None);
+    }
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, String, Vec};
+
+use crate::schema::DataKey;
+
+/// Get the IDs of the modules of a course, in creation order.
+///
+/// Returns an empty vector for unknown courses and courses without modules,
+/// so other contracts can call this without risking a panic.
+pub fn get_course_module_ids(env: &Env, course_id: String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseModules(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    #[test]
+    fn test_get_course_module_ids_tracks_add_and_remove() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(client.get_course_module_ids(&course.id).len(), 0);
+
+        let first = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"));
+        let second = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Basics"));
+        let ids = client.get_course_module_ids(&course.id);
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids.get(0).unwrap(), first.id);
+        assert_eq!(ids.get(1).unwrap(), second.id);
+
+        client.remove_module(&first.id);
+        let ids = client.get_course_module_ids(&course.id);
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids.get(0).unwrap(), second.id);
+
+        assert_eq!(client.get_course_module_ids(&String::from_str(&env, "missing")).len(), 0);
+    }
+}
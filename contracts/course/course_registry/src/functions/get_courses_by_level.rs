@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::course_level::validate_course_level;
+use crate::schema::{Course, CourseLevel, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Lists published, non-archived courses of the given level.
+///
+/// Reads only the course IDs stored in the level index instead of scanning
+/// every course.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - level: the level to look up.
+/// - limit: optional maximum number of courses to return (default 10, max 100).
+/// - offset: optional number of matching courses to skip.
+///
+/// Returns:
+/// - Vec<Course>: the requested page of courses in index order.
+pub fn get_courses_by_level(
+    env: &Env,
+    level: CourseLevel,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> Vec<Course> {
+    let limit_value: u32 = limit.unwrap_or(10);
+    if limit_value == 0 || limit_value > 100 {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+    let offset_value: u32 = offset.unwrap_or(0);
+    let level: CourseLevel = validate_course_level(env, &level);
+
+    let course_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::LevelCourses(level))
+        .unwrap_or(Vec::new(env));
+
+    let mut results: Vec<Course> = Vec::new(env);
+    let mut skipped: u32 = 0;
+
+    for course_id in course_ids.iter() {
+        if results.len() >= limit_value {
+            break;
+        }
+
+        let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+            Some(course) => course,
+            None => continue,
+        };
+
+        // Same visibility rules as the public listing
+        if course.is_archived || !course.published {
+            continue;
+        }
+
+        if skipped < offset_value {
+            skipped += 1;
+        } else {
+            results.push_back(course);
+        }
+    }
+
+    results
+}
+
+/// Adds a course to the index bucket of its level
+pub fn add_to_level_index(env: &Env, level: &CourseLevel, course_id: &String) {
+    let key: DataKey = DataKey::LevelCourses(level.clone());
+    let mut course_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+
+    if !course_ids.contains(course_id) {
+        course_ids.push_back(course_id.clone());
+        env.storage().persistent().set(&key, &course_ids);
+    }
+}
+
+/// Removes a course from the index bucket of its level
+pub fn remove_from_level_index(env: &Env, level: &CourseLevel, course_id: &String) {
+    let key: DataKey = DataKey::LevelCourses(level.clone());
+    let mut course_ids: Vec<String> = match env.storage().persistent().get(&key) {
+        Some(course_ids) => course_ids,
+        None => return,
+    };
+
+    if let Some(index) = course_ids.first_index_of(course_id) {
+        course_ids.remove(index);
+        if course_ids.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &course_ids);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::EditCourseParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course(
+        client: &CourseRegistryClient<'_>,
+        creator: &Address,
+        title: &str,
+        level: &str,
+    ) -> String {
+        let env = &client.env;
+        let course_id = client
+            .create_course(
+                creator,
+                &String::from_str(env, title),
+                &String::from_str(env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &Some(String::from_str(env, level)),
+                &None,
+            )
+            .id;
+        let mut params = edit_params();
+        params.new_published = Some(true);
+        client.edit_course(creator, &course_id, &params);
+        course_id
+    }
+
+    fn edit_params() -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_get_courses_by_level_moves_between_buckets() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let beginner = String::from_str(&env, "Beginner");
+        let intermediate = String::from_str(&env, "Intermediate");
+
+        let first_id = create_course(&client, &creator, "First", "Beginner");
+        let second_id = create_course(&client, &creator, "Second", "Beginner");
+        let third_id = create_course(&client, &creator, "Third", "Intermediate");
+
+        let beginner_courses = client.get_courses_by_level(&beginner, &None, &None);
+        assert_eq!(beginner_courses.len(), 2);
+        assert_eq!(beginner_courses.get(0).unwrap().id, first_id);
+        assert_eq!(beginner_courses.get(1).unwrap().id, second_id);
+        assert_eq!(client.get_courses_by_level(&intermediate, &None, &None).len(), 1);
+
+        // Move the second course to Intermediate
+        let mut params = edit_params();
+        params.new_level = Some(Some(intermediate.clone()));
+        client.edit_course(&creator, &second_id, &params);
+
+        let beginner_courses = client.get_courses_by_level(&beginner, &None, &None);
+        assert_eq!(beginner_courses.len(), 1);
+        assert_eq!(beginner_courses.get(0).unwrap().id, first_id);
+
+        let intermediate_courses = client.get_courses_by_level(&intermediate, &None, &None);
+        assert_eq!(intermediate_courses.len(), 2);
+        assert_eq!(intermediate_courses.get(0).unwrap().id, third_id);
+        assert_eq!(intermediate_courses.get(1).unwrap().id, second_id);
+
+        // Pagination over the bucket
+        let page = client.get_courses_by_level(&intermediate, &Some(1), &Some(1));
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().id, second_id);
+    }
+
+    #[test]
+    fn test_get_courses_by_level_after_delete() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let beginner = String::from_str(&env, "Beginner");
+
+        let first_id = create_course(&client, &creator, "First", "Beginner");
+        let second_id = create_course(&client, &creator, "Second", "Beginner");
+
+        client.delete_course(&creator, &second_id);
+
+        let courses = client.get_courses_by_level(&beginner, &None, &None);
+        assert_eq!(courses.len(), 1);
+        assert_eq!(courses.get(0).unwrap().id, first_id);
+    }
+
+    #[test]
+    fn test_get_courses_by_level_skips_unpublished() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        client.create_course(
+            &creator,
+            &String::from_str(&env, "Draft"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &Some(String::from_str(&env, "Beginner")),
+            &None,
+        );
+
+        let courses = client.get_courses_by_level(&String::from_str(&env, "Beginner"), &None, &None);
+        assert_eq!(courses.len(), 0);
+    }
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, String, Vec};
+
+use crate::schema::{CourseGoal, DataKey};
+
+/// Get the number of goals of a course.
+///
+/// The count is the length of the course's goal list, which every goal
+/// write keeps in sync. Unknown courses have 0 goals.
+pub fn get_goal_count(env: &Env, course_id: &String) -> u32 {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Vec<CourseGoal>>(&DataKey::CourseGoalList(course_id.clone()))
+        .map(|goals| goals.len())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{
+        symbol_short, testutils::Address as _, testutils::Events, Address, Env, IntoVal, String, Val,
+    };
+
+    fn last_event_data(env: &Env) -> Val {
+        let (_, _, data) = env.events().all().last().unwrap();
+        data
+    }
+
+    #[test]
+    fn test_goal_count_tracks_add_and_remove() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(client.get_goal_count(&course.id), 0);
+
+        let first_content = String::from_str(&env, "Learn the basics");
+        let first = client.add_goal(&creator, &course.id, &first_content);
+        let (_, topics, _) = env.events().all().last().unwrap();
+        assert_eq!(
+            topics,
+            (symbol_short!("goalAdded"), course.id.clone(), first.goal_id.clone()).into_val(&env)
+        );
+        let data: (String, u32) = soroban_sdk::FromVal::from_val(&env, &last_event_data(&env));
+        assert_eq!(data, (first_content.clone(), 1));
+
+        client.add_goal(&creator, &course.id, &String::from_str(&env, "Build a project"));
+        let data: (String, u32) = soroban_sdk::FromVal::from_val(&env, &last_event_data(&env));
+        assert_eq!(data.1, 2);
+        assert_eq!(client.get_goal_count(&course.id), 2);
+
+        client.remove_goal(&creator, &course.id, &first.goal_id);
+        let data: (String, u32) = soroban_sdk::FromVal::from_val(&env, &last_event_data(&env));
+        assert_eq!(data, (first_content, 1));
+        assert_eq!(client.get_goal_count(&course.id), 1);
+    }
+
+    #[test]
+    fn test_goal_count_unknown_course() {
+        let env = Env::default();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        assert_eq!(client.get_goal_count(&String::from_str(&env, "missing")), 0);
+    }
+}
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::functions::get_courses_by_instructor::get_courses_by_instructor;
+use crate::schema::{CourseGoal, DataKey};
+
+/// Maximum number of goals returned by `get_goals_by_instructor`
+pub const MAX_INSTRUCTOR_GOALS: u32 = 200;
+
+/// Collects the learning goals of every course of an instructor.
+///
+/// Goals are grouped by course, in course creation order, and each course's
+/// goals keep the order they were added in. Archived courses are skipped,
+/// like in `get_courses_by_instructor`. At most `MAX_INSTRUCTOR_GOALS` goals
+/// are returned.
+pub fn get_goals_by_instructor(env: &Env, instructor: Address) -> Vec<CourseGoal> {
+    let mut results: Vec<CourseGoal> = Vec::new(env);
+
+    for course in get_courses_by_instructor(env, instructor).iter() {
+        let goals: Vec<CourseGoal> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CourseGoalList(course.id))
+            .unwrap_or(Vec::new(env));
+
+        for goal in goals.iter() {
+            if results.len() >= MAX_INSTRUCTOR_GOALS {
+                return results;
+            }
+            results.push_back(goal);
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_get_goals_by_instructor() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let instructor = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        let course1 = create_course(&env, &client, &instructor, "course1");
+        let other_course = create_course(&env, &client, &other, "other");
+        let course2 = create_course(&env, &client, &instructor, "course2");
+
+        let goal1 = client.add_goal(&instructor, &course1.id, &String::from_str(&env, "Learn ownership"));
+        let goal2 = client.add_goal(&instructor, &course1.id, &String::from_str(&env, "Learn lifetimes"));
+        client.add_goal(&other, &other_course.id, &String::from_str(&env, "Not included"));
+        let goal3 = client.add_goal(&instructor, &course2.id, &String::from_str(&env, "Write contracts"));
+
+        let goals = client.get_goals_by_instructor(&instructor);
+        assert_eq!(goals.len(), 3);
+        assert_eq!(goals.get(0).unwrap(), goal1);
+        assert_eq!(goals.get(1).unwrap(), goal2);
+        assert_eq!(goals.get(2).unwrap(), goal3);
+
+        assert_eq!(client.get_goals_by_instructor(&Address::generate(&env)).len(), 0);
+    }
+}
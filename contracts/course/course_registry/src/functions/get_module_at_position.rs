@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, String, Symbol, TryFromVal, Val, Vec};
+
+use crate::schema::{CourseModule, DataKey};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+const POSITION_KEY: Symbol = symbol_short!("pos");
+
+/// Get the module of a course at a given position.
+///
+/// The position marker written by `add_module` holds the module ID, so the
+/// module is resolved without scanning. Markers written before the ID was
+/// stored only hold `true`; for those the course's module index is scanned.
+/// Returns `None` if no module occupies the position, including when the
+/// module at that position has been removed.
+pub fn get_module_at_position(env: &Env, course_id: String, position: u32) -> Option<CourseModule> {
+    let position_key: (Symbol, String, u32) = (POSITION_KEY, course_id.clone(), position);
+    let marker: Val = env.storage().persistent().get(&position_key)?;
+
+    match String::try_from_val(env, &marker) {
+        Ok(module_id) => env
+            .storage()
+            .persistent()
+            .get::<(Symbol, String), CourseModule>(&(MODULE_KEY, module_id))
+            .filter(|module| module.course_id == course_id && module.position == position),
+        Err(_) => find_module_at_position(env, &course_id, position),
+    }
+}
+
+/// Scan the course's module index for the module at `position`
+fn find_module_at_position(env: &Env, course_id: &String, position: u32) -> Option<CourseModule> {
+    let module_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseModules(course_id.clone()))
+        .unwrap_or(Vec::new(env));
+
+    module_ids.iter().find_map(|module_id| {
+        env.storage()
+            .persistent()
+            .get::<(Symbol, String), CourseModule>(&(MODULE_KEY, module_id))
+            .filter(|module| module.position == position)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{schema::Course, CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+
+    fn setup_course<'a>() -> (Env, CourseRegistryClient<'a>, Address, Course) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, creator, course)
+    }
+
+    #[test]
+    fn test_get_module_at_position() {
+        let (env, client, creator, course) = setup_course();
+
+        let intro = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"));
+        let advanced = client.add_module(&creator, &course.id, &7, &String::from_str(&env, "Advanced"));
+
+        assert_eq!(client.get_module_at_position(&course.id, &0), Some(intro));
+        assert_eq!(client.get_module_at_position(&course.id, &7), Some(advanced));
+    }
+
+    #[test]
+    fn test_get_module_at_empty_position() {
+        let (env, client, creator, course) = setup_course();
+
+        client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Intro"));
+
+        assert_eq!(client.get_module_at_position(&course.id, &2), None);
+        assert_eq!(client.get_module_at_position(&String::from_str(&env, "unknown"), &1), None);
+    }
+
+    #[test]
+    fn test_get_module_at_position_after_removal() {
+        let (env, client, creator, course) = setup_course();
+
+        let module = client.add_module(&creator, &course.id, &3, &String::from_str(&env, "Intro"));
+        client.remove_module(&module.id);
+
+        assert_eq!(client.get_module_at_position(&course.id, &3), None);
+    }
+
+    #[test]
+    fn test_get_module_at_position_with_legacy_marker() {
+        let (env, client, creator, course) = setup_course();
+
+        let module = client.add_module(&creator, &course.id, &2, &String::from_str(&env, "Intro"));
+        env.as_contract(&client.address, || {
+            env.storage()
+                .persistent()
+                .set(&(symbol_short!("pos"), course.id.clone(), 2u32), &true);
+        });
+
+        assert_eq!(client.get_module_at_position(&course.id, &2), Some(module));
+    }
+}
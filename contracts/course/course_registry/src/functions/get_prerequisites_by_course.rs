@@ -1,11 +1,14 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Env, String, Vec, Symbol};
-use crate::schema::{Course, CourseId};
+use soroban_sdk::{symbol_short, Env, Map, String, Vec, Symbol};
+use crate::schema::{Course, CourseId, DataKey};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
+/// Maximum number of courses returned by `get_prerequisite_closure`
+const MAX_PREREQUISITE_CLOSURE: u32 = 100;
+
 pub fn get_prerequisites_by_course_id(env: &Env, course_id: String) -> Vec<CourseId> {
     let key: (Symbol, String) = (COURSE_KEY, course_id);
 
@@ -14,3 +17,109 @@ pub fn get_prerequisites_by_course_id(env: &Env, course_id: String) -> Vec<Cours
         None => Vec::new(env), // Return empty if course doesn't exist
     }
 }
+
+/// Every course that must be completed before `course_id`, directly or
+/// through other prerequisites.
+///
+/// Courses are listed nearest first; `count` is the number of prerequisite
+/// steps from `course_id` (1 for direct prerequisites). At most
+/// `MAX_PREREQUISITE_CLOSURE` courses are returned.
+pub fn get_prerequisite_closure(env: &Env, course_id: String) -> Vec<CourseId> {
+    let mut closure: Vec<CourseId> = Vec::new(env);
+    let mut seen: Map<String, bool> = Map::new(env);
+    seen.set(course_id.clone(), true);
+
+    // Breadth-first, so every course is recorded at its shortest distance
+    let mut index: u32 = 0;
+    let mut current: String = course_id;
+    let mut depth: u128 = 0;
+    loop {
+        let prerequisites: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CoursePrerequisites(current))
+            .unwrap_or(Vec::new(env));
+        for prerequisite_id in prerequisites.iter() {
+            if seen.contains_key(prerequisite_id.clone()) {
+                continue;
+            }
+            if closure.len() >= MAX_PREREQUISITE_CLOSURE {
+                return closure;
+            }
+            seen.set(prerequisite_id.clone(), true);
+            closure.push_back(CourseId {
+                id: prerequisite_id,
+                count: depth + 1,
+            });
+        }
+
+        match closure.get(index) {
+            Some(next) => {
+                current = next.id;
+                depth = next.count;
+                index += 1;
+            }
+            None => return closure,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::CourseId;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn create_course(client: &CourseRegistryClient, creator: &Address, title: &str) -> String {
+        let env = &client.env;
+        client
+            .create_course(
+                creator,
+                &String::from_str(env, title),
+                &String::from_str(env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .id
+    }
+
+    #[test]
+    fn test_get_prerequisite_closure() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let basics = create_course(&client, &creator, "Basics");
+        let intermediate = create_course(&client, &creator, "Intermediate");
+        let advanced = create_course(&client, &creator, "Advanced");
+        client.add_prerequisite(&creator, &intermediate, &vec![&env, basics.clone()]);
+        client.add_prerequisite(&creator, &advanced, &vec![&env, intermediate.clone()]);
+
+        assert_eq!(
+            client.get_prerequisite_closure(&advanced),
+            vec![
+                &env,
+                CourseId { id: intermediate.clone(), count: 1 },
+                CourseId { id: basics.clone(), count: 2 },
+            ]
+        );
+
+        // A course reachable several ways is listed once, at its shortest distance
+        client.add_prerequisite(&creator, &advanced, &vec![&env, intermediate.clone(), basics.clone()]);
+        assert_eq!(
+            client.get_prerequisite_closure(&advanced),
+            vec![
+                &env,
+                CourseId { id: intermediate.clone(), count: 1 },
+                CourseId { id: basics.clone(), count: 1 },
+            ]
+        );
+        assert_eq!(client.get_prerequisite_closure(&basics).len(), 0);
+    }
+}
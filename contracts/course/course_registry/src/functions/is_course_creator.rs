@@ -2,18 +2,24 @@
 // Copyright (c) 2025 SkillCert
 
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_creator_or_co_creator;
 use crate::schema::Course;
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
+/// Returns `true` if `user` is the creator or a co-creator of the course
+///
+/// Fails with `Error::CourseNotFound` for unknown courses.
 pub fn is_course_creator(env: &Env, course_id: String, user: Address) -> bool {
     let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
-    let course: Course = env.storage().persistent().get(&key).unwrap();
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(env, Error::CourseNotFound));
 
-    if course.creator == user {
-        return true;
-    }
-    false
+    is_creator_or_co_creator(&course, &user)
 }
 
 #[cfg(test)]
@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error, ValidationError};
+use crate::functions::access_control::is_admin;
+use crate::functions::utils::{to_lowercase, trim};
+use crate::schema::DataKey;
+
+const LANGUAGE_ALLOWLIST_EVENT: Symbol = symbol_short!("langList");
+
+/// Maximum number of language codes in the allowlist
+pub const MAX_LANGUAGE_ALLOWLIST_SIZE: u32 = 200;
+
+/// Replace the list of ISO-639 language codes courses may use.
+///
+/// Codes are two or three ASCII letters and are stored lowercase. An empty
+/// list disables the check. Only admins can change the allowlist.
+pub fn set_language_allowlist(env: &Env, admin: Address, languages: Vec<String>) {
+    admin.require_auth();
+
+    if !is_admin(env, &admin) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if languages.len() > MAX_LANGUAGE_ALLOWLIST_SIZE {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+
+    let mut allowlist: Vec<String> = Vec::new(env);
+    for language in languages.iter() {
+        if !is_iso_639_code(&language) {
+            handle_error(env, ValidationError::InvalidCourseLanguage)
+        }
+        let code: String = to_lowercase(env, &language);
+        if !allowlist.contains(&code) {
+            allowlist.push_back(code);
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::LanguageAllowlist, &allowlist);
+
+    env.events()
+        .publish((LANGUAGE_ALLOWLIST_EVENT,), (admin, allowlist));
+}
+
+/// Get the configured language allowlist (empty if never set)
+pub fn get_language_allowlist(env: &Env) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LanguageAllowlist)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Validate a course language against the allowlist.
+///
+/// With no allowlist configured any language is accepted unchanged.
+/// Otherwise the language must match an allowed code, ignoring case and
+/// surrounding whitespace, and the lowercase code is returned so that stored
+/// languages are consistent. Off-list languages are rejected with
+/// `InvalidCourseLanguage`.
+pub fn validate_course_language(env: &Env, language: &String) -> String {
+    let allowlist: Vec<String> = get_language_allowlist(env);
+    if allowlist.is_empty() {
+        return language.clone();
+    }
+
+    let code: String = normalize_course_language(env, language);
+    if !allowlist.contains(&code) {
+        handle_error(env, ValidationError::InvalidCourseLanguage)
+    }
+    code
+}
+
+/// Returns the form in which a language is stored.
+///
+/// Languages are stored as given while no allowlist is configured, and as
+/// lowercase codes once one is.
+pub fn normalize_course_language(env: &Env, language: &String) -> String {
+    if get_language_allowlist(env).is_empty() {
+        return language.clone();
+    }
+    to_lowercase(env, &trim(env, language))
+}
+
+/// ISO-639-1 and ISO-639-2 codes are two or three ASCII letters
+fn is_iso_639_code(code: &String) -> bool {
+    let len: usize = code.len() as usize;
+    if !(2..=3).contains(&len) {
+        return false;
+    }
+
+    let mut buf: [u8; 3] = [0u8; 3];
+    code.copy_into_slice(&mut buf[..len]);
+    buf[..len].iter().all(|byte| byte.is_ascii_alphabetic())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::EditCourseParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    // Mock UserManagement contract for testing
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup_test_env() -> (Env, Address, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+
+        (env, admin, client)
+    }
+
+    fn create_course_with_language(
+        env: &Env,
+        client: &CourseRegistryClient,
+        creator: &Address,
+        language: &str,
+    ) -> crate::schema::Course {
+        client.create_course(
+            creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "Description"),
+            &1000_u128,
+            &None,
+            &Some(String::from_str(env, language)),
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_no_allowlist_is_permissive() {
+        let (env, _admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+
+        let course = create_course_with_language(&env, &client, &creator, "Spnaish");
+        assert_eq!(course.language, Some(String::from_str(&env, "Spnaish")));
+    }
+
+    #[test]
+    fn test_allowlisted_language_accepted() {
+        let (env, admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+
+        client.set_language_allowlist(
+            &admin,
+            &vec![&env, String::from_str(&env, "en"), String::from_str(&env, "ES")],
+        );
+        assert_eq!(
+            client.get_language_allowlist(),
+            vec![&env, String::from_str(&env, "en"), String::from_str(&env, "es")]
+        );
+
+        let course = create_course_with_language(&env, &client, &creator, "Es");
+        assert_eq!(course.language, Some(String::from_str(&env, "es")));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #61)")]
+    fn test_off_list_language_rejected_on_create() {
+        let (env, admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+
+        client.set_language_allowlist(&admin, &vec![&env, String::from_str(&env, "en")]);
+        create_course_with_language(&env, &client, &creator, "Spnaish");
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #61)")]
+    fn test_off_list_language_rejected_on_edit() {
+        let (env, admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+
+        client.set_language_allowlist(&admin, &vec![&env, String::from_str(&env, "en")]);
+        let course = create_course_with_language(&env, &client, &creator, "en");
+
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: Some(Some(String::from_str(&env, "fr"))),
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(&creator, &course.id, &params);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #61)")]
+    fn test_allowlist_rejects_non_iso_codes() {
+        let (env, admin, client) = setup_test_env();
+
+        client.set_language_allowlist(&admin, &vec![&env, String::from_str(&env, "english")]);
+    }
+}
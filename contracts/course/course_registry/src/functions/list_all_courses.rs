@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::functions::utils::u32_to_string;
+use crate::schema::Course;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Lists every course in the registry for audit purposes (admin-only).
+///
+/// Unlike the public listing functions, no visibility filtering is applied:
+/// drafts (unpublished) and archived courses are included.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - admin: caller address (must be an admin).
+/// - limit: optional maximum number of courses to return (default 10, max 100).
+/// - offset: optional number of courses to skip.
+///
+/// Returns:
+/// - Vec<Course>: the requested page of courses ordered by ID.
+pub fn list_all_courses(
+    env: &Env,
+    admin: Address,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> Vec<Course> {
+    admin.require_auth();
+
+    if !is_admin(env, &admin) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let limit_value: u32 = limit.unwrap_or(10);
+    if limit_value == 0 || limit_value > 100 {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+    let offset_value: u32 = offset.unwrap_or(0);
+
+    // Highest course ID generated so far (0 if no courses exist yet)
+    let max_id: u128 = env.storage().persistent().get(&COURSE_KEY).unwrap_or(0);
+
+    let mut results: Vec<Course> = Vec::new(env);
+    let mut skipped: u32 = 0;
+    let mut id: u128 = 1;
+
+    while id <= max_id && results.len() < limit_value {
+        let course_id: String = u32_to_string(env, id as u32);
+        let key: (Symbol, String) = (COURSE_KEY, course_id);
+
+        // Deleted courses leave holes in the ID sequence
+        if let Some(course) = env.storage().persistent().get::<_, Course>(&key) {
+            if skipped < offset_value {
+                skipped += 1;
+            } else {
+                results.push_back(course);
+            }
+        }
+
+        id += 1;
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::EditCourseParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    // Mock UserManagement contract for testing
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup_test_env() -> (Env, Address, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+
+        (env, admin, client)
+    }
+
+    fn create_course(client: &CourseRegistryClient<'_>, creator: &Address, title: &str) -> String {
+        client
+            .create_course(
+                creator,
+                &String::from_str(&client.env, title),
+                &String::from_str(&client.env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .id
+    }
+
+    #[test]
+    fn test_list_all_courses_includes_every_state() {
+        let (env, admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+
+        let draft_id = create_course(&client, &creator, "Draft");
+        let published_id = create_course(&client, &creator, "Published");
+        let archived_id = create_course(&client, &creator, "Archived");
+
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(true),
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(&creator, &published_id, &params);
+        client.archive_course(&creator, &archived_id);
+
+        let first_page = client.list_all_courses(&admin, &Some(2), &None);
+        assert_eq!(first_page.len(), 2);
+        assert_eq!(first_page.get(0).unwrap().id, draft_id);
+        assert!(!first_page.get(0).unwrap().published);
+        assert_eq!(first_page.get(1).unwrap().id, published_id);
+        assert!(first_page.get(1).unwrap().published);
+
+        let second_page = client.list_all_courses(&admin, &Some(2), &Some(2));
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page.get(0).unwrap().id, archived_id);
+        assert!(second_page.get(0).unwrap().is_archived);
+    }
+
+    #[test]
+    fn test_list_all_courses_empty() {
+        let (_env, admin, client) = setup_test_env();
+
+        let courses = client.list_all_courses(&admin, &None, &None);
+        assert_eq!(courses.len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_list_all_courses_requires_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        // No user management contract configured, so nobody is an admin
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.list_all_courses(&Address::generate(&env), &None, &None);
+    }
+}
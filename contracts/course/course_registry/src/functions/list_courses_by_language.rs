@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::language_allowlist::normalize_course_language;
+use crate::schema::{Course, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Lists published, non-archived courses in the given language.
+///
+/// Reads only the course IDs stored in the language index instead of
+/// scanning every course.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - language: the language to look up.
+/// - limit: optional maximum number of courses to return (default 10, max 100).
+/// - offset: optional number of matching courses to skip.
+///
+/// Returns:
+/// - Vec<Course>: the requested page of courses in index order.
+pub fn list_courses_by_language(
+    env: &Env,
+    language: String,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> Vec<Course> {
+    let limit_value: u32 = limit.unwrap_or(10);
+    if limit_value == 0 || limit_value > 100 {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+    let offset_value: u32 = offset.unwrap_or(0);
+    let language: String = normalize_course_language(env, &language);
+
+    let course_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::LanguageCourses(language))
+        .unwrap_or(Vec::new(env));
+
+    let mut results: Vec<Course> = Vec::new(env);
+    let mut skipped: u32 = 0;
+
+    for course_id in course_ids.iter() {
+        if results.len() >= limit_value {
+            break;
+        }
+
+        let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+            Some(course) => course,
+            None => continue,
+        };
+
+        // Same visibility rules as the public listing
+        if course.is_archived || !course.published {
+            continue;
+        }
+
+        if skipped < offset_value {
+            skipped += 1;
+        } else {
+            results.push_back(course);
+        }
+    }
+
+    results
+}
+
+/// Adds a course to the index bucket of its language
+pub fn add_to_language_index(env: &Env, language: &String, course_id: &String) {
+    let key: DataKey = DataKey::LanguageCourses(language.clone());
+    let mut course_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+
+    if !course_ids.contains(course_id) {
+        course_ids.push_back(course_id.clone());
+        env.storage().persistent().set(&key, &course_ids);
+    }
+}
+
+/// Removes a course from the index bucket of its language
+pub fn remove_from_language_index(env: &Env, language: &String, course_id: &String) {
+    let key: DataKey = DataKey::LanguageCourses(language.clone());
+    let mut course_ids: Vec<String> = match env.storage().persistent().get(&key) {
+        Some(course_ids) => course_ids,
+        None => return,
+    };
+
+    if let Some(index) = course_ids.first_index_of(course_id) {
+        course_ids.remove(index);
+        if course_ids.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &course_ids);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, DataKey, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+    fn create_course(
+        client: &CourseRegistryClient<'_>,
+        creator: &Address,
+        title: &str,
+        language: &str,
+    ) -> String {
+        let env = &client.env;
+        let course_id = client
+            .create_course(
+                creator,
+                &String::from_str(env, title),
+                &String::from_str(env, "description"),
+                &1000_u128,
+                &None,
+                &Some(String::from_str(env, language)),
+                &None,
+                &None,
+                &None,
+            )
+            .id;
+        let mut params = edit_params();
+        params.new_published = Some(true);
+        client.edit_course(creator, &course_id, &params);
+        course_id
+    }
+
+    fn edit_params() -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    fn ids(courses: Vec<Course>) -> Vec<String> {
+        let mut ids: Vec<String> = Vec::new(courses.env());
+        for course in courses.iter() {
+            ids.push_back(course.id);
+        }
+        ids
+    }
+
+    #[test]
+    fn test_list_courses_by_language_moves_between_buckets() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let english = String::from_str(&env, "en");
+        let spanish = String::from_str(&env, "es");
+
+        let first_id = create_course(&client, &creator, "First", "en");
+        let second_id = create_course(&client, &creator, "Second", "en");
+        let third_id = create_course(&client, &creator, "Third", "es");
+
+        assert_eq!(
+            ids(client.list_courses_by_language(&english, &None, &None)),
+            vec![&env, first_id.clone(), second_id.clone()]
+        );
+        assert_eq!(
+            ids(client.list_courses_by_language(&spanish, &None, &None)),
+            vec![&env, third_id.clone()]
+        );
+
+        // Move the second course to Spanish
+        let mut params = edit_params();
+        params.new_language = Some(Some(spanish.clone()));
+        client.edit_course(&creator, &second_id, &params);
+
+        assert_eq!(
+            ids(client.list_courses_by_language(&english, &None, &None)),
+            vec![&env, first_id.clone()]
+        );
+        assert_eq!(
+            ids(client.list_courses_by_language(&spanish, &None, &None)),
+            vec![&env, third_id.clone(), second_id.clone()]
+        );
+
+        // Clearing the language removes the course from its bucket. `Some(None)`
+        // cannot be told apart from `None` once encoded, so edit in-contract.
+        let mut params = edit_params();
+        params.new_language = Some(None);
+        env.as_contract(&contract_id, || {
+            crate::functions::edit_course::edit_course(env.clone(), creator.clone(), third_id.clone(), params);
+        });
+
+        assert_eq!(
+            ids(client.list_courses_by_language(&spanish, &None, &None)),
+            vec![&env, second_id.clone()]
+        );
+
+        // Deleting the last English course drops the bucket entirely
+        client.delete_course(&creator, &first_id);
+        assert_eq!(client.list_courses_by_language(&english, &None, &None).len(), 0);
+        let bucket_exists: bool = env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .has(&DataKey::LanguageCourses(english.clone()))
+        });
+        assert!(!bucket_exists);
+    }
+
+    #[test]
+    fn test_list_courses_by_language_skips_unpublished() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        client.create_course(
+            &creator,
+            &String::from_str(&env, "Draft"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &Some(String::from_str(&env, "en")),
+            &None,
+            &None,
+            &None,
+        );
+
+        let courses = client.list_courses_by_language(&String::from_str(&env, "en"), &None, &None);
+        assert_eq!(courses.len(), 0);
+    }
+}
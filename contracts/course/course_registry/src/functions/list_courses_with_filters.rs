@@ -1,4 +1,6 @@
 use crate::error::{handle_error, Error};
+use crate::functions::course_level::normalize_course_level;
+use crate::functions::debug_mode::emit_perf_metric;
 use crate::functions::utils::u32_to_string;
 
 use crate::schema::{Course, CourseFilters, MAX_EMPTY_CHECKS};
@@ -40,6 +42,9 @@ pub fn list_courses_with_filters(
     let mut count: u32 = 0;
     let mut matched: u32 = 0;
     let mut empty_checks: u32 = 0;
+    // Tracked for the debug-mode `perfMetric` event
+    let mut storage_reads: u32 = 0;
+    let mut ids_scanned: u32 = 0;
 
     let offset_value: u32 = offset.unwrap_or(0);
     let limit_value: u32 = limit.unwrap_or(10); // Reduced default limit for budget
@@ -59,6 +64,8 @@ pub fn list_courses_with_filters(
         let course_id: String = u32_to_string(env, id as u32);
         let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
 
+        ids_scanned += 1;
+        storage_reads += 1;
         if !env.storage().persistent().has(&key) {
             empty_checks += 1;
             id += 1;
@@ -68,6 +75,7 @@ pub fn list_courses_with_filters(
         // Reset empty checks when we find a course
         empty_checks = 0;
 
+        storage_reads += 1;
         let course: Course = env.storage().persistent().get(&key).unwrap();
 
         // Skip archived or unpublished courses
@@ -82,6 +90,7 @@ pub fn list_courses_with_filters(
         // - Category filter
         // - Level filter
         // - Duration filter (min/max, only if course has duration)
+        // - Duration presence filter
         // - Text search filter (title and description)
         let passes_filters: bool = filters.min_price.map_or(true, |min| course.price >= min)
             && filters.max_price.map_or(true, |max| course.price <= max)
@@ -92,13 +101,16 @@ pub fn list_courses_with_filters(
             && filters
                 .level
                 .as_ref()
-                .map_or(true, |lvl| course.level.as_ref() == Some(lvl))
+                .map_or(true, |lvl| {
+                    course.level.is_some() && course.level == normalize_course_level(env, lvl)
+                })
             && filters.min_duration.map_or(true, |min| {
                 course.duration_hours.map_or(false, |d| d >= min)
             })
             && filters.max_duration.map_or(true, |max| {
                 course.duration_hours.map_or(false, |d| d <= max)
             })
+            && (filters.require_duration != Some(true) || course.duration_hours.is_some())
             && filters.search_text.as_ref().map_or(true, |search| {
                 // Text search in title and description
                 // Note: Case-sensitive search due to Soroban String limitations
@@ -123,6 +135,8 @@ pub fn list_courses_with_filters(
         id += 1;
     }
 
+    emit_perf_metric(env, symbol_short!("lstFilter"), storage_reads, ids_scanned);
+
     results
 }
 
@@ -148,6 +162,7 @@ mod test {
             level: None,
             min_duration: None,
             max_duration: None,
+            require_duration: None,
             search_text: None,
         };
 
@@ -200,6 +215,7 @@ mod test {
             level: None,
             min_duration: None,
             max_duration: None,
+            require_duration: None,
             search_text: None,
         };
 
@@ -238,6 +254,7 @@ mod test {
             level: None,
             min_duration: None,
             max_duration: None,
+            require_duration: None,
             search_text: None,
         };
 
@@ -275,6 +292,7 @@ mod test {
             level: None,
             min_duration: None,
             max_duration: None,
+            require_duration: None,
             search_text: None,
         };
 
@@ -340,6 +358,7 @@ mod test {
             level: None,
             min_duration: None,
             max_duration: None,
+            require_duration: None,
             search_text: Some(String::from_str(&env, "Rust Programming")),
         };
 
@@ -355,6 +374,7 @@ mod test {
             level: None,
             min_duration: None,
             max_duration: None,
+            require_duration: None,
             search_text: Some(String::from_str(&env, "Introduction to web development")),
         };
 
@@ -370,10 +390,85 @@ mod test {
             level: None,
             min_duration: None,
             max_duration: None,
+            require_duration: None,
             search_text: Some(String::from_str(&env, "Python")),
         };
 
         let none_results = client.list_courses_with_filters(&none_filters, &None, &None);
         assert_eq!(none_results.len(), 0);
     }
+
+    #[test]
+    fn test_require_duration_filter() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let timed = client.create_course(
+            &creator,
+            &String::from_str(&env, "Timed Course"),
+            &String::from_str(&env, "Description"),
+            &100,
+            &None,
+            &None,
+            &None,
+            &None,
+            &Some(10),
+        );
+        let untimed = client.create_course(
+            &creator,
+            &String::from_str(&env, "Untimed Course"),
+            &String::from_str(&env, "Description"),
+            &100,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        use crate::schema::EditCourseParams;
+        let publish_params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(true),
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(&creator, &timed.id, &publish_params);
+        client.edit_course(&creator, &untimed.id, &publish_params);
+
+        let mut filters = CourseFilters {
+            min_price: None,
+            max_price: None,
+            category: None,
+            level: None,
+            min_duration: None,
+            max_duration: None,
+            require_duration: Some(true),
+            search_text: None,
+        };
+        let results = client.list_courses_with_filters(&filters, &None, &None);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results.get(0).unwrap().id, timed.id);
+
+        filters.require_duration = Some(false);
+        assert_eq!(client.list_courses_with_filters(&filters, &None, &None).len(), 2);
+
+        filters.require_duration = None;
+        assert_eq!(client.list_courses_with_filters(&filters, &None, &None).len(), 2);
+
+        // A duration range already leaves out courses without a duration
+        filters.min_duration = Some(1);
+        let results = client.list_courses_with_filters(&filters, &None, &None);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results.get(0).unwrap().id, timed.id);
+    }
 }
@@ -4,26 +4,49 @@
 pub mod access_control;
 pub mod add_goal;
 pub mod add_module;
+pub mod append_module;
 pub mod archive_course;
 pub mod backup_recovery;
+pub mod can_manage_module;
+pub mod co_creators;
 pub mod contract_versioning;
 pub mod create_course;
 pub mod create_course_category;
+pub mod create_course_full;
 pub mod create_prerequisite;
+pub mod debug_mode;
+pub mod course_level;
+pub mod count_courses_by_category;
+pub mod courses_exist;
 pub mod course_rate_limit_utils;
+pub mod course_sale;
 pub mod delete_course;
+pub mod duration_validation;
 pub mod edit_course;
+pub mod edit_course_full;
 pub mod edit_goal;
 pub mod edit_prerequisite;
+pub mod estimate_course_storage;
 pub mod get_course;
 pub mod get_course_category;
+pub mod get_course_module_ids;
 pub mod get_courses_by_instructor;
+pub mod get_courses_by_level;
+pub mod get_goal_count;
+pub mod get_goals_by_instructor;
+pub mod get_module_at_position;
 pub mod get_prerequisites_by_course;
 pub mod is_course_creator;
+pub mod language_allowlist;
+pub mod list_all_courses;
 pub mod list_categories;
+pub mod list_courses_by_language;
 pub mod list_courses_with_filters;
 pub mod list_modules;
+pub mod purge_all_courses;
 pub mod remove_goal;
 pub mod remove_module;
 pub mod remove_prerequisite;
+pub mod strict_categories;
+pub mod transfer_all_courses;
 pub mod utils;
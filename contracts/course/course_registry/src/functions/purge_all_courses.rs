@@ -0,0 +1,413 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_staging};
+use crate::functions::count_courses_by_category::remove_from_category_index;
+use crate::functions::delete_course::notify_course_access;
+use crate::functions::get_courses_by_level::remove_from_level_index;
+use crate::functions::list_courses_by_language::remove_from_language_index;
+use crate::functions::utils::{to_lowercase, u32_to_string};
+use crate::schema::{Course, CourseGoal, CourseModule, DataKey, PurgeResult};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+const TITLE_KEY: Symbol = symbol_short!("title");
+const POSITION_KEY: Symbol = symbol_short!("pos");
+
+const PURGE_ALL_EVENT: Symbol = symbol_short!("purgeAll");
+
+/// Removes all course data from the registry (admin-only).
+///
+/// Only runs on deployments the owner has flagged as staging: every course
+/// is removed along with its title, level, language and category index
+/// entries, modules, module position markers, goals and prerequisites, and
+/// the configured course access contract is told about every removed course.
+/// Categories, admins and configuration are left untouched.
+///
+/// Courses are purged in pages by ascending ID: each call removes up to
+/// `limit` courses starting at `cursor` and returns the cursor of the next
+/// call. The course ID counter is reset by the call that purges the last page.
+///
+/// Modules are found through the per-course module index, so modules added
+/// before that index existed are not removed.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - admin: caller address (must be an admin).
+/// - limit: optional maximum number of courses to remove (default 10, max 100).
+/// - cursor: optional course ID to resume from, as returned by the previous call.
+///
+/// Returns:
+/// - PurgeResult: the number of courses removed and the next cursor.
+pub fn purge_all_courses(
+    env: &Env,
+    admin: Address,
+    limit: Option<u32>,
+    cursor: Option<u32>,
+) -> PurgeResult {
+    admin.require_auth();
+
+    if !is_admin(env, &admin) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    // Only wipe deployments explicitly flagged as staging
+    if !is_staging(env) {
+        handle_error(env, Error::InvalidAdminOperation)
+    }
+
+    let limit_value: u32 = limit.unwrap_or(10);
+    if limit_value == 0 || limit_value > 100 {
+        handle_error(env, Error::InvalidLimitValue)
+    }
+
+    let max_id: u128 = env.storage().persistent().get(&COURSE_KEY).unwrap_or(0);
+    let mut removed: u32 = 0;
+    let mut id: u128 = cursor.unwrap_or(1).max(1) as u128;
+
+    while id <= max_id && removed < limit_value {
+        let course_id: String = u32_to_string(env, id as u32);
+        let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+        id += 1;
+
+        // Deleted courses leave holes in the ID sequence
+        let course: Course = match env.storage().persistent().get(&key) {
+            Some(course) => course,
+            None => continue,
+        };
+
+        purge_course_modules(env, &course_id);
+        purge_course_goals(env, &course_id);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CoursePrerequisites(course_id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&(TITLE_KEY, to_lowercase(env, &course.title)));
+        env.storage().persistent().remove(&key);
+        if let Some(ref level) = course.level {
+            remove_from_level_index(env, level, &course_id);
+        }
+        if let Some(ref language) = course.language {
+            remove_from_language_index(env, language, &course_id);
+        }
+        if let Some(ref category) = course.category {
+            remove_from_category_index(env, category, &course_id);
+        }
+        notify_course_access(env, &course_id);
+
+        removed += 1;
+    }
+
+    let next_cursor: Option<u32> = if id <= max_id {
+        Some(id as u32)
+    } else {
+        // Every course is gone, so reset the course ID counter
+        env.storage().persistent().remove(&COURSE_KEY);
+        None
+    };
+
+    env.events().publish((PURGE_ALL_EVENT,), (admin, removed));
+
+    PurgeResult {
+        removed,
+        next_cursor,
+    }
+}
+
+fn purge_course_modules(env: &Env, course_id: &String) {
+    let modules_key: DataKey = DataKey::CourseModules(course_id.clone());
+    let module_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&modules_key)
+        .unwrap_or(Vec::new(env));
+
+    for module_id in module_ids.iter() {
+        let key: (Symbol, String) = (MODULE_KEY, module_id);
+        if let Some(module) = env.storage().persistent().get::<_, CourseModule>(&key) {
+            env.storage()
+                .persistent()
+                .remove(&(POSITION_KEY, course_id.clone(), module.position));
+        }
+        env.storage().persistent().remove(&key);
+    }
+
+    env.storage().persistent().remove(&modules_key);
+}
+
+fn purge_course_goals(env: &Env, course_id: &String) {
+    let list_key: DataKey = DataKey::CourseGoalList(course_id.clone());
+    let goals: Vec<CourseGoal> = env
+        .storage()
+        .persistent()
+        .get(&list_key)
+        .unwrap_or(Vec::new(env));
+
+    for goal in goals.iter() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CourseGoal(course_id.clone(), goal.goal_id));
+    }
+
+    env.storage().persistent().remove(&list_key);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{CourseGoal, CourseModule, DataKey, PurgeResult};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env, String};
+
+    // Mock UserManagement contract for testing
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    // Mock CourseAccess contract recording the deleted courses it is notified of
+    mod mock_course_access {
+        use soroban_sdk::{contract, contractimpl, symbol_short, Env, String, Vec};
+
+        #[contract]
+        pub struct CourseAccess;
+
+        #[contractimpl]
+        impl CourseAccess {
+            pub fn on_course_deleted(env: Env, course_id: String) -> u32 {
+                let mut deleted: Vec<String> = Self::deleted_courses(env.clone());
+                deleted.push_back(course_id);
+                env.storage().instance().set(&symbol_short!("deleted"), &deleted);
+                deleted.len()
+            }
+
+            pub fn deleted_courses(env: Env) -> Vec<String> {
+                env.storage()
+                    .instance()
+                    .get(&symbol_short!("deleted"))
+                    .unwrap_or(Vec::new(&env))
+            }
+        }
+    }
+
+    fn setup_test_env() -> (Env, Address, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+        client.set_staging_mode(&admin, &true);
+
+        (env, admin, client)
+    }
+
+    fn create_course(client: &CourseRegistryClient<'_>, creator: &Address, title: &str) -> String {
+        client
+            .create_course(
+                creator,
+                &String::from_str(&client.env, title),
+                &String::from_str(&client.env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .id
+    }
+
+    #[test]
+    fn test_purge_all_courses_removes_everything() {
+        let (env, admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+
+        let first_id = create_course(&client, &creator, "First");
+        let second_id = create_course(&client, &creator, "Second");
+
+        let module: CourseModule = client.add_module(
+            &creator,
+            &first_id,
+            &1,
+            &String::from_str(&env, "Intro"),
+        );
+        let goal: CourseGoal =
+            client.add_goal(&creator, &first_id, &String::from_str(&env, "Learn"));
+        client.add_prerequisite(&creator, &second_id, &vec![&env, first_id.clone()]);
+
+        let result: PurgeResult = client.purge_all_courses(&admin, &None, &None);
+        assert_eq!(
+            result,
+            PurgeResult {
+                removed: 2,
+                next_cursor: None,
+            }
+        );
+
+        env.as_contract(&client.address, || {
+            let storage = env.storage().persistent();
+            for course_id in [&first_id, &second_id] {
+                assert!(!storage.has(&(symbol_short!("course"), course_id.clone())));
+                assert!(!storage.has(&DataKey::CoursePrerequisites(course_id.clone())));
+                assert!(!storage.has(&DataKey::CourseModules(course_id.clone())));
+                assert!(!storage.has(&DataKey::CourseGoalList(course_id.clone())));
+            }
+            assert!(!storage.has(&(symbol_short!("module"), module.id.clone())));
+            assert!(!storage.has(&(symbol_short!("pos"), first_id.clone(), 1u32)));
+            assert!(!storage.has(&DataKey::CourseGoal(first_id.clone(), goal.goal_id.clone())));
+            assert!(!storage.has(&(symbol_short!("title"), String::from_str(&env, "first"))));
+            assert!(!storage.has(&symbol_short!("course")));
+        });
+
+        // The ID counter restarts and titles can be reused
+        let recreated_id = create_course(&client, &Address::generate(&env), "First");
+        assert_eq!(recreated_id, String::from_str(&env, "1"));
+    }
+
+    #[test]
+    fn test_purge_all_courses_empty() {
+        let (_env, admin, client) = setup_test_env();
+
+        assert_eq!(
+            client.purge_all_courses(&admin, &None, &None),
+            PurgeResult {
+                removed: 0,
+                next_cursor: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_purge_all_courses_in_pages() {
+        let (env, admin, client) = setup_test_env();
+        let first_creator = Address::generate(&env);
+        let second_creator = Address::generate(&env);
+
+        let first_id = create_course(&client, &first_creator, "First");
+        let second_id = create_course(&client, &first_creator, "Second");
+        let third_id = create_course(&client, &second_creator, "Third");
+        client.delete_course(&first_creator, &second_id);
+
+        // The hole left by the deleted course is skipped
+        let page: PurgeResult = client.purge_all_courses(&admin, &Some(1), &None);
+        assert_eq!(page.removed, 1);
+        assert_eq!(page.next_cursor, Some(2));
+        assert!(client.try_get_course(&first_id).is_err());
+        assert_eq!(client.get_course(&third_id).id, third_id);
+
+        // The counter is kept until the last page so IDs are not reused early
+        env.as_contract(&client.address, || {
+            assert!(env.storage().persistent().has(&symbol_short!("course")));
+        });
+
+        let page: PurgeResult = client.purge_all_courses(&admin, &Some(1), &page.next_cursor);
+        assert_eq!(
+            page,
+            PurgeResult {
+                removed: 1,
+                next_cursor: None,
+            }
+        );
+        assert!(client.try_get_course(&third_id).is_err());
+        env.as_contract(&client.address, || {
+            assert!(!env.storage().persistent().has(&symbol_short!("course")));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #46)")]
+    fn test_purge_all_courses_rejects_invalid_limit() {
+        let (_env, admin, client) = setup_test_env();
+
+        client.purge_all_courses(&admin, &Some(101), &None);
+    }
+
+    #[test]
+    fn test_purge_all_courses_notifies_course_access() {
+        let (env, admin, client) = setup_test_env();
+        let access_id: Address = env.register(mock_course_access::CourseAccess, ());
+        let access = mock_course_access::CourseAccessClient::new(&env, &access_id);
+        client.set_course_access_contract(&client.get_admin(), &Some(access_id));
+
+        let creator = Address::generate(&env);
+        let first_id = create_course(&client, &creator, "First");
+        let second_id = create_course(&client, &creator, "Second");
+
+        client.purge_all_courses(&admin, &None, &None);
+
+        assert_eq!(access.deleted_courses(), vec![&env, first_id, second_id]);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #403)")]
+    fn test_purge_all_courses_refused_unless_staging() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+        create_course(&client, &Address::generate(&env), "First");
+
+        // Deployments are not staging unless the owner says so
+        assert!(!client.is_staging());
+        client.purge_all_courses(&admin, &None, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #403)")]
+    fn test_purge_all_courses_refused_after_staging_disabled() {
+        let (env, admin, client) = setup_test_env();
+        create_course(&client, &Address::generate(&env), "First");
+
+        client.set_staging_mode(&admin, &false);
+        assert!(!client.is_staging());
+
+        client.purge_all_courses(&admin, &None, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_staging_mode_requires_owner() {
+        let (env, _admin, client) = setup_test_env();
+
+        client.set_staging_mode(&Address::generate(&env), &true);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_purge_all_courses_requires_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        // No user management contract configured, so nobody is an admin
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.purge_all_courses(&Address::generate(&env), &None, &None);
+    }
+}
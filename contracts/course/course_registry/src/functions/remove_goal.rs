@@ -1,9 +1,10 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
 use crate::error::{handle_error, Error};
+use crate::functions::get_goal_count::get_goal_count;
 use crate::schema::{Course, CourseGoal, DataKey};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
@@ -51,10 +52,26 @@ pub fn remove_goal(env: Env, caller: Address, course_id: String, goal_id: String
     // Remove the goal from storage
     env.storage().persistent().remove(&goal_storage_key);
 
-    // Emits an event for successful goal removal.
+    // Keep the course's goal list in sync
+    let list_key: DataKey = DataKey::CourseGoalList(course_id.clone());
+    if let Some(mut goals) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<CourseGoal>>(&list_key)
+    {
+        for (i, listed) in goals.iter().enumerate() {
+            if listed.goal_id == goal_id {
+                goals.remove(i as u32);
+                env.storage().persistent().set(&list_key, &goals);
+                break;
+            }
+        }
+    }
+
+    // Emits an event for successful goal removal, with the new goal count.
     env.events().publish(
         (GOAL_REMOVED_EVENT, course_id.clone(), goal_id.clone()),
-        goal.content.clone(),
+        (goal.content.clone(), get_goal_count(&env, &course_id)),
     );
 }
 
@@ -1,10 +1,10 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
 
 use crate::error::{handle_error, Error};
-use crate::schema::CourseModule;
+use crate::schema::{CourseModule, DataKey};
 
 const MODULE_KEY: Symbol = symbol_short!("module");
 
@@ -22,15 +22,29 @@ pub fn remove_module(env: &Env, module_id: String) -> Result<(), &'static str> {
         .get(&(MODULE_KEY, module_id.clone()));
 
     // Validate that the module exists and is a valid CourseModule
-    if module.is_none() {
-        handle_error(env, Error::ModuleNotFound)
-    }
+    let module: CourseModule = match module {
+        Some(module) => module,
+        None => handle_error(env, Error::ModuleNotFound),
+    };
 
     // Delete the CourseModule directly from persistent storage using its key.
     env.storage()
         .persistent()
         .remove(&(MODULE_KEY, module_id.clone()));
 
+    // Drop the module from the course's module index
+    let modules_key: DataKey = DataKey::CourseModules(module.course_id);
+    if let Some(mut module_ids) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<String>>(&modules_key)
+    {
+        if let Some(index) = module_ids.first_index_of(&module_id) {
+            module_ids.remove(index);
+            env.storage().persistent().set(&modules_key, &module_ids);
+        }
+    }
+
     // Emits an event to indicate the module has been removed.
     env.events().publish((REMOVE_MODULE_EVENT,), module_id);
 
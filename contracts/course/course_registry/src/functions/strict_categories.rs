@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::functions::create_course_category::find_category_by_name;
+use crate::schema::DataKey;
+
+const STRICT_CATEGORIES_EVENT: Symbol = symbol_short!("strictCat");
+
+/// Enable or disable strict categories. Only admins can toggle it.
+///
+/// While enabled, courses can only be created with or moved to a category
+/// that matches an existing `CourseCategory` by name.
+pub fn set_strict_categories(env: &Env, admin: Address, enabled: bool) {
+    admin.require_auth();
+
+    if !is_admin(env, &admin) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::StrictCategories, &enabled);
+
+    env.events().publish((STRICT_CATEGORIES_EVENT,), (admin, enabled));
+}
+
+/// Check whether strict categories are enabled (`false` if never set)
+pub fn is_strict_categories(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StrictCategories)
+        .unwrap_or(false)
+}
+
+/// Reject a course category that does not name an existing category.
+///
+/// Does nothing unless strict categories are enabled. Names are matched
+/// the same way `create_course_category` detects duplicates, ignoring case
+/// and surrounding whitespace. Fails with `InvalidCategoryName` if no
+/// category matches.
+pub fn require_known_category(env: &Env, category: &String) {
+    if !is_strict_categories(env) {
+        return;
+    }
+
+    if find_category_by_name(env, category).is_none() {
+        handle_error(env, Error::InvalidCategoryName)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{DataKey, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup_test_env() -> (Env, Address, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Admins, &vec![&env, admin.clone()]);
+        });
+
+        (env, admin, client)
+    }
+
+    fn create_with_category(
+        env: &Env,
+        client: &CourseRegistryClient,
+        creator: &Address,
+        title: &str,
+        category: &str,
+    ) -> Result<crate::schema::Course, soroban_sdk::Error> {
+        client
+            .try_create_course(
+                creator,
+                &String::from_str(env, title),
+                &String::from_str(env, "Description"),
+                &1000,
+                &Some(String::from_str(env, category)),
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .map(|course| course.unwrap())
+            .map_err(|err| err.unwrap())
+    }
+
+    fn category_edit(env: &Env, category: &str) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: Some(Some(String::from_str(env, category))),
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_strict_categories_disabled_by_default() {
+        let (env, _admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+
+        assert!(!client.is_strict_categories());
+
+        let course = create_with_category(&env, &client, &creator, "Free Tag", "anything").unwrap();
+        assert_eq!(course.category, Some(String::from_str(&env, "anything")));
+    }
+
+    #[test]
+    fn test_strict_categories_rejects_unknown_category() {
+        let (env, admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+
+        client.set_strict_categories(&admin, &true);
+        assert!(client.is_strict_categories());
+
+        let result = create_with_category(&env, &client, &creator, "Unknown", "Nonexistent");
+        assert_eq!(result.unwrap_err(), soroban_sdk::Error::from_contract_error(27));
+    }
+
+    #[test]
+    fn test_strict_categories_accepts_known_category() {
+        let (env, admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+
+        client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+        client.set_strict_categories(&admin, &true);
+
+        // Matching ignores case and surrounding whitespace
+        let course = create_with_category(&env, &client, &creator, "Rust", " programming ").unwrap();
+        assert_eq!(course.category, Some(String::from_str(&env, " programming ")));
+    }
+
+    #[test]
+    fn test_strict_categories_applies_to_edit_course() {
+        let (env, admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+
+        client.create_course_category(&admin, &String::from_str(&env, "Design"), &None);
+        let course = create_with_category(&env, &client, &creator, "Figma", "legacy tag").unwrap();
+        client.set_strict_categories(&admin, &true);
+
+        let result = client.try_edit_course(&creator, &course.id, &category_edit(&env, "Nonexistent"));
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            soroban_sdk::Error::from_contract_error(27)
+        );
+
+        let edited = client.edit_course(&creator, &course.id, &category_edit(&env, "Design"));
+        assert_eq!(edited.category, Some(String::from_str(&env, "Design")));
+    }
+
+    #[test]
+    fn test_set_strict_categories_requires_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        // Without a user management contract nobody is an admin
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let stranger = Address::generate(&env);
+
+        let result = client.try_set_strict_categories(&stranger, &true);
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            soroban_sdk::Error::from_contract_error(6)
+        );
+        assert!(!client.is_strict_categories());
+    }
+}
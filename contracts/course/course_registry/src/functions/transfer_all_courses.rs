@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::functions::utils::u32_to_string;
+use crate::schema::{Course, MAX_LOOP_GUARD};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const COURSE_ID: Symbol = symbol_short!("course");
+
+const TRANSFER_ALL_COURSES_EVENT: Symbol = symbol_short!("trfAllCrs");
+
+/// Reassign every course of `current_creator` to `new_creator`.
+///
+/// Archived courses move too. If `new_creator` was a co-creator of a course,
+/// they are dropped from its co-creators. The caller must be
+/// `current_creator` or an admin.
+///
+/// Returns the number of courses transferred.
+pub fn transfer_all_courses(
+    env: &Env,
+    caller: Address,
+    current_creator: Address,
+    new_creator: Address,
+) -> u32 {
+    caller.require_auth();
+
+    if caller != current_creator && !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if current_creator == new_creator {
+        return 0;
+    }
+
+    // Walk every ID ever issued, so deleted courses don't end the scan early
+    let last_id: u128 = env.storage().persistent().get(&COURSE_ID).unwrap_or(0);
+    let last_id: u32 = last_id.min(MAX_LOOP_GUARD as u128) as u32;
+    let now: u64 = env.ledger().timestamp();
+
+    let mut moved: u32 = 0;
+    for id in 1..=last_id {
+        let key: (Symbol, String) = (COURSE_KEY, u32_to_string(env, id));
+        let mut course: Course = match env.storage().persistent().get(&key) {
+            Some(course) => course,
+            None => continue,
+        };
+        if course.creator != current_creator {
+            continue;
+        }
+
+        course.creator = new_creator.clone();
+        if let Some(index) = course.co_creators.first_index_of(&new_creator) {
+            course.co_creators.remove(index);
+        }
+        course.updated_at = now;
+        env.storage().persistent().set(&key, &course);
+        moved += 1;
+    }
+
+    env.events().publish(
+        (TRANSFER_ALL_COURSES_EVENT, current_creator, new_creator),
+        (moved, caller),
+    );
+
+    moved
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use crate::schema::DEFAULT_COURSE_RATE_LIMIT_WINDOW;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, String};
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, creator: &Address, title: &str) -> String {
+        // Stay clear of the course creation rate limit
+        env.ledger()
+            .with_mut(|li| li.timestamp += DEFAULT_COURSE_RATE_LIMIT_WINDOW + 1);
+        client
+            .create_course(
+                creator,
+                &String::from_str(env, title),
+                &String::from_str(env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .id
+    }
+
+    #[test]
+    fn test_transfer_all_courses_moves_every_course() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let leaving: Address = Address::generate(&env);
+        let successor: Address = Address::generate(&env);
+        let other: Address = Address::generate(&env);
+
+        let first = create_course(&env, &client, &leaving, "First");
+        let kept = create_course(&env, &client, &other, "Other");
+        let second = create_course(&env, &client, &leaving, "Second");
+        let deleted = create_course(&env, &client, &leaving, "Deleted");
+        let third = create_course(&env, &client, &leaving, "Third");
+        client.delete_course(&leaving, &deleted);
+
+        let moved: u32 = client.transfer_all_courses(&leaving, &leaving, &successor);
+        assert_eq!(moved, 3);
+
+        assert_eq!(client.get_courses_by_instructor(&leaving).len(), 0);
+        for course_id in [first, second, third] {
+            assert_eq!(client.get_course(&course_id).creator, successor);
+            assert!(client.is_course_creator(&course_id, &successor));
+        }
+        assert_eq!(client.get_course(&kept).creator, other);
+
+        // The new owner controls the courses
+        let course_id = client.get_courses_by_instructor(&successor).get(0).unwrap().id;
+        client.add_co_creator(&successor, &course_id, &other);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_transfer_all_courses_by_other_user_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        // Without a user management contract there are no admins
+        let leaving: Address = Address::generate(&env);
+        create_course(&env, &client, &leaving, "First");
+
+        let outsider: Address = Address::generate(&env);
+        client.transfer_all_courses(&outsider, &leaving, &outsider);
+    }
+}
@@ -164,6 +164,12 @@ mod tests {
 
             duration_hours: Some(1),
             level: Some(String::from_str(env, "entry")),
+            created_at: 0,
+            updated_at: 0,
+            tags: Vec::new(env),
+            co_creators: Vec::new(env),
+            sale_price: None,
+            sale_ends_at: None,
         }
     }
 
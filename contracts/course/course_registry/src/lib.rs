@@ -4,7 +4,7 @@
 #![no_std]
 
 /// Contract version for tracking deployments and upgrades
-pub const VERSION: &str = "1.0.0";
+pub const VERSION: &str = "1.3.0";
 
 pub mod error;
 pub mod functions;
@@ -14,7 +14,8 @@ pub mod schema;
 mod test;
 
 use crate::schema::{
-    Course, CourseCategory, CourseFilters, CourseGoal, CourseLevel, CourseModule, EditCourseParams,
+    Course, CourseCategory, CourseFilters, CourseId, CourseGoal, CourseLevel, CourseModule,
+    CreateCourseParams, DurationValidationMode, EditCourseParams, PurgeResult,
 };
 use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
 
@@ -43,7 +44,7 @@ impl CourseRegistry {
     /// * `category` - Optional course category
     /// * `language` - Optional course language
     /// * `thumbnail_url` - Optional URL for the course thumbnail image
-    /// * `level` - Optional course difficulty level
+    /// * `level` - Optional course difficulty level ("Beginner", "Intermediate" or "Advanced")
     /// * `duration_hours` - Optional estimated duration in hours
     ///
     /// # Returns
@@ -55,6 +56,7 @@ impl CourseRegistry {
     /// * If title or description are empty
     /// * If creator address is invalid
     /// * If price exceeds maximum allowed value
+    /// * If the level is not one of the supported levels
     ///
     /// # Examples
     ///
@@ -68,7 +70,7 @@ impl CourseRegistry {
     ///     Some("Programming".try_into().unwrap()),
     ///     Some("en".try_into().unwrap()),
     ///     Some("https://example.com/thumb.jpg".try_into().unwrap()),
-    ///     Some(CourseLevel::Beginner),
+    ///     Some("beginner".try_into().unwrap()), // stored as "Beginner"
     ///     Some(40)
     /// );
     /// ```
@@ -105,6 +107,45 @@ impl CourseRegistry {
         )
     }
 
+    /// Create a course together with its first modules and goals.
+    ///
+    /// Creates the course like `create_course`, then adds each module like
+    /// `add_module` and each goal like `add_goal`. Every module and goal is
+    /// validated before anything is written; if any check fails the whole
+    /// call is rejected and nothing is stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `params` - The fields of the new course
+    /// * `modules` - The `(position, title)` of each module to add
+    /// * `goals` - The content of each goal to add
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `Course` object.
+    ///
+    /// # Panics
+    ///
+    /// * If any `create_course` check fails
+    /// * If a module title or position is invalid, or two modules share a position
+    /// * If a goal's content is empty or too long
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Empty lists**: Behaves like `create_course`
+    /// * **Rate limiting**: Counts as one course creation
+    pub fn create_course_full(
+        env: Env,
+        creator: Address,
+        params: CreateCourseParams,
+        modules: Vec<(u32, String)>,
+        goals: Vec<String>,
+    ) -> Course {
+        functions::create_course_full::create_course_full(env, creator, params, modules, goals)
+    }
+
     /// Create a new course category.
     ///
     /// This function creates a new category that can be used to classify courses.
@@ -151,6 +192,51 @@ impl CourseRegistry {
         functions::create_course_category::create_course_category(env, caller, name, description)
     }
 
+    /// Get or create a course category by name.
+    ///
+    /// Idempotent variant of `create_course_category` for ingestion pipelines
+    /// that want "ensure category exists" semantics instead of a duplicate error.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin
+    /// * `name` - The category name
+    /// * `description` - Optional description, used only if the category is created
+    ///
+    /// # Returns
+    ///
+    /// Returns the ID of the existing or newly created category.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    /// * If a new category's name is empty or too long
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let category_id = contract.ensure_course_category(
+    ///     env.clone(),
+    ///     admin_address,
+    ///     "Programming".try_into().unwrap(),
+    ///     None
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Normalized names**: " programming " matches an existing "Programming"
+    /// * **Existing categories**: Returned unchanged, the description is ignored
+    pub fn ensure_course_category(
+        env: Env,
+        caller: Address,
+        name: String,
+        description: Option<String>,
+    ) -> u128 {
+        functions::create_course_category::ensure_course_category(env, caller, name, description)
+    }
+
     /// Retrieve a course by its ID.
     ///
     /// This function fetches a course's complete information using its unique identifier.
@@ -219,6 +305,24 @@ impl CourseRegistry {
         functions::get_course_category::get_course_category(&env, category_id)
     }
 
+    /// Check whether a course category exists.
+    ///
+    /// A cheap probe that does not load the category. IDs outside the range
+    /// of assigned category IDs are answered without a storage read of the
+    /// category.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `category_id` - The unique identifier of the category
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the category exists.
+    pub fn category_exists(env: Env, category_id: u128) -> bool {
+        functions::get_course_category::category_exists(&env, category_id)
+    }
+
     /// Get all courses created by a specific instructor.
     ///
     /// This function retrieves all courses that were created by the specified instructor.
@@ -252,6 +356,199 @@ impl CourseRegistry {
         functions::get_courses_by_instructor::get_courses_by_instructor(&env, instructor)
     }
 
+    /// Transfer every course of an instructor to a new creator.
+    ///
+    /// Meant for instructor offboarding, instead of moving courses one by one.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The current creator or an admin
+    /// * `current_creator` - The instructor whose courses are transferred
+    /// * `new_creator` - The address that becomes creator of the courses
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of courses transferred.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither `current_creator` nor an admin
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let moved = contract.transfer_all_courses(env.clone(), admin_address, leaving_instructor, successor);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Archived courses**: Transferred as well
+    /// * **Co-creators**: A new creator who was a co-creator is removed from the co-creators
+    /// * **Same address**: Returns 0 without changing anything
+    pub fn transfer_all_courses(
+        env: Env,
+        caller: Address,
+        current_creator: Address,
+        new_creator: Address,
+    ) -> u32 {
+        functions::transfer_all_courses::transfer_all_courses(&env, caller, current_creator, new_creator)
+    }
+
+    /// Get the learning goals of all courses of an instructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `instructor` - The address of the instructor
+    ///
+    /// # Returns
+    ///
+    /// Returns the goals grouped by course, in course creation order, with
+    /// each course's goals in the order they were added. At most 200 goals
+    /// are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let goals = contract.get_goals_by_instructor(env.clone(), instructor_address);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Archived courses**: Their goals are left out
+    /// * **More than 200 goals**: The result is truncated
+    /// * **Unknown instructor**: Returns an empty vector
+    pub fn get_goals_by_instructor(env: Env, instructor: Address) -> Vec<CourseGoal> {
+        functions::get_goals_by_instructor::get_goals_by_instructor(&env, instructor)
+    }
+
+    /// Get the number of goals of a course.
+    ///
+    /// `add_goal` and `remove_goal` include the new count in their events.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the goal count, or 0 for unknown courses.
+    pub fn get_goal_count(env: Env, course_id: String) -> u32 {
+        functions::get_goal_count::get_goal_count(&env, &course_id)
+    }
+
+    /// Get published courses of a specific level.
+    ///
+    /// Uses a per-level index maintained on course creation, edit and
+    /// deletion, so only courses of the requested level are read.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `level` - The course level to query
+    /// * `limit` - Optional maximum number of courses to return (default 10, max 100)
+    /// * `offset` - Optional number of matching courses to skip
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of `Course` objects with the given level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let beginner_courses = contract.get_courses_by_level(
+    ///     env.clone(),
+    ///     "Beginner".try_into().unwrap(),
+    ///     Some(10),
+    ///     None
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unpublished or archived courses**: Excluded, as in `list_courses_with_filters`
+    /// * **Level changed**: The course moves to the new level's results
+    /// * **Level spelling**: Matched case-insensitively ("beginner" finds "Beginner")
+    /// * **Unknown level**: Panics with `InvalidCourseLevel`
+    pub fn get_courses_by_level(
+        env: Env,
+        level: CourseLevel,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Vec<Course> {
+        functions::get_courses_by_level::get_courses_by_level(&env, level, limit, offset)
+    }
+
+    /// List published courses in a given language.
+    ///
+    /// Reads the language index instead of scanning every course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `language` - The language to look up
+    /// * `limit` - Optional maximum number of courses to return (default 10, max 100)
+    /// * `offset` - Optional number of matching courses to skip
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of `Course` objects in the given language.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let spanish_courses = contract.list_courses_by_language(
+    ///     env.clone(),
+    ///     "es".try_into().unwrap(),
+    ///     Some(10),
+    ///     None
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unpublished or archived courses**: Excluded, as in `list_courses_with_filters`
+    /// * **Language changed or cleared**: The course moves to the new bucket or leaves the index
+    /// * **Allowlist configured**: The language is matched case-insensitively
+    pub fn list_courses_by_language(
+        env: Env,
+        language: String,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Vec<Course> {
+        functions::list_courses_by_language::list_courses_by_language(&env, language, limit, offset)
+    }
+
+    /// Count the published courses in a category.
+    ///
+    /// Reads the category index instead of loading every course, for
+    /// showing counts next to category names.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `category` - The category name, matched exactly
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of published, non-archived courses in the category.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let count = contract.count_courses_by_category(env.clone(), "Programming".try_into().unwrap());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Empty or unknown category**: Returns 0
+    /// * **Category changed or cleared**: The course moves to the new bucket or leaves the index
+    pub fn count_courses_by_category(env: Env, category: String) -> u32 {
+        functions::count_courses_by_category::count_courses_by_category(&env, category)
+    }
+
     /// Remove a module from a course.
     ///
     /// This function removes a specific module from its associated course.
@@ -305,7 +602,7 @@ impl CourseRegistry {
     ///
     /// * `env` - The Soroban environment
     /// * `course_id` - The unique identifier of the course to add the module to
-    /// * `position` - The position where the module should be inserted
+    /// * `position` - The 0-based position where the module should be inserted
     /// * `title` - The title of the new module
     ///
     /// # Returns
@@ -334,7 +631,8 @@ impl CourseRegistry {
     ///
     /// # Edge Cases
     ///
-    /// * **Invalid position**: Position must be valid for the course
+    /// * **Invalid position**: Position must be at most 10000 and not already taken
+    /// * **Position 0**: Positions are 0-based, so 0 is a valid position
     /// * **Empty title**: Module title cannot be empty
     /// * **Creator only**: Only course creator can add modules
     /// * **Auto-generated ID**: Module gets unique auto-generated ID
@@ -348,6 +646,49 @@ impl CourseRegistry {
         functions::add_module::course_registry_add_module(env, caller, course_id, position, title)
     }
 
+    /// Add a module after the last module of a course.
+    ///
+    /// The module is placed at the highest position used by the course's
+    /// modules plus one, or at position 0 if the course has no modules yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `title` - The title of the new module
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `CourseModule` object.
+    ///
+    /// # Panics
+    ///
+    /// Same as `add_module`.
+    pub fn append_module(env: Env, caller: Address, course_id: String, title: String) -> CourseModule {
+        functions::append_module::append_module(env, caller, course_id, title)
+    }
+
+    /// Get the module of a course at a given position.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `position` - The 0-based module position
+    ///
+    /// # Returns
+    ///
+    /// Returns the module at that position, or `None` if no module occupies it.
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Removed module**: Returns `None` once the module at the position is removed
+    /// * **Unknown course**: Returns `None`
+    pub fn get_module_at_position(env: Env, course_id: String, position: u32) -> Option<CourseModule> {
+        functions::get_module_at_position::get_module_at_position(&env, course_id, position)
+    }
+
     /// Delete a course from the registry.
     ///
     /// This function permanently removes a course from the registry.
@@ -377,7 +718,9 @@ impl CourseRegistry {
     /// * **Permission denied**: Only course creator can delete their courses
     /// * **Non-existent course**: Will panic if course doesn't exist
     /// * **Permanent deletion**: Course and all associated data are permanently removed
-    /// * **Enrolled students**: Consider impact on enrolled students before deletion
+    /// * **Enrolled students**: If a course access contract is configured with
+    ///   `set_course_access_contract`, its `on_course_deleted` hook is called
+    ///   so their access is revoked; the course is deleted even if the hook fails
     /// 
     /// Panics if the deletion fails or if the creator is not authorized.
     pub fn delete_course(env: Env, creator: Address, course_id: String) {
@@ -385,6 +728,43 @@ impl CourseRegistry {
             .unwrap_or_else(|e| panic!("{}", e))
     }
 
+    /// Delete several courses in a single call.
+    ///
+    /// Deletes every listed course that was created by `creator`. Courses
+    /// that don't exist or belong to someone else are skipped rather than
+    /// failing the whole batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_ids` - The identifiers of the courses to delete (at most 50)
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of courses deleted.
+    ///
+    /// # Panics
+    ///
+    /// * If more than 50 course IDs are passed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let deleted = contract.delete_courses_batch(env.clone(), course_creator_address, course_ids);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unowned courses**: Skipped and left untouched
+    /// * **Non-existent or duplicate IDs**: Skipped
+    /// * **Empty list**: Returns 0
+    /// * **Enrolled students**: The course access contract, if configured, is
+    ///   notified of each deleted course as in `delete_course`
+    pub fn delete_courses_batch(env: Env, creator: Address, course_ids: Vec<String>) -> u32 {
+        functions::delete_course::delete_courses_batch(&env, creator, course_ids)
+    }
+
     /// Simple hello world function for testing.
     ///
     /// This is a basic function that returns a greeting message,
@@ -653,6 +1033,33 @@ impl CourseRegistry {
         )
     }
 
+    /// Get every prerequisite of a course, including prerequisites of prerequisites.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the prerequisite courses nearest first, each with `count` set to
+    /// the number of prerequisite steps from `course_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let required = contract.get_prerequisite_closure(env.clone(), "advanced_rust".try_into().unwrap());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **No prerequisites or unknown course**: Returns an empty list
+    /// * **Shared prerequisites**: Listed once, at their shortest distance
+    /// * **Bounded**: At most 100 courses are returned
+    pub fn get_prerequisite_closure(env: Env, course_id: String) -> Vec<CourseId> {
+        functions::get_prerequisites_by_course::get_prerequisite_closure(&env, course_id)
+    }
+
     /// Edit the prerequisites for a course.
     ///
     /// This function replaces all existing prerequisites with a new set
@@ -710,7 +1117,7 @@ impl CourseRegistry {
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `creator` - The address of the course creator
+    /// * `creator` - The address of the course creator or a co-creator
     /// * `course_id` - The unique identifier of the course to edit
     /// * `params` - Parameters containing the fields to update
     ///
@@ -720,8 +1127,9 @@ impl CourseRegistry {
     ///
     /// # Panics
     ///
+    /// * If no field of `params` is set
     /// * If course doesn't exist
-    /// * If creator is not the course creator
+    /// * If creator is neither the course creator nor a co-creator
     /// * If any field validation fails
     ///
     /// # Examples
@@ -747,8 +1155,10 @@ impl CourseRegistry {
     ///
     /// * **Partial updates**: Only provided fields are updated
     /// * **Validation**: All fields must pass validation rules
-    /// * **Creator only**: Only course creator can edit course
+    /// * **Creator only**: Only the course creator and co-creators can edit the course
     /// * **Price limits**: Price must be within allowed bounds
+    /// * **Publishing**: Depending on the duration validation mode, publishing a
+    ///   course with `duration_hours` set but no modules warns or is rejected
     pub fn edit_course(
         env: Env,
         creator: Address,
@@ -758,10 +1168,212 @@ impl CourseRegistry {
         functions::edit_course::edit_course(env, creator, course_id, params)
     }
 
-    /// Archive a course.
+    /// Edit a course and replace its prerequisites atomically.
     ///
-    /// This function marks a course as archived, making it unavailable for new enrollments
-    /// while preserving existing data and access for current students.
+    /// Applies `params` like `edit_course` and, if `new_prerequisites` is
+    /// provided, replaces the prerequisites like `edit_prerequisite`. If any
+    /// validation fails the whole call is rejected and nothing is changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator or a co-creator
+    /// * `course_id` - The unique identifier of the course to edit
+    /// * `params` - Parameters containing the fields to update
+    /// * `new_prerequisites` - The new prerequisite list, or `None` to keep the current one
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course` object.
+    ///
+    /// # Panics
+    ///
+    /// * If any `edit_course` check fails
+    /// * If prerequisites are given and `creator` is not the course creator
+    /// * If a prerequisite doesn't exist, is duplicated or creates a cycle
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let updated_course = contract.edit_course_full(
+    ///     env.clone(),
+    ///     course_creator_address,
+    ///     "course_123".try_into().unwrap(),
+    ///     params,
+    ///     Some(prerequisites)
+    /// );
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Co-creators**: May edit fields but not replace prerequisites
+    /// * **Empty list**: `Some` of an empty list clears all prerequisites
+    pub fn edit_course_full(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        params: EditCourseParams,
+        new_prerequisites: Option<Vec<String>>,
+    ) -> Course {
+        functions::edit_course_full::edit_course_full(env, creator, course_id, params, new_prerequisites)
+    }
+
+    /// Configure the duration consistency check applied when publishing (admin only).
+    ///
+    /// A course that sets `duration_hours` but has no modules is likely
+    /// misdescribed. Depending on the mode, publishing such a course is
+    /// allowed silently (`Off`, the default), allowed with a `durWarn` event
+    /// (`Warn`), or rejected (`Reject`).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The address of the caller (must be an admin)
+    /// * `mode` - The validation mode to apply
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_duration_validation_mode(env.clone(), admin, DurationValidationMode::Reject);
+    /// ```
+    pub fn set_duration_validation_mode(env: Env, admin: Address, mode: DurationValidationMode) {
+        functions::duration_validation::set_duration_validation_mode(&env, admin, mode)
+    }
+
+    /// Enable or disable debug mode (admin only).
+    ///
+    /// While enabled, heavy read paths such as `list_courses_with_filters`
+    /// emit a `perfMetric` event whose second topic names the function and
+    /// whose data is `(storage_reads, ids_scanned)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The address of the caller (must be an admin)
+    /// * `enabled` - Whether to emit performance metric events
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_debug_mode(env.clone(), admin, true);
+    /// ```
+    pub fn set_debug_mode(env: Env, admin: Address, enabled: bool) {
+        functions::debug_mode::set_debug_mode(&env, admin, enabled)
+    }
+
+    /// Check whether debug mode is enabled.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - `true` if `perfMetric` events are emitted, `false` by default
+    pub fn is_debug_mode(env: Env) -> bool {
+        functions::debug_mode::is_debug_mode(&env)
+    }
+
+    /// Enable or disable strict categories (admin only).
+    ///
+    /// While enabled, `create_course` and `edit_course` reject a category
+    /// that does not match the name of an existing course category. Courses
+    /// keep free-form categories by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The address of the caller (must be an admin)
+    /// * `enabled` - Whether course categories must name an existing category
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_strict_categories(env.clone(), admin, true);
+    /// ```
+    pub fn set_strict_categories(env: Env, admin: Address, enabled: bool) {
+        functions::strict_categories::set_strict_categories(&env, admin, enabled)
+    }
+
+    /// Check whether strict categories are enabled.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - `true` if course categories must name an existing category, `false` by default
+    pub fn is_strict_categories(env: Env) -> bool {
+        functions::strict_categories::is_strict_categories(&env)
+    }
+
+    /// Restrict course languages to a list of ISO-639 codes (admin only).
+    ///
+    /// Once a non-empty allowlist is set, `create_course` and `edit_course`
+    /// reject languages that are not on it and store the lowercase code.
+    /// Setting an empty list makes languages unrestricted again.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The address of the caller (must be an admin)
+    /// * `languages` - Two- or three-letter ISO-639 codes, e.g. "en", "es", "fil"
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    /// * If a code is not two or three ASCII letters
+    /// * If more than 200 codes are passed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.set_language_allowlist(env.clone(), admin, vec![&env, "en".into(), "es".into()]);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Existing courses**: Not re-validated; only new values are checked
+    /// * **Duplicates and case**: Codes are lowercased and deduplicated
+    pub fn set_language_allowlist(env: Env, admin: Address, languages: Vec<String>) {
+        functions::language_allowlist::set_language_allowlist(&env, admin, languages)
+    }
+
+    /// Get the configured course language allowlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the allowed lowercase language codes; empty means unrestricted.
+    pub fn get_language_allowlist(env: Env) -> Vec<String> {
+        functions::language_allowlist::get_language_allowlist(&env)
+    }
+
+    /// Get the duration consistency check applied when publishing.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the configured `DurationValidationMode` (`Off` if never set).
+    pub fn get_duration_validation_mode(env: Env) -> DurationValidationMode {
+        functions::duration_validation::get_duration_validation_mode(&env)
+    }
+
+    /// Archive a course.
+    ///
+    /// This function marks a course as archived, making it unavailable for new enrollments
+    /// while preserving existing data and access for current students.
     ///
     /// # Arguments
     ///
@@ -813,7 +1425,7 @@ impl CourseRegistry {
     ///
     /// # Returns
     ///
-    /// Returns `true` if the user is the course creator, `false` otherwise.
+    /// Returns `true` if the user is the course creator or a co-creator, `false` otherwise.
     ///
     /// # Panics
     ///
@@ -843,6 +1455,254 @@ impl CourseRegistry {
         functions::is_course_creator::is_course_creator(env, course_id, user)
     }
 
+    /// Check whether a user can manage a specific module.
+    ///
+    /// Resolves the module's course and applies the same rules as course
+    /// management: the course creator, a co-creator or an admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the user to check
+    /// * `module_id` - The unique identifier of the module
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the user can manage the module, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let can_edit = contract.can_manage_module(env.clone(), user_address, "module_123".try_into().unwrap());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Non-existent module**: Returns `false` instead of panicking
+    pub fn can_manage_module(env: Env, caller: Address, module_id: String) -> bool {
+        functions::can_manage_module::can_manage_module(&env, caller, module_id)
+    }
+
+    /// Check whether several courses exist in a single call.
+    ///
+    /// Meant for cross-contract validation, where checking each course
+    /// separately would cost one contract call per course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_ids` - The identifiers of the courses to check (at most 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector aligned with `course_ids`, holding `true` at each
+    /// position whose course exists.
+    ///
+    /// # Panics
+    ///
+    /// * If more than 100 course IDs are passed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let exists = contract.courses_exist(env.clone(), course_ids);
+    /// if exists.iter().all(|e| e) {
+    ///     // Every course is valid
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Duplicate IDs**: Each position is answered independently
+    /// * **Empty list**: Returns an empty vector
+    pub fn courses_exist(env: Env, course_ids: Vec<String>) -> Vec<bool> {
+        functions::courses_exist::courses_exist(&env, course_ids)
+    }
+
+    /// Get the module IDs of a course.
+    ///
+    /// Used by other contracts, such as course_access for progress tracking,
+    /// to validate modules and count them.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the module IDs in creation order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let module_ids = contract.get_course_module_ids(env.clone(), "course_123".try_into().unwrap());
+    /// let total_modules = module_ids.len();
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Unknown course**: Returns an empty vector
+    /// * **Removed modules**: No longer listed
+    pub fn get_course_module_ids(env: Env, course_id: String) -> Vec<String> {
+        functions::get_course_module_ids::get_course_module_ids(&env, course_id)
+    }
+
+    /// Estimate the storage a course uses, in bytes.
+    ///
+    /// Helps operators budget storage rent and find bloated courses.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the summed serialized size of the course, its modules, goals
+    /// and prerequisites.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let bytes = contract.estimate_course_storage(env.clone(), "course_123".try_into().unwrap());
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Approximate**: Storage keys and ledger entry overhead are not counted
+    pub fn estimate_course_storage(env: Env, course_id: String) -> u32 {
+        functions::estimate_course_storage::estimate_course_storage(&env, course_id)
+    }
+
+    /// Add a co-creator to a course.
+    ///
+    /// Co-creators can edit and manage the course like its creator, but
+    /// only the creator can add or remove co-creators.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `co_creator` - The address to add as co-creator
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course`.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If `creator` is not the course creator
+    /// * If the course already has 10 co-creators
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.add_co_creator(env.clone(), creator_address, "course_123".try_into().unwrap(), co_author);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Existing co-creator or the creator**: Nothing changes
+    pub fn add_co_creator(env: Env, creator: Address, course_id: String, co_creator: Address) -> Course {
+        functions::co_creators::add_co_creator(&env, creator, course_id, co_creator)
+    }
+
+    /// Remove a co-creator from a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `co_creator` - The co-creator to remove
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course`.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If `creator` is not the course creator
+    /// * If `co_creator` is not a co-creator of the course
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// contract.remove_co_creator(env.clone(), creator_address, "course_123".try_into().unwrap(), co_author);
+    /// ```
+    pub fn remove_co_creator(env: Env, creator: Address, course_id: String, co_creator: Address) -> Course {
+        functions::co_creators::remove_co_creator(&env, creator, course_id, co_creator)
+    }
+
+    /// Put a course on sale or end its sale.
+    ///
+    /// While the sale runs, `get_effective_price` returns the sale price.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The course creator or a co-creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `sale_price` - The sale price, or `None` to end the sale
+    /// * `ends_at` - Ledger timestamp the sale ends at, or `None` for no end
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course`.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If `creator` is not the course creator or a co-creator
+    /// * If the sale price is zero or not below the regular price (`InvalidPrice`)
+    /// * If `ends_at` is not in the future (`InvalidPrice`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Half price for a week
+    /// contract.set_course_sale(env.clone(), creator_address, course_id, Some(2500), Some(now + 604_800));
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Regular price lowered below the sale price**: The lower regular price applies
+    pub fn set_course_sale(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        sale_price: Option<u128>,
+        ends_at: Option<u64>,
+    ) -> Course {
+        functions::course_sale::set_course_sale(&env, creator, course_id, sale_price, ends_at)
+    }
+
+    /// Get the price a course is currently sold at.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the sale price while a sale runs, otherwise the regular price.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    pub fn get_effective_price(env: Env, course_id: String) -> u128 {
+        functions::course_sale::get_effective_price(&env, course_id)
+    }
+
     /// List all available course categories.
     ///
     /// This function retrieves all course categories that have been created
@@ -919,6 +1779,8 @@ impl CourseRegistry {
     /// * **Large limits**: Limit should be reasonable to avoid gas issues
     /// * **Public access**: Anyone can list courses
     /// * **Archived courses**: May or may not be included based on filter settings
+    /// * **No duration**: Courses without a duration never match `min_duration` or
+    ///   `max_duration`; set `require_duration` to `Some(true)` to exclude them without a range
     pub fn list_courses_with_filters(
         env: Env,
         filters: CourseFilters,
@@ -930,6 +1792,235 @@ impl CourseRegistry {
         )
     }
 
+    /// List every course in the registry (admin only).
+    ///
+    /// This function walks the entire catalog for audit purposes. Unlike the
+    /// public listing functions, it applies no visibility filtering, so drafts
+    /// and archived courses are included.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The address of the admin performing the audit
+    /// * `limit` - Optional maximum number of courses to return (default 10, max 100)
+    /// * `offset` - Optional number of courses to skip for pagination
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of `Course` objects ordered by course ID.
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not an admin
+    /// * If limit is zero or exceeds 100
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Fetch the second page of 20 courses
+    /// let courses = contract.list_all_courses(env.clone(), admin_address, Some(20), Some(20));
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Deleted courses**: Holes in the ID sequence are skipped
+    /// * **Archived and unpublished courses**: Always included
+    /// * **Offset past the end**: Returns an empty vector
+    pub fn list_all_courses(
+        env: Env,
+        admin: Address,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Vec<Course> {
+        functions::list_all_courses::list_all_courses(&env, admin, limit, offset)
+    }
+
+    /// Remove all course data from the registry (admin only).
+    ///
+    /// Wipes every course together with its modules, goals, prerequisites and
+    /// index entries, and resets the course ID counter. If a course access
+    /// contract is configured, its `on_course_deleted` hook is called for
+    /// every removed course. Only runs once the owner has flagged the
+    /// deployment as staging with `set_staging_mode`.
+    ///
+    /// Courses are removed in pages: call again with the returned
+    /// `next_cursor` until it is `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The address of the caller (must be an admin)
+    /// * `limit` - Optional maximum number of courses to remove (default 10, max 100)
+    /// * `cursor` - Optional course ID to resume from, as returned by the previous call
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of courses removed and the cursor for the next call.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    /// * If the contract is not flagged as staging
+    /// * If limit is zero or exceeds 100
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut cursor = None;
+    /// loop {
+    ///     let page = contract.purge_all_courses(env.clone(), admin_address.clone(), Some(50), cursor);
+    ///     cursor = page.next_cursor;
+    ///     if cursor.is_none() {
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Empty registry**: Returns 0 removed and no cursor
+    /// * **Course ID counter**: Only reset by the call that purges the last page
+    /// * **Failing course access contract**: Courses are still removed
+    /// * **Categories and configuration**: Left untouched
+    /// * **Modules added before the per-course module index**: Not found, so
+    ///   not removed
+    pub fn purge_all_courses(
+        env: Env,
+        admin: Address,
+        limit: Option<u32>,
+        cursor: Option<u32>,
+    ) -> PurgeResult {
+        functions::purge_all_courses::purge_all_courses(&env, admin, limit, cursor)
+    }
+
+    /// Rewrite every stored course level to its canonical spelling (admin only).
+    ///
+    /// Levels stored before validation existed may be free-form. Known
+    /// spellings and common synonyms ("entry", "basic", "expert", ...) are
+    /// mapped to "Beginner", "Intermediate" or "Advanced"; anything else is
+    /// cleared. The level index is updated to match.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The address of the caller (must be an admin)
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of courses whose level changed.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let migrated = contract.migrate_course_levels(env.clone(), admin_address);
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Already canonical**: Courses are left untouched and not counted
+    /// * **Repeated runs**: Return 0 once every level is canonical
+    pub fn migrate_course_levels(env: Env, admin: Address) -> u32 {
+        functions::course_level::migrate_course_levels(&env, &admin)
+    }
+
+    /// Flag or unflag this deployment as staging (owner only).
+    ///
+    /// Destructive maintenance operations such as `purge_all_courses` only
+    /// run while the flag is set. Deployments start unflagged.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the caller (must be the contract owner)
+    /// * `enabled` - Whether the deployment is staging
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the contract owner
+    /// * If the contract is not initialized
+    pub fn set_staging_mode(env: Env, caller: Address, enabled: bool) {
+        functions::access_control::set_staging_mode(&env, &caller, enabled)
+    }
+
+    /// Check whether this deployment is flagged as staging.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if `set_staging_mode` last set the flag.
+    pub fn is_staging(env: Env) -> bool {
+        functions::access_control::is_staging(&env)
+    }
+
+    /// Set the course access contract notified when a course is deleted (owner only).
+    ///
+    /// Once set, `delete_course` and `delete_courses_batch` call the
+    /// contract's `on_course_deleted` hook for every deleted course so its
+    /// enrollments are revoked. The hook is best effort: if it fails, the
+    /// course is still deleted. Pass `None` to stop the notifications.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the caller (must be the contract owner)
+    /// * `course_access` - The course access contract address, or `None`
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the contract owner
+    /// * If the contract is not initialized
+    pub fn set_course_access_contract(env: Env, caller: Address, course_access: Option<Address>) {
+        functions::access_control::set_course_access_addr(&env, &caller, &course_access)
+    }
+
+    /// Get the course access contract notified when a course is deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the configured address, or `None` if deletions are not forwarded.
+    pub fn get_course_access_contract(env: Env) -> Option<Address> {
+        functions::access_control::get_course_access_addr(&env)
+    }
+
+    /// Get the contract owner.
+    ///
+    /// Returns the address set as owner when access control was initialized.
+    /// The owner manages the user management address, the staging flag and
+    /// the course access contract; admins are resolved through the user
+    /// management contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the owner's address.
+    ///
+    /// # Panics
+    ///
+    /// * If the contract is not initialized (`NotInitialized`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let owner = contract.get_admin(env.clone());
+    /// ```
+    pub fn get_admin(env: Env) -> Address {
+        functions::access_control::get_owner(&env)
+    }
+
     /// Export all course data for backup purposes (admin only)
     ///
     /// This function exports all course data including courses, categories,
@@ -940,7 +2031,8 @@ impl CourseRegistry {
     /// * `caller` - Address performing the export (must be admin)
     ///
     /// # Returns
-    /// * `CourseBackupData` - Complete backup data structure
+    /// * `CourseBackupData` - Complete backup data structure, stamped with the
+    ///   exporting contract version and export timestamp
     ///
     /// # Panics
     /// * If caller is not an admin
@@ -964,11 +2056,46 @@ impl CourseRegistry {
     /// # Panics
     /// * If caller is not an admin
     /// * If backup data is invalid
+    /// * If the backup was exported by an incompatible contract version
+    /// * If the backup checksum does not match its contents
+    /// * If `backup_data` is a chunk rather than a whole backup
     /// * If import operation fails
     pub fn import_course_data(env: Env, caller: Address, backup_data: crate::schema::CourseBackupData) -> u32 {
         functions::backup_recovery::import_course_data(env, caller, backup_data)
     }
 
+    /// Import one chunk of course data from backup (admin only)
+    ///
+    /// Lets large backups be restored across several transactions without
+    /// exceeding the budget. Split the exported backup with
+    /// `backup_recovery::split_backup` and pass the chunks in order, each
+    /// with the token returned by the previous call. Each chunk holds at
+    /// most `IMPORT_CHUNK_SIZE` courses, categories and modules and is
+    /// verified against its own checksum. The import progress is stored, so
+    /// chunks cannot be replayed, skipped, reordered or mixed across backups.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address performing the import (must be admin)
+    /// * `chunk` - Part of a backup to import
+    /// * `continuation_token` - Token returned by the previous call, or `None` for the first chunk
+    ///
+    /// # Returns
+    /// * `u32` - Token for the next call
+    ///
+    /// # Panics
+    /// * Same conditions as `import_course_data`, checked against the chunk
+    /// * If the chunk holds more than `IMPORT_CHUNK_SIZE` entries in any map
+    /// * If the chunk or token does not match the import in progress
+    pub fn import_course_data_chunk(
+        env: Env,
+        caller: Address,
+        chunk: crate::schema::CourseBackupData,
+        continuation_token: Option<u32>,
+    ) -> u32 {
+        functions::backup_recovery::import_course_data_chunk(env, caller, chunk, continuation_token)
+    }
+
     /// Get the current contract version
     ///
     /// Returns the semantic version of the current contract deployment.
@@ -1013,11 +2140,33 @@ impl CourseRegistry {
         functions::contract_versioning::is_version_compatible(&env, from_version, to_version)
     }
 
+    /// Check compatibility of several version pairs at once
+    ///
+    /// Applies `is_version_compatible` to each `(from_version, to_version)`
+    /// pair, for tooling that upgrades many contracts in one go.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `pairs` - Up to 50 `(from_version, to_version)` pairs
+    ///
+    /// # Returns
+    /// * `Vec<bool>` - One result per pair, in the same order; malformed versions map to false
+    ///
+    /// # Panics
+    /// * If more than 50 pairs are passed
+    pub fn check_compatibility_batch(env: Env, pairs: Vec<(String, String)>) -> Vec<bool> {
+        functions::contract_versioning::check_compatibility_batch(&env, pairs)
+    }
+
     /// Migrate course data between contract versions
     ///
-    /// Performs data migration from one contract version to another.
-    /// This function handles the transformation of course data structures
-    /// when upgrading contract versions.
+    /// Performs data migration from one contract version to another by
+    /// running each course layout step in between: 1.0.0 to 1.1.0 adds
+    /// `created_at`/`updated_at` (set to the migration time) and `tags`,
+    /// 1.1.0 to 1.2.0 adds `co_creators` and 1.2.0 to 1.3.0 adds
+    /// `sale_price`/`sale_ends_at`. Admins migrate every course; creators
+    /// only migrate their own. Already migrated courses are skipped, so the
+    /// migration can be rerun.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
@@ -1026,14 +2175,56 @@ impl CourseRegistry {
     /// * `to_version` - The target version to migrate to
     ///
     /// # Returns
-    /// * `bool` - True if migration was successful, false otherwise
+    /// * `bool` - True if migration was successful, false otherwise. Also
+    ///   false, with nothing changed, if the data is already at `to_version`
+    ///   or later or if either version is not a known course layout
     ///
     /// # Events
-    /// Emits a migration event upon successful completion
+    /// Emits a `crsMigr` event with the versions, the number of courses
+    /// migrated and whether the migration succeeded
     pub fn migrate_course_data(env: Env, caller: Address, from_version: String, to_version: String) -> bool {
         functions::contract_versioning::migrate_course_data(&env, caller, from_version, to_version)
     }
 
+    /// Roll the stored data version back after a failed migration
+    ///
+    /// Resets the data version marker so a corrected migration can run
+    /// again. Only steps backward, to 1.0.0 or a version from the history.
+    /// Course data is not rewritten; migrations skip courses already in the
+    /// current layout.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `admin` - The address performing the rollback (must be admin)
+    /// * `to_version` - The earlier version to return to
+    ///
+    /// # Returns
+    /// * `bool` - True if the data version was reset, false (with the reason
+    ///   in the migration status) if `to_version` is not an older known version
+    ///
+    /// # Panics
+    /// * If the caller is not an admin
+    ///
+    /// # Events
+    /// Emits a `crsRollbk` event with the previous and the new data version
+    pub fn rollback_migration(env: Env, admin: Address, to_version: String) -> bool {
+        functions::contract_versioning::rollback_migration(&env, admin, to_version)
+    }
+
+    /// Get the version of the layout stored course data is in
+    ///
+    /// Advanced by `migrate_course_data` once every course has been
+    /// migrated. Data written before the version was tracked reports 1.0.0.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    /// * `String` - The current data version
+    pub fn get_data_version(env: Env) -> String {
+        functions::contract_versioning::get_data_version(&env)
+    }
+
     /// Get migration status for the current contract
     ///
     /// Returns information about the current migration status and any
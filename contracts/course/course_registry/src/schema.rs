@@ -46,6 +46,21 @@ pub struct CourseRateLimitConfig {
     pub max_courses_per_window: u32,
 }
 
+/// How publishing reacts to a course claiming a duration without any modules.
+///
+/// The check is opt-in: the default `Off` keeps existing publishing flows
+/// unchanged.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DurationValidationMode {
+    /// No validation
+    Off,
+    /// Publish anyway but emit a warning event
+    Warn,
+    /// Refuse to publish
+    Reject,
+}
+
 /// Rate limiting tracking data for course operations per address.
 ///
 /// Stores the current usage count and window start time for course rate limiting.
@@ -71,7 +86,7 @@ pub struct CourseCategory {
 pub enum DataKey {
     Module(String),
     Courses,
-    CourseGoalList(String),     // Optional: Keep a list of goal IDs per course
+    CourseGoalList(String),     // List of goals per course
     CourseGoal(String, String), // (course_id, goal_id)
     CoursePrerequisites(String),
     CategorySeq,          // Sequence counter for category IDs
@@ -81,6 +96,24 @@ pub enum DataKey {
     CourseRateLimitConfig,
     /// Key for storing course rate limiting data per address: address -> CourseRateLimitData
     CourseRateLimit(Address),
+    /// Key for storing the module IDs of a course: course_id -> Vec<String>
+    CourseModules(String),
+    /// Key for storing the duration validation mode applied on publish
+    DurationValidationMode,
+    /// Key for storing whether debug mode (`perfMetric` events) is enabled
+    DebugMode,
+    /// Key for storing the course IDs of a level: level -> Vec<String>
+    LevelCourses(CourseLevel),
+    /// Key for storing the allowed ISO-639 course language codes -> Vec<String>
+    LanguageAllowlist,
+    /// Key for storing the course IDs of a language: language -> Vec<String>
+    LanguageCourses(String),
+    /// Key for storing the course IDs of a category: category -> Vec<String>
+    CategoryCourses(String),
+    /// Key for storing whether course categories must name an existing category
+    StrictCategories,
+    /// Key for storing the progress of a chunked backup import
+    ImportProgress,
 }
 
 #[contracttype]
@@ -99,6 +132,85 @@ pub struct Course {
     pub is_archived: bool,
     pub level: Option<CourseLevel>,
     pub duration_hours: Option<u32>,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub tags: Vec<String>,
+    pub co_creators: Vec<Address>,
+    pub sale_price: Option<u128>,
+    pub sale_ends_at: Option<u64>,
+}
+
+/// Layout of `Course` as stored by contract version 1.0.0, before the
+/// `created_at`, `updated_at` and `tags` fields existed.
+///
+/// Only used to read courses that have not been migrated yet.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyCourseV1 {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub creator: Address,
+    pub price: u128,
+    pub category: Option<String>,
+    pub language: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub published: bool,
+    pub prerequisites: Vec<CourseId>,
+    pub is_archived: bool,
+    pub level: Option<CourseLevel>,
+    pub duration_hours: Option<u32>,
+}
+
+/// Layout of `Course` as stored by contract version 1.1.0, before the
+/// `co_creators` field existed.
+///
+/// Only used to read courses that have not been migrated yet.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyCourseV1_1 {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub creator: Address,
+    pub price: u128,
+    pub category: Option<String>,
+    pub language: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub published: bool,
+    pub prerequisites: Vec<CourseId>,
+    pub is_archived: bool,
+    pub level: Option<CourseLevel>,
+    pub duration_hours: Option<u32>,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub tags: Vec<String>,
+}
+
+/// Layout of `Course` as stored by contract version 1.2.0, before the
+/// `sale_price` and `sale_ends_at` fields existed.
+///
+/// Only used to read courses that have not been migrated yet.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyCourseV1_2 {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub creator: Address,
+    pub price: u128,
+    pub category: Option<String>,
+    pub language: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub published: bool,
+    pub prerequisites: Vec<CourseId>,
+    pub is_archived: bool,
+    pub level: Option<CourseLevel>,
+    pub duration_hours: Option<u32>,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub tags: Vec<String>,
+    pub co_creators: Vec<Address>,
 }
 
 #[contracttype]
@@ -126,12 +238,30 @@ pub struct CourseFilters {
     pub max_price: Option<u128>,
     pub category: Option<String>,
     pub level: Option<CourseLevel>,
+    /// Minimum duration in hours; courses without a duration never match
     pub min_duration: Option<u32>,
+    /// Maximum duration in hours; courses without a duration never match
     pub max_duration: Option<u32>,
+    /// With `Some(true)`, only courses that state a duration match
+    pub require_duration: Option<bool>,
     /// Text search in course title and description
     pub search_text: Option<String>,
 }
 
+/// Fields of a new course, as taken by `create_course`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateCourseParams {
+    pub title: String,
+    pub description: String,
+    pub price: u128,
+    pub category: Option<String>,
+    pub language: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub level: Option<CourseLevel>,
+    pub duration_hours: Option<u32>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct EditCourseParams {
@@ -167,8 +297,50 @@ pub struct CourseBackupData {
     pub category_seq: u128,
     /// List of admin addresses
     pub admins: soroban_sdk::Vec<Address>,
-    /// Backup timestamp
+    /// Ledger timestamp at which the backup was exported
     pub backup_timestamp: u64,
     /// Backup version for compatibility
     pub backup_version: String,
+    /// Contract version that produced the backup
+    pub source_version: String,
+    /// Position of this data within its backup when split into chunks,
+    /// `None` for a whole backup
+    pub chunk: Option<BackupChunk>,
+    /// Hex-encoded SHA-256 over all other fields, verified on import
+    pub checksum: String,
+}
+
+/// Identifies a chunk produced by `split_backup` within its backup.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BackupChunk {
+    /// Checksum of the whole backup the chunk was split from
+    pub backup_checksum: String,
+    /// Position of the chunk, starting at 0
+    pub index: u32,
+    /// Total number of chunks in the backup
+    pub count: u32,
+}
+
+/// Progress of a chunked backup import, stored between calls.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportProgress {
+    /// Checksum of the whole backup being imported
+    pub backup_checksum: String,
+    /// Index of the next chunk to import
+    pub next_index: u32,
+    /// Total number of chunks in the backup
+    pub count: u32,
+}
+
+/// Outcome of one `purge_all_courses` call.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PurgeResult {
+    /// Number of courses removed by this call
+    pub removed: u32,
+    /// Course ID to pass as the cursor of the next call, `None` once every
+    /// course has been purged
+    pub next_cursor: Option<u32>,
 }
\ No newline at end of file
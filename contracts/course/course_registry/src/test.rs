@@ -2,7 +2,11 @@
 // Copyright (c) 2025 SkillCert
 
 use crate::{schema::Category, CourseRegistry, CourseRegistryClient};
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String, Vec};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env, String, Vec,
+};
 
 use crate::{
     functions::{
@@ -486,3 +490,411 @@ fn test_course_backup_and_recovery_system() {
     let imported_count = client.import_course_data(&admin, &backup_data);
     assert!(imported_count >= 2);
 }
+
+#[test]
+#[should_panic(expected = "Incompatible backup source version")]
+fn test_import_course_data_rejects_incompatible_source_version() {
+    let env = Env::default();
+    let contract_id: Address = env.register(CourseRegistry, ());
+    let client = CourseRegistryClient::new(&env, &contract_id);
+
+    let admin: Address = Address::generate(&env);
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_700_000_000);
+
+    env.as_contract(&contract_id, || {
+        let mut admin_list = Vec::new(&env);
+        admin_list.push_back(admin.clone());
+        env.storage()
+            .persistent()
+            .set(&crate::schema::DataKey::Admins, &admin_list);
+    });
+
+    let mut backup_data = client.export_course_data(&admin);
+    assert_eq!(backup_data.source_version, String::from_str(&env, crate::VERSION));
+    assert_eq!(backup_data.backup_timestamp, 1_700_000_000);
+
+    // Pretend the backup came from an incompatible major version
+    backup_data.source_version = String::from_str(&env, "2.0.0");
+
+    client.import_course_data(&admin, &backup_data);
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #59)")]
+fn test_import_course_data_rejects_tampered_backup() {
+    let env = Env::default();
+    let contract_id: Address = env.register(CourseRegistry, ());
+    let client = CourseRegistryClient::new(&env, &contract_id);
+
+    let admin: Address = Address::generate(&env);
+    let instructor: Address = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let course = client.create_course(
+        &instructor,
+        &String::from_str(&env, "Rust Programming"),
+        &String::from_str(&env, "Learn Rust from basics"),
+        &1000_u128,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+
+    env.as_contract(&contract_id, || {
+        let mut admin_list = Vec::new(&env);
+        admin_list.push_back(admin.clone());
+        env.storage()
+            .persistent()
+            .set(&crate::schema::DataKey::Admins, &admin_list);
+    });
+
+    let mut backup_data = client.export_course_data(&admin);
+    assert_eq!(backup_data.checksum.len(), 64);
+
+    // Tamper with a course price after export
+    let mut tampered = backup_data.courses.get(course.id.clone()).unwrap();
+    tampered.price = 1;
+    backup_data.courses.set(course.id.clone(), tampered);
+
+    client.import_course_data(&admin, &backup_data);
+}
+
+#[test]
+fn test_import_course_data_in_chunks() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let source_id: Address = env.register(CourseRegistry, ());
+    let source = CourseRegistryClient::new(&env, &source_id);
+    let target_id: Address = env.register(CourseRegistry, ());
+    let target = CourseRegistryClient::new(&env, &target_id);
+
+    let admin: Address = Address::generate(&env);
+    for contract_id in [&source_id, &target_id] {
+        env.as_contract(contract_id, || {
+            let mut admin_list = Vec::new(&env);
+            admin_list.push_back(admin.clone());
+            env.storage()
+                .persistent()
+                .set(&crate::schema::DataKey::Admins, &admin_list);
+        });
+    }
+
+    // More courses than fit in a single chunk, spread over several
+    // instructors to stay under the creation rate limit
+    let titles = [
+        "Course 01", "Course 02", "Course 03", "Course 04", "Course 05", "Course 06",
+        "Course 07", "Course 08", "Course 09", "Course 10", "Course 11", "Course 12",
+    ];
+    let mut course_ids: Vec<String> = Vec::new(&env);
+    let mut instructor: Address = Address::generate(&env);
+    for (i, title) in titles.iter().enumerate() {
+        if i % 3 == 0 {
+            instructor = Address::generate(&env);
+        }
+        let course = source.create_course(
+            &instructor,
+            &String::from_str(&env, title),
+            &String::from_str(&env, "Description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        course_ids.push_back(course.id);
+    }
+
+    let backup_data = source.export_course_data(&admin);
+
+    // The whole backup is too large to pass as a single chunk
+    let result = target.try_import_course_data_chunk(&admin, &backup_data, &None);
+    assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(46));
+
+    let chunks = crate::functions::backup_recovery::split_backup(&env, &backup_data);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(
+        chunks.get(0).unwrap().courses.len(),
+        crate::functions::backup_recovery::IMPORT_CHUNK_SIZE
+    );
+
+    // A chunk is not a whole backup
+    let result = target.try_import_course_data(&admin, &chunks.get(0).unwrap());
+    assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(65));
+
+    // Only the first chunk may start an import
+    let result = target.try_import_course_data_chunk(&admin, &chunks.get(1).unwrap(), &None);
+    assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(65));
+
+    let token = target.import_course_data_chunk(&admin, &chunks.get(0).unwrap(), &None);
+    assert_eq!(token, 1);
+
+    // Replaying a chunk, or a token that was not returned, is rejected
+    let result = target.try_import_course_data_chunk(&admin, &chunks.get(0).unwrap(), &Some(0));
+    assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(65));
+    let result = target.try_import_course_data_chunk(&admin, &chunks.get(1).unwrap(), &Some(2));
+    assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(65));
+
+    // A chunk of another backup cannot continue this import
+    source.create_course(
+        &Address::generate(&env),
+        &String::from_str(&env, "Course 13"),
+        &String::from_str(&env, "Description"),
+        &1000_u128,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+    let other_chunks = crate::functions::backup_recovery::split_backup(&env, &source.export_course_data(&admin));
+    let result = target.try_import_course_data_chunk(&admin, &other_chunks.get(1).unwrap(), &Some(token));
+    assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(65));
+
+    let token = target.import_course_data_chunk(&admin, &chunks.get(1).unwrap(), &Some(token));
+    assert_eq!(token, 2);
+
+    // The import is complete, so its last chunk cannot be replayed
+    let result = target.try_import_course_data_chunk(&admin, &chunks.get(1).unwrap(), &Some(1));
+    assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(65));
+
+    for course_id in course_ids.iter() {
+        assert_eq!(target.get_course(&course_id).id, course_id);
+    }
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #59)")]
+fn test_import_course_data_chunk_rejects_tampered_chunk() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id: Address = env.register(CourseRegistry, ());
+    let client = CourseRegistryClient::new(&env, &contract_id);
+    let admin: Address = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        let mut admin_list = Vec::new(&env);
+        admin_list.push_back(admin.clone());
+        env.storage()
+            .persistent()
+            .set(&crate::schema::DataKey::Admins, &admin_list);
+    });
+
+    let course = client.create_course(
+        &Address::generate(&env),
+        &String::from_str(&env, "Course 01"),
+        &String::from_str(&env, "Description"),
+        &1000_u128,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+
+    let backup_data = client.export_course_data(&admin);
+    let mut chunk = crate::functions::backup_recovery::split_backup(&env, &backup_data)
+        .get(0)
+        .unwrap();
+    let mut tampered = chunk.courses.get(course.id.clone()).unwrap();
+    tampered.price = 1;
+    chunk.courses.set(course.id, tampered);
+
+    client.import_course_data_chunk(&admin, &chunk, &None);
+}
+
+/// Register a source and a target registry that share an admin, for backup round trips
+fn setup_backup_contracts(env: &Env) -> (CourseRegistryClient<'static>, CourseRegistryClient<'static>, Address) {
+    let source = CourseRegistryClient::new(env, &env.register(CourseRegistry, ()));
+    let target = CourseRegistryClient::new(env, &env.register(CourseRegistry, ()));
+
+    let admin: Address = Address::generate(env);
+    for contract_id in [&source.address, &target.address] {
+        env.as_contract(contract_id, || {
+            let mut admin_list = Vec::new(env);
+            admin_list.push_back(admin.clone());
+            env.storage()
+                .persistent()
+                .set(&crate::schema::DataKey::Admins, &admin_list);
+        });
+    }
+    (source, target, admin)
+}
+
+/// Create a published course with the given listing attributes
+fn create_listed_course(
+    client: &CourseRegistryClient<'_>,
+    creator: &Address,
+    title: &str,
+    category: Option<&str>,
+    language: Option<&str>,
+    level: Option<&str>,
+) -> String {
+    let env = &client.env;
+    let course_id = client
+        .create_course(
+            creator,
+            &String::from_str(env, title),
+            &String::from_str(env, "Description"),
+            &1000_u128,
+            &category.map(|category| String::from_str(env, category)),
+            &language.map(|language| String::from_str(env, language)),
+            &None,
+            &level.map(|level| String::from_str(env, level)),
+            &None,
+        )
+        .id;
+    client.edit_course(
+        creator,
+        &course_id,
+        &crate::schema::EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(true),
+            new_level: None,
+            new_duration_hours: None,
+        },
+    );
+    course_id
+}
+
+#[test]
+fn test_import_course_data_rebuilds_level_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target, admin) = setup_backup_contracts(&env);
+    let beginner = String::from_str(&env, "Beginner");
+    let advanced = String::from_str(&env, "Advanced");
+
+    // The target's own course is overwritten by the backed-up course with the same ID
+    let stale_id = create_listed_course(&target, &Address::generate(&env), "Local", None, None, Some("Beginner"));
+    let course_id = create_listed_course(&source, &Address::generate(&env), "Backed up", None, None, Some("Advanced"));
+    assert_eq!(stale_id, course_id);
+
+    target.import_course_data(&admin, &source.export_course_data(&admin));
+
+    let listed = target.get_courses_by_level(&advanced, &None, &None);
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap().id, course_id);
+    assert_eq!(target.get_courses_by_level(&beginner, &None, &None).len(), 0);
+}
+
+#[test]
+fn test_import_course_data_chunk_rebuilds_level_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target, admin) = setup_backup_contracts(&env);
+    let advanced = String::from_str(&env, "Advanced");
+    let course_id = create_listed_course(&source, &Address::generate(&env), "Backed up", None, None, Some("Advanced"));
+
+    let chunks = crate::functions::backup_recovery::split_backup(&env, &source.export_course_data(&admin));
+    target.import_course_data_chunk(&admin, &chunks.get(0).unwrap(), &None);
+
+    let listed = target.get_courses_by_level(&advanced, &None, &None);
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap().id, course_id);
+}
+
+#[test]
+fn test_import_course_data_rebuilds_language_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target, admin) = setup_backup_contracts(&env);
+    let english = String::from_str(&env, "english");
+    let spanish = String::from_str(&env, "spanish");
+
+    // The target's own course is overwritten by the backed-up course with the same ID
+    let stale_id = create_listed_course(&target, &Address::generate(&env), "Local", None, Some("english"), None);
+    let course_id = create_listed_course(&source, &Address::generate(&env), "Backed up", None, Some("spanish"), None);
+    assert_eq!(stale_id, course_id);
+
+    target.import_course_data(&admin, &source.export_course_data(&admin));
+
+    let listed = target.list_courses_by_language(&spanish, &None, &None);
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap().id, course_id);
+    assert_eq!(target.list_courses_by_language(&english, &None, &None).len(), 0);
+}
+
+#[test]
+fn test_import_course_data_chunk_rebuilds_language_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target, admin) = setup_backup_contracts(&env);
+    let spanish = String::from_str(&env, "spanish");
+    let course_id = create_listed_course(&source, &Address::generate(&env), "Backed up", None, Some("spanish"), None);
+
+    let chunks = crate::functions::backup_recovery::split_backup(&env, &source.export_course_data(&admin));
+    target.import_course_data_chunk(&admin, &chunks.get(0).unwrap(), &None);
+
+    let listed = target.list_courses_by_language(&spanish, &None, &None);
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed.get(0).unwrap().id, course_id);
+}
+
+#[test]
+fn test_import_course_data_rebuilds_category_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target, admin) = setup_backup_contracts(&env);
+
+    // The target's own course is overwritten by the backed-up course with the same ID
+    let stale_id = create_listed_course(&target, &Address::generate(&env), "Local", Some("Design"), None, None);
+    let course_id = create_listed_course(&source, &Address::generate(&env), "Backed up", Some("Programming"), None, None);
+    assert_eq!(stale_id, course_id);
+
+    target.import_course_data(&admin, &source.export_course_data(&admin));
+
+    assert_eq!(target.count_courses_by_category(&String::from_str(&env, "Programming")), 1);
+    assert_eq!(target.count_courses_by_category(&String::from_str(&env, "Design")), 0);
+}
+
+#[test]
+fn test_import_course_data_chunk_rebuilds_category_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (source, target, admin) = setup_backup_contracts(&env);
+    create_listed_course(&source, &Address::generate(&env), "Backed up", Some("Programming"), None, None);
+
+    let chunks = crate::functions::backup_recovery::split_backup(&env, &source.export_course_data(&admin));
+    target.import_course_data_chunk(&admin, &chunks.get(0).unwrap(), &None);
+
+    assert_eq!(target.count_courses_by_category(&String::from_str(&env, "Programming")), 1);
+}
+
+#[test]
+fn test_get_admin_returns_owner() {
+    let env = Env::default();
+    let contract_id = env.register(CourseRegistry, ());
+    let client = CourseRegistryClient::new(&env, &contract_id);
+    let owner = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        crate::functions::access_control::initialize(&env, &owner, &Address::generate(&env));
+    });
+
+    assert_eq!(client.get_admin(), owner);
+}
+
+#[test]
+fn test_get_admin_not_initialized() {
+    let env = Env::default();
+    let contract_id = env.register(CourseRegistry, ());
+    let client = CourseRegistryClient::new(&env, &contract_id);
+
+    let err = client.try_get_admin().unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        soroban_sdk::Error::from_contract_error(crate::error::StateError::NotInitialized as u32)
+    );
+}
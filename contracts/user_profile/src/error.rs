@@ -10,6 +10,8 @@ pub enum Error {
     UserProfileNotFound = 1,
     InvalidInput = 2,
     UnauthorizedAccess = 3,
+    ProfileAlreadyExists = 4,
+    AlreadyInitialized = 5,
 }
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
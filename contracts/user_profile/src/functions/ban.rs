@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::functions::initialize::require_owner;
+use crate::schema::DataKey;
+
+const SET_BANNED_EVENT: Symbol = symbol_short!("prflBan");
+
+/// Ban or unban a user platform-wide
+///
+/// Only the contract owner may change bans. Unbanning removes the entry, so
+/// only banned users take up storage.
+pub fn set_banned(env: &Env, caller: Address, user: Address, banned: bool) {
+    caller.require_auth();
+    require_owner(env, &caller);
+
+    let key: DataKey = DataKey::Banned(user.clone());
+    if banned {
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 100, 1000);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events().publish((SET_BANNED_EVENT, user), banned);
+}
+
+/// Whether the user is banned platform-wide
+pub fn is_banned(env: &Env, user: Address) -> bool {
+    env.storage()
+        .persistent()
+        .get::<DataKey, bool>(&DataKey::Banned(user))
+        .unwrap_or(false)
+}
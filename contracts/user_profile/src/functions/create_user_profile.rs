@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{DataKey, UserProfile};
+
+const PROFILE_CREATED_EVENT: Symbol = symbol_short!("prflCrtd");
+
+/// Create a profile for `user` referencing their off-chain profile record
+///
+/// Requires the user's authorization. Rejects an empty `off_chain_ref_id`
+/// or an empty `did_hash` with `InvalidInput`, and a user who already has a
+/// profile with `ProfileAlreadyExists`. The personal fields start out empty
+/// and the profile is private.
+pub fn create_user_profile(
+    env: &Env,
+    user: Address,
+    off_chain_ref_id: String,
+    did_hash: Option<String>,
+) -> UserProfile {
+    user.require_auth();
+
+    if off_chain_ref_id.is_empty() || did_hash.as_ref().is_some_and(|hash| hash.is_empty()) {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let key: DataKey = DataKey::Profile(user.clone());
+    if env.storage().persistent().has(&key) {
+        handle_error(env, Error::ProfileAlreadyExists)
+    }
+
+    let now: u64 = env.ledger().timestamp();
+    let empty: String = String::from_str(env, "");
+    let profile: UserProfile = UserProfile {
+        address: user.clone(),
+        name: empty.clone(),
+        email: None,
+        country: empty.clone(),
+        profession: empty.clone(),
+        goals: empty,
+        off_chain_ref_id: off_chain_ref_id.clone(),
+        did_hash,
+        privacy_public: false,
+        created_at: now,
+        updated_at: now,
+    };
+
+    env.storage().persistent().set(&key, &profile);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events()
+        .publish((PROFILE_CREATED_EVENT, user), off_chain_ref_id);
+
+    profile
+}
@@ -1,13 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{Address, Env, Symbol, symbol_short};
+use soroban_sdk::{Address, Env};
 
-use crate::schema::UserProfile;
+use crate::schema::{DataKey, UserProfile};
 use crate::error::{Error, handle_error};
 
-const PROFILE_KEY: Symbol = symbol_short!("profile");
-
 pub fn user_profile_get_user_profile(env: &Env, user_address: Address) -> UserProfile {
     // Input validation
     // If Address type supports is_empty or similar, add check. Otherwise, skip.
@@ -16,8 +14,8 @@ pub fn user_profile_get_user_profile(env: &Env, user_address: Address) -> UserPr
     // Get the user profile from storage with proper error handling
     match env
         .storage()
-        .instance()
-        .get::<(Symbol, Address), UserProfile>(&(PROFILE_KEY, user_address.clone()))
+        .persistent()
+        .get::<DataKey, UserProfile>(&DataKey::Profile(user_address))
     {
         Some(profile) => profile,
         None => handle_error(env, Error::UserProfileNotFound),
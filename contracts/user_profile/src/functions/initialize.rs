@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::schema::DataKey;
+
+const INIT_EVENT: Symbol = symbol_short!("init");
+
+/// Set the contract owner
+///
+/// Requires the owner's authorization and fails with `AlreadyInitialized`
+/// if an owner is already set.
+pub fn initialize(env: &Env, owner: Address) {
+    owner.require_auth();
+
+    if env.storage().instance().has(&DataKey::Owner) {
+        handle_error(env, Error::AlreadyInitialized)
+    }
+
+    env.storage().instance().set(&DataKey::Owner, &owner);
+
+    env.events().publish((INIT_EVENT,), owner);
+}
+
+/// Fail with `UnauthorizedAccess` unless `caller` is the contract owner
+pub fn require_owner(env: &Env, caller: &Address) {
+    let owner: Option<Address> = env.storage().instance().get(&DataKey::Owner);
+    if owner.as_ref() != Some(caller) {
+        handle_error(env, Error::UnauthorizedAccess)
+    }
+}
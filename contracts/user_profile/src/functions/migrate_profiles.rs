@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::functions::initialize::require_owner;
+use crate::schema::{DataKey, LegacyUserProfile, UserProfile};
+
+/// Key under which profiles used to be kept in instance storage
+const LEGACY_PROFILE_KEY: Symbol = symbol_short!("profile");
+
+const MIGRATE_PROFILES_EVENT: Symbol = symbol_short!("prflMigr");
+
+/// Move the given users' profiles from instance to persistent storage
+///
+/// Instance storage cannot be enumerated, so the caller passes the users to
+/// migrate. Each legacy entry is removed; if a persistent profile already
+/// exists it is kept and the legacy copy is dropped. Legacy profiles predate
+/// off-chain records, so they are migrated with an empty `off_chain_ref_id`
+/// and no `did_hash`; the personal fields are kept as they were.
+///
+/// Only the contract owner may migrate profiles.
+///
+/// Returns the number of profiles written to persistent storage.
+pub fn migrate_profiles(env: &Env, caller: Address, users: Vec<Address>) -> u32 {
+    caller.require_auth();
+    require_owner(env, &caller);
+
+    let mut migrated: u32 = 0;
+
+    for user in users.iter() {
+        let legacy_key: (Symbol, Address) = (LEGACY_PROFILE_KEY, user.clone());
+        let legacy: LegacyUserProfile = match env.storage().instance().get(&legacy_key) {
+            Some(legacy) => legacy,
+            None => continue,
+        };
+
+        let key: DataKey = DataKey::Profile(user);
+        if !env.storage().persistent().has(&key) {
+            env.storage().persistent().set(&key, &from_legacy(env, legacy));
+            env.storage().persistent().extend_ttl(&key, 100, 1000);
+            migrated += 1;
+        }
+        env.storage().instance().remove(&legacy_key);
+    }
+
+    env.events().publish((MIGRATE_PROFILES_EVENT,), migrated);
+
+    migrated
+}
+
+fn from_legacy(env: &Env, legacy: LegacyUserProfile) -> UserProfile {
+    UserProfile {
+        address: legacy.address,
+        name: legacy.name,
+        email: legacy.email,
+        country: legacy.country,
+        profession: legacy.profession,
+        goals: legacy.goals,
+        off_chain_ref_id: String::from_str(env, ""),
+        did_hash: None,
+        privacy_public: legacy.privacy_public,
+        created_at: legacy.created_at,
+        updated_at: legacy.updated_at,
+    }
+}
@@ -1,4 +1,8 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+pub mod ban;
+pub mod create_user_profile;
 pub mod get_user_profile;
+pub mod initialize;
+pub mod migrate_profiles;
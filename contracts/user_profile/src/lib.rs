@@ -14,17 +14,65 @@ pub mod schema;
 mod test;
 
 use crate::schema::UserProfile;
-use soroban_sdk::{contract, contractimpl, Address, Env};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
 
 /// User Profile Contract
 ///
-/// This contract provides read-only access to user profile information
-/// with privacy controls and permission checks.
+/// This contract stores user profiles that reference off-chain profile
+/// records and provides access to them with privacy controls and
+/// permission checks.
 #[contract]
 pub struct UserProfileContract;
 
 #[contractimpl]
 impl UserProfileContract {
+    /// Initialize the contract with its owner.
+    ///
+    /// The owner is the only address allowed to run maintenance operations
+    /// such as `migrate_profiles`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `owner` - The address that owns the contract (must authorize the call)
+    ///
+    /// # Panics
+    ///
+    /// * If the contract has already been initialized (`AlreadyInitialized`)
+    pub fn initialize(env: Env, owner: Address) {
+        functions::initialize::initialize(&env, owner)
+    }
+
+    /// Create a profile for a user.
+    ///
+    /// The profile references a record kept off-chain, so the personal
+    /// fields start out empty and the profile is private. The user must
+    /// authorize the call.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The blockchain address of the user creating their profile
+    /// * `off_chain_ref_id` - Reference to the off-chain profile record (must not be empty)
+    /// * `did_hash` - Optional hash of the user's decentralized identifier
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `UserProfile`.
+    ///
+    /// # Panics
+    ///
+    /// * If `off_chain_ref_id` or a provided `did_hash` is empty (`InvalidInput`)
+    /// * If the user already has a profile (`ProfileAlreadyExists`)
+    pub fn create_user_profile(
+        env: Env,
+        user: Address,
+        off_chain_ref_id: String,
+        did_hash: Option<String>,
+    ) -> UserProfile {
+        functions::create_user_profile::create_user_profile(&env, user, off_chain_ref_id, did_hash)
+    }
+
     /// Get a user profile by address.
     ///
     /// This function retrieves a user's profile information using their blockchain address.
@@ -68,4 +116,61 @@ impl UserProfileContract {
             requester_address,
         )
     }
+
+    /// Ban or unban a user platform-wide.
+    ///
+    /// Other contracts, such as course_access, consult `is_banned` before
+    /// granting access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner (must authorize the call)
+    /// * `user` - The address to ban or unban
+    /// * `banned` - `true` to ban the user, `false` to lift the ban
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the contract owner (`UnauthorizedAccess`)
+    pub fn set_banned(env: Env, caller: Address, user: Address, banned: bool) {
+        functions::ban::set_banned(&env, caller, user, banned)
+    }
+
+    /// Check whether a user is banned platform-wide.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the user is banned, `false` otherwise.
+    pub fn is_banned(env: Env, user: Address) -> bool {
+        functions::ban::is_banned(&env, user)
+    }
+
+    /// Move profiles from legacy instance storage to persistent storage.
+    ///
+    /// Profiles used to be stored in instance storage, which is shared by the
+    /// whole contract and does not scale to many users. Reads only consult
+    /// persistent storage, so existing profiles must be migrated.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The contract owner (must authorize the call)
+    /// * `users` - The addresses whose profiles should be migrated
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of profiles written to persistent storage.
+    /// Users without a legacy profile are skipped.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not the contract owner (`UnauthorizedAccess`)
+    pub fn migrate_profiles(env: Env, caller: Address, users: Vec<Address>) -> u32 {
+        functions::migrate_profiles::migrate_profiles(&env, caller, users)
+    }
 }
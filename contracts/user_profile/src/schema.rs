@@ -10,6 +10,37 @@ use soroban_sdk::{contracttype, Address, String};
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct UserProfile {
+    /// User's blockchain address
+    pub address: Address,
+    /// User's full name
+    pub name: String,
+    /// Optional email address (may be hidden for privacy)
+    pub email: Option<String>,
+    /// User's country of residence
+    pub country: String,
+    /// User's profession or job title
+    pub profession: String,
+    /// User's learning goals or objectives
+    pub goals: String,
+    /// Reference to the profile record kept off-chain
+    pub off_chain_ref_id: String,
+    /// Optional hash of the user's decentralized identifier (DID)
+    pub did_hash: Option<String>,
+    /// Whether the profile is publicly viewable
+    pub privacy_public: bool,
+    /// Timestamp when the profile was created
+    pub created_at: u64,
+    /// Timestamp when the profile was last updated
+    pub updated_at: u64,
+}
+
+/// Layout of `UserProfile` as kept in legacy instance storage, before
+/// profiles referenced an off-chain record.
+///
+/// Only used to read profiles that have not been migrated yet.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyUserProfile {
     /// User's blockchain address
     pub address: Address,
     /// User's full name
@@ -39,4 +70,8 @@ pub struct UserProfile {
 pub enum DataKey {
     /// Key for storing user profiles: address -> UserProfile
     Profile(Address),
+    /// Key for the contract owner set by `initialize`
+    Owner,
+    /// Key marking a user as banned platform-wide: address -> bool
+    Banned(Address),
 }
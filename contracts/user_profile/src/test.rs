@@ -1,8 +1,9 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{testutils::Address as _, Address, Env, String, Symbol};
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Env, String, Symbol};
 
+use crate::schema::{DataKey, LegacyUserProfile};
 use crate::{UserProfile, UserProfileContract, UserProfileContractClient};
 
 /// Helper function to create a test user profile
@@ -14,6 +15,8 @@ fn create_test_profile(env: &Env, address: Address) -> UserProfile {
         country: String::from_str(env, "United States"),
         profession: String::from_str(env, "Software Engineer"),
         goals: String::from_str(env, "Learn blockchain development"),
+        off_chain_ref_id: String::from_str(env, "profile-ref-1"),
+        did_hash: None,
         privacy_public: true,
         created_at: env.ledger().timestamp(),
         updated_at: env.ledger().timestamp(),
@@ -22,10 +25,35 @@ fn create_test_profile(env: &Env, address: Address) -> UserProfile {
 
 /// Helper function to save a profile to storage
 fn save_profile_to_storage(env: &Env, profile: &UserProfile) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Profile(profile.address.clone()), profile);
+}
+
+/// Helper function to save a profile in the legacy layout under the legacy instance storage key
+///
+/// Returns the profile the migration is expected to produce from it.
+fn save_legacy_profile(env: &Env, profile: &UserProfile) -> UserProfile {
+    let legacy: LegacyUserProfile = LegacyUserProfile {
+        address: profile.address.clone(),
+        name: profile.name.clone(),
+        email: profile.email.clone(),
+        country: profile.country.clone(),
+        profession: profile.profession.clone(),
+        goals: profile.goals.clone(),
+        privacy_public: profile.privacy_public,
+        created_at: profile.created_at,
+        updated_at: profile.updated_at,
+    };
     let key: Symbol = Symbol::new(env, "profile");
     env.storage()
         .instance()
-        .set(&(key, profile.address.clone()), profile);
+        .set(&(key, profile.address.clone()), &legacy);
+
+    let mut migrated: UserProfile = profile.clone();
+    migrated.off_chain_ref_id = String::from_str(env, "");
+    migrated.did_hash = None;
+    migrated
 }
 
 #[test]
@@ -237,3 +265,220 @@ fn test_multiple_users_profiles() {
     assert_eq!(result2, profile2);
     assert_ne!(result1, result2);
 }
+
+#[test]
+#[should_panic(expected = "escalating error to panic")]
+fn test_legacy_instance_profile_not_consulted() {
+    let env: Env = Env::default();
+    let contract_id: Address = env.register(UserProfileContract, {});
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+
+    let user_address: Address = Address::generate(&env);
+    let profile: UserProfile = create_test_profile(&env, user_address.clone());
+
+    env.as_contract(&contract_id, || {
+        save_legacy_profile(&env, &profile);
+    });
+
+    client.get_user_profile(&user_address);
+}
+
+#[test]
+fn test_migrate_profiles_moves_instance_entry() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserProfileContract, {});
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+    let owner: Address = Address::generate(&env);
+    client.initialize(&owner);
+
+    let user_address: Address = Address::generate(&env);
+    let without_profile: Address = Address::generate(&env);
+    let profile: UserProfile = create_test_profile(&env, user_address.clone());
+
+    let expected: UserProfile = env.as_contract(&contract_id, || save_legacy_profile(&env, &profile));
+
+    let migrated: u32 = client.migrate_profiles(&owner, &vec![&env, user_address.clone(), without_profile]);
+    assert_eq!(migrated, 1);
+    assert_eq!(client.get_user_profile(&user_address), expected);
+
+    // The legacy entry is gone, so migrating again moves nothing
+    env.as_contract(&contract_id, || {
+        let key: Symbol = Symbol::new(&env, "profile");
+        assert!(!env.storage().instance().has(&(key, user_address.clone())));
+    });
+    assert_eq!(client.migrate_profiles(&owner, &vec![&env, user_address]), 0);
+}
+
+#[test]
+fn test_migrate_profiles_keeps_persistent_profile() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserProfileContract, {});
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+    let owner: Address = Address::generate(&env);
+    client.initialize(&owner);
+
+    let user_address: Address = Address::generate(&env);
+    let legacy: UserProfile = create_test_profile(&env, user_address.clone());
+    let mut current: UserProfile = legacy.clone();
+    current.name = String::from_str(&env, "Jane Doe");
+
+    env.as_contract(&contract_id, || {
+        save_legacy_profile(&env, &legacy);
+        save_profile_to_storage(&env, &current);
+    });
+
+    assert_eq!(client.migrate_profiles(&owner, &vec![&env, user_address.clone()]), 0);
+    assert_eq!(client.get_user_profile(&user_address), current);
+}
+
+#[test]
+fn test_create_user_profile_round_trip() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let contract_id: Address = env.register(UserProfileContract, {});
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+
+    let user_address: Address = Address::generate(&env);
+    let ref_id: String = String::from_str(&env, "profile-ref-1");
+    let did_hash: Option<String> = Some(String::from_str(&env, "did-hash"));
+
+    let created: UserProfile = client.create_user_profile(&user_address, &ref_id, &did_hash);
+    assert_eq!(created.address, user_address);
+    assert_eq!(created.off_chain_ref_id, ref_id);
+    assert_eq!(created.did_hash, did_hash);
+    assert!(!created.privacy_public);
+    assert_eq!(created.created_at, 1_000);
+    assert_eq!(created.updated_at, 1_000);
+
+    assert_eq!(client.get_user_profile(&user_address), created);
+}
+
+#[test]
+fn test_create_user_profile_duplicate_rejected() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserProfileContract, {});
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+
+    let user_address: Address = Address::generate(&env);
+    client.create_user_profile(&user_address, &String::from_str(&env, "profile-ref-1"), &None);
+
+    // ProfileAlreadyExists (code 4), and the first profile is kept
+    let result = client.try_create_user_profile(&user_address, &String::from_str(&env, "profile-ref-2"), &None);
+    assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(4));
+    assert_eq!(
+        client.get_user_profile(&user_address).off_chain_ref_id,
+        String::from_str(&env, "profile-ref-1")
+    );
+}
+
+#[test]
+fn test_create_user_profile_requires_auth() {
+    let env: Env = Env::default();
+    let contract_id: Address = env.register(UserProfileContract, {});
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+
+    let user_address: Address = Address::generate(&env);
+    let result = client.try_create_user_profile(&user_address, &String::from_str(&env, "profile-ref-1"), &None);
+    assert!(result.is_err());
+    assert!(client.try_get_user_profile(&user_address).is_err());
+}
+
+#[test]
+fn test_create_user_profile_empty_ref_id_rejected() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserProfileContract, {});
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+
+    // InvalidInput (code 2)
+    let user_address: Address = Address::generate(&env);
+    let result = client.try_create_user_profile(&user_address, &String::from_str(&env, ""), &None);
+    assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(2));
+}
+
+#[test]
+fn test_migrate_profiles_requires_owner() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserProfileContract, {});
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+    let owner: Address = Address::generate(&env);
+    client.initialize(&owner);
+
+    let user_address: Address = Address::generate(&env);
+    let profile: UserProfile = create_test_profile(&env, user_address.clone());
+    env.as_contract(&contract_id, || {
+        save_legacy_profile(&env, &profile);
+    });
+
+    // UnauthorizedAccess (code 3), and the legacy entry is left in place
+    let result = client.try_migrate_profiles(&Address::generate(&env), &vec![&env, user_address.clone()]);
+    assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(3));
+    assert!(client.try_get_user_profile(&user_address).is_err());
+}
+
+#[test]
+fn test_migrate_profiles_requires_owner_auth() {
+    let env: Env = Env::default();
+    let contract_id: Address = env.register(UserProfileContract, {});
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+    let owner: Address = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&owner);
+    env.set_auths(&[]);
+
+    let result = client.try_migrate_profiles(&owner, &vec![&env, Address::generate(&env)]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_initialize_twice_rejected() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserProfileContract, {});
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+
+    // AlreadyInitialized (code 5)
+    let result = client.try_initialize(&Address::generate(&env));
+    assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(5));
+}
+
+#[test]
+fn test_set_banned_round_trip() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserProfileContract, {});
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+    let owner: Address = Address::generate(&env);
+    client.initialize(&owner);
+
+    let user_address: Address = Address::generate(&env);
+    assert!(!client.is_banned(&user_address));
+
+    client.set_banned(&owner, &user_address, &true);
+    assert!(client.is_banned(&user_address));
+    assert!(!client.is_banned(&Address::generate(&env)));
+
+    client.set_banned(&owner, &user_address, &false);
+    assert!(!client.is_banned(&user_address));
+}
+
+#[test]
+fn test_set_banned_requires_owner() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserProfileContract, {});
+    let client: UserProfileContractClient<'_> = UserProfileContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env));
+
+    // UnauthorizedAccess (code 3)
+    let user_address: Address = Address::generate(&env);
+    let result = client.try_set_banned(&Address::generate(&env), &user_address, &true);
+    assert_eq!(result.unwrap_err().unwrap(), soroban_sdk::Error::from_contract_error(3));
+    assert!(!client.is_banned(&user_address));
+}
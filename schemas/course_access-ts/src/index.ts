@@ -45,7 +45,49 @@ export const Errors = {
   9: {message:"EmptyCourseId"},
   10: {message:"InvalidTransferData"},
   11: {message:"SameUserTransfer"},
-  12: {message:"Initialized"}
+  12: {message:"Initialized"},
+  13: {message:"UserProfileNotFound"},
+  14: {message:"BackupChecksumMismatch"},
+  15: {message:"InvalidBackupData"},
+  16: {message:"TrialAlreadyUsed"},
+  17: {message:"NotTrialAccess"},
+  18: {message:"InvalidEnrollmentProof"},
+  19: {message:"InvalidLimit"},
+  20: {message:"InvalidHash"},
+  21: {message:"VoucherNotFound"},
+  22: {message:"VoucherAlreadyExists"},
+  23: {message:"VoucherExpired"},
+  24: {message:"VoucherExhausted"},
+  25: {message:"VoucherRevoked"},
+  26: {message:"VoucherAlreadyRedeemed"},
+  27: {message:"InvalidVoucher"},
+  28: {message:"InviteNotFound"},
+  29: {message:"InviteExpired"},
+  30: {message:"EnrollmentClosed"},
+  31: {message:"CourseAlreadyCompleted"},
+  32: {message:"UserBanned"},
+  33: {message:"CompletionNotFound"},
+  34: {message:"ModuleNotFound"},
+  35: {message:"AccessSuspended"},
+  36: {message:"AccessNotSuspended"},
+  37: {message:"TooManyAdmins"},
+  38: {message:"InvalidVersion"},
+  39: {message:"NotInitialized"},
+  40: {message:"RegistryUnavailable"},
+  41: {message:"CourseNotFoundInRegistry"},
+  42: {message:"UserEnrollmentLimitReached"},
+  43: {message:"InvalidTimeRange"},
+  44: {message:"UserDenylisted"},
+  45: {message:"DenylistFull"},
+  46: {message:"InvalidConfig"},
+  47: {message:"MigrationInProgress"},
+  48: {message:"TooManyGranters"},
+  49: {message:"Decommissioned"},
+  50: {message:"GrantRateLimitExceeded"}
+}
+
+export const AccessError = {
+  51: {message:"ProfileRequired"}
 }
 
 /**
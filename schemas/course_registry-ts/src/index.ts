@@ -86,6 +86,19 @@ export const Errors = {
   58: {message:"CourseRateLimitNotConfigured"}
 }
 
+export const ValidationError = {
+  59: {message:"BackupChecksumMismatch"},
+  60: {message:"InvalidCourseLevel"},
+  61: {message:"InvalidCourseLanguage"},
+  62: {message:"TooManyCoCreators"},
+  63: {message:"CoCreatorNotFound"}
+}
+
+export const StateError = {
+  64: {message:"NotInitialized"},
+  65: {message:"InvalidImportChunk"}
+}
+
 /**
  * Errors that can occur during contract versioning operations
  */